@@ -0,0 +1,41 @@
+//! Benchmark formatting quantities into a fixed buffer
+//!
+//! Run with `cargo bench`.
+use mag::length::{ft, m, mi};
+use mag::time::{h, s};
+use mag::Speed;
+use std::fmt::{self, Write};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 1_000_000;
+
+/// Writer which discards output, counting bytes
+struct Sink(usize);
+
+impl Write for Sink {
+    fn write_str(&mut self, st: &str) -> fmt::Result {
+        self.0 += st.len();
+        Ok(())
+    }
+}
+
+/// Time formatting a value repeatedly
+fn bench<Q: fmt::Display>(name: &str, q: Q) {
+    let mut sink = Sink(0);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        write!(sink, "{:.3}", black_box(&q)).unwrap();
+    }
+    let elapsed = start.elapsed();
+    black_box(sink.0);
+    let ns = elapsed.as_nanos() / u128::from(ITERATIONS);
+    println!("{name:<8} {ns:>5} ns/iter");
+}
+
+fn main() {
+    bench("length", 2.5 * m);
+    bench("area", 3.0 * ft * ft);
+    bench("period", 1.5 * s);
+    bench("speed", Speed::<mi, h>::new(55.0));
+}
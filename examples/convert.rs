@@ -0,0 +1,67 @@
+// convert.rs
+//
+// Convert a length between units from the command line.
+//
+// Usage: convert <value> <from> <to>
+//
+use mag::length::{cm, ft, km, m, mi, mm, yd, In, Unit};
+use mag::Length;
+use std::env;
+use std::process::ExitCode;
+
+/// Create a length in meters from a value and unit label
+fn length_m(value: f64, label: &str) -> Option<Length<m>> {
+    Some(match label {
+        "km" => (value * km).to(),
+        "m" => value * m,
+        "cm" => (value * cm).to(),
+        "mm" => (value * mm).to(),
+        "mi" => (value * mi).to(),
+        "yd" => (value * yd).to(),
+        "ft" => (value * ft).to(),
+        "in" => (value * In).to(),
+        _ => return None,
+    })
+}
+
+/// Convert a length to a displayable string in the specified unit
+fn display<U: Unit>(len: Length<m>) -> String {
+    len.to::<U>().to_string()
+}
+
+/// Format a length with a unit label
+fn format_as(len: Length<m>, label: &str) -> Option<String> {
+    Some(match label {
+        "km" => display::<km>(len),
+        "m" => display::<m>(len),
+        "cm" => display::<cm>(len),
+        "mm" => display::<mm>(len),
+        "mi" => display::<mi>(len),
+        "yd" => display::<yd>(len),
+        "ft" => display::<ft>(len),
+        "in" => display::<In>(len),
+        _ => return None,
+    })
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 3 {
+        eprintln!("Usage: convert <value> <from> <to>");
+        return ExitCode::FAILURE;
+    }
+    let Ok(value) = args[0].parse::<f64>() else {
+        eprintln!("Invalid value: {}", args[0]);
+        return ExitCode::FAILURE;
+    };
+    let Some(len) = length_m(value, &args[1]) else {
+        eprintln!("Unknown unit: {}", args[1]);
+        return ExitCode::FAILURE;
+    };
+    let Some(out) = format_as(len, &args[2]) else {
+        eprintln!("Unknown unit: {}", args[2]);
+        return ExitCode::FAILURE;
+    };
+    println!("{out}");
+    ExitCode::SUCCESS
+}
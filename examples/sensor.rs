@@ -0,0 +1,54 @@
+// sensor.rs
+//
+// Process a stream of raw sensor readings into typed quantities.
+//
+use mag::{
+    length::{cm, km, m},
+    quan::Quantity,
+    temp::{DegC, DegF},
+    time::{h, s},
+    Frequency,
+};
+
+/// Wheel circumference of the vehicle
+const WHEEL_CM: f64 = 210.0;
+
+/// One sample from the vehicle sensors
+struct Sample {
+    /// Wheel pulses per second
+    pulses: f64,
+    /// Air temperature in degrees Fahrenheit
+    temp_f: f64,
+}
+
+fn main() {
+    let samples = [
+        Sample {
+            pulses: 11.2,
+            temp_f: 71.6,
+        },
+        Sample {
+            pulses: 12.8,
+            temp_f: 72.1,
+        },
+        Sample {
+            pulses: 13.1,
+            temp_f: 72.3,
+        },
+        Sample {
+            pulses: 9.7,
+            temp_f: 72.0,
+        },
+    ];
+    let wheel = (WHEEL_CM * cm).to::<m>();
+    let mut total = Quantity::<DegC>::new(0.0);
+    for sample in &samples {
+        let rate = Frequency::<s>::new(sample.pulses);
+        let speed = (wheel * rate).to::<km, h>();
+        let temp = (sample.temp_f * DegF).to::<DegC>();
+        total += temp;
+        println!("speed: {speed:.1}, temperature: {temp:.1}");
+    }
+    let mean = Quantity::<DegC>::new(total.value() / samples.len() as f64);
+    println!("mean temperature: {mean:.2}");
+}
@@ -0,0 +1,33 @@
+// travel_time.rs
+//
+// Estimate travel time for a trip, and check recorded speeds against the
+// posted limit.
+//
+use mag::{
+    length::{km, mi},
+    time::{h, min},
+    Period, Speed,
+};
+
+/// Calculate travel time over a distance at a constant speed
+fn travel_time(dist: mag::Length<mi>, speed: Speed<mi, h>) -> Period<min> {
+    Period::<h>::new(dist.quantity() / speed.quantity()).to()
+}
+
+fn main() {
+    let dist = 42.195 * km;
+    let limit = 55.0 * mi / h;
+    let time = travel_time(dist.to(), limit);
+    println!("{dist} at {limit} takes {time:.1}");
+
+    let recorded = [78.1 * km / h, 61.0 * km / h, 97.0 * km / h, 88.4 * km / h];
+    for speed in recorded {
+        let speed = speed.to::<mi, h>();
+        if speed > limit {
+            let over = speed - limit;
+            println!("{speed:.1}: over limit by {over:.1}");
+        } else {
+            println!("{speed:.1}: ok");
+        }
+    }
+}
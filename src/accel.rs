@@ -0,0 +1,440 @@
+// accel.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Private module for acceleration structs
+//!
+use crate::quan::{
+    fmt_quantity, parse_quantity, Label, Magnitude, Mass, ParseError, Quantity,
+    Scalable, Unit,
+};
+use crate::{length, time, Force, Period, Speed};
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Quantity of _acceleration_.
+///
+/// Acceleration is a derived quantity with [length unit]s and [time unit]s
+/// (squared).
+///
+/// ## Operations
+///
+/// * [Speed] `/` [time unit] `=>` Acceleration
+/// * [Speed] `/` [Period] `=>` Acceleration
+/// * Acceleration `+` Acceleration `=>` Acceleration
+/// * Acceleration `-` Acceleration `=>` Acceleration
+/// * Acceleration `*` f64 `=>` Acceleration
+/// * f64 `*` Acceleration `=>` Acceleration
+/// * i32 `*` Acceleration `=>` Acceleration
+/// * u32 `*` Acceleration `=>` Acceleration
+/// * Acceleration `*` u32 `=>` Acceleration
+/// * Acceleration `*` [Period] `=>` [Speed]
+/// * Acceleration `/` f64 `=>` Acceleration
+/// * Acceleration `/` Acceleration `=>` [Ratio]
+/// * Acceleration `+=` Acceleration
+/// * Acceleration `-=` Acceleration
+/// * Acceleration `*=` f64
+/// * Acceleration `/=` f64
+/// * Mass `*` Acceleration `=>` [Force]
+///
+/// Units must be the same for operations with two Acceleration operands.  The
+/// [to] method can be used for conversion.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::{ft, m}, time::s, Acceleration};
+///
+/// let a = 9.8 * m / s / s;
+/// let b = Acceleration::<ft, s>::new(32.0);
+///
+/// assert_eq!(a.to_string(), "9.8 m/s²");
+/// assert_eq!(b.to_string(), "32 ft/s²");
+/// assert_eq!(format!("{:.2}", b.to::<m, s>()), "9.75 m/s²");
+/// ```
+/// [Force]: struct.Force.html
+/// [Period]: struct.Period.html
+/// [Ratio]: ratio/struct.Ratio.html
+/// [Speed]: struct.Speed.html
+/// [length unit]: length/index.html
+/// [time unit]: time/index.html
+/// [to]: struct.Acceleration.html#method.to
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Acceleration quantity
+    #[deprecated(note = "use the `quantity()` method")]
+    pub quantity: f64,
+
+    /// Length unit
+    length: PhantomData<L>,
+
+    /// Period unit
+    period: PhantomData<P>,
+}
+
+// Acceleration + Acceleration => Acceleration
+impl<L, P> Add for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() + other.quantity())
+    }
+}
+
+// Acceleration - Acceleration => Acceleration
+impl<L, P> Sub for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() - other.quantity())
+    }
+}
+
+// Sum of Acceleration => Acceleration
+impl<L, P> Sum for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
+    }
+}
+
+// Acceleration * f64 => Acceleration
+impl<L, P> Mul<f64> for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() * scalar)
+    }
+}
+
+// f64 * Acceleration => Acceleration
+impl<L, P> Mul<Acceleration<L, P>> for f64
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Acceleration<L, P>;
+    fn mul(self, other: Acceleration<L, P>) -> Self::Output {
+        Acceleration::new(self * other.quantity())
+    }
+}
+
+// i32 * Acceleration => Acceleration
+impl<L, P> Mul<Acceleration<L, P>> for i32
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Acceleration<L, P>;
+    fn mul(self, other: Acceleration<L, P>) -> Self::Output {
+        Acceleration::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Acceleration * u32 => Acceleration
+impl<L, P> Mul<u32> for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, count: u32) -> Self::Output {
+        Self::new(self.quantity() * f64::from(count))
+    }
+}
+
+// u32 * Acceleration => Acceleration
+impl<L, P> Mul<Acceleration<L, P>> for u32
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Acceleration<L, P>;
+    fn mul(self, other: Acceleration<L, P>) -> Self::Output {
+        Acceleration::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Acceleration / f64 => Acceleration
+impl<L, P> Div<f64> for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() / scalar)
+    }
+}
+
+// Acceleration += Acceleration
+impl<L, P> AddAssign for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() + other.quantity());
+    }
+}
+
+// Acceleration -= Acceleration
+impl<L, P> SubAssign for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() - other.quantity());
+    }
+}
+
+// Acceleration *= f64
+impl<L, P> MulAssign<f64> for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() * scalar);
+    }
+}
+
+// Acceleration /= f64
+impl<L, P> DivAssign<f64> for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() / scalar);
+    }
+}
+
+impl_base_ops!(@access Acceleration<L, P> where L: length::Unit, P: time::Unit);
+
+impl<L, P> Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Create a new acceleration quantity
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
+        Acceleration::<L, P> {
+            quantity: crate::check_finite(quantity),
+            length: PhantomData,
+            period: PhantomData,
+        }
+    }
+
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.quantity() * count as f64)
+    }
+
+    /// Quantize to an integer multiple of a scale step.
+    ///
+    /// Returns the nearest step count and the residual error (the quantity
+    /// minus the quantized value).
+    pub fn quantize(self, scale: Self) -> (i64, Self) {
+        let (count, err) = crate::quantize(self.quantity(), scale.quantity());
+        (count, Self::new(err))
+    }
+
+    /// Change the units **without converting** the quantity.
+    ///
+    /// This is an escape hatch for cases where the quantity is already known
+    /// to be in the target units, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<N, R>(self) -> Acceleration<N, R>
+    where
+        N: length::Unit,
+        R: time::Unit,
+    {
+        Acceleration::new(self.quantity())
+    }
+
+    /// Convert to specified units
+    pub const fn to<N, R>(self) -> Acceleration<N, R>
+    where
+        N: length::Unit,
+        R: time::Unit,
+    {
+        let per = <P as time::Factor<R>>::FACTOR;
+        let factor = <L as length::Factor<N>>::FACTOR / (per * per);
+        Acceleration::new(self.quantity() * factor)
+    }
+
+    /// Convert to SI base units (`m/s²`)
+    pub const fn to_base(self) -> Acceleration<length::m, time::s> {
+        self.to()
+    }
+}
+
+impl<L, P> Magnitude for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn magnitude(&self) -> f64 {
+        self.quantity()
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<L, P> Scalable for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+}
+
+impl<L, P> Label for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(L::LABEL)?;
+        w.write_char('/')?;
+        w.write_str(P::LABEL)?;
+        w.write_char('²')
+    }
+}
+
+impl<L, P> fmt::Display for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_quantity(self, f)
+    }
+}
+
+impl<L, P> FromStr for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
+    }
+}
+
+// Speed / Period => Acceleration
+impl<L, P> Div<Period<P>> for Speed<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Acceleration<L, P>;
+    fn div(self, per: Period<P>) -> Self::Output {
+        Acceleration::new(self.quantity() / per.quantity())
+    }
+}
+
+// Acceleration * Period => Speed
+impl<L, P> Mul<Period<P>> for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Speed<L, P>;
+    fn mul(self, per: Period<P>) -> Self::Output {
+        Speed::new(self.quantity() * per.quantity())
+    }
+}
+
+// Mass * Acceleration => Force
+impl<M, L, P> Mul<Acceleration<L, P>> for Quantity<M>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Force<M, L, P>;
+    fn mul(self, acc: Acceleration<L, P>) -> Self::Output {
+        Force::new(self.value() * acc.quantity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::force::N;
+    use crate::length::{ft, km, m, mi};
+    use crate::mass::kg;
+    use crate::time::{h, ms, s};
+    use alloc::string::ToString;
+
+    #[test]
+    fn acceleration_display() {
+        assert_eq!((9.8 * m / s / s).to_string(), "9.8 m/s²");
+        assert_eq!(Acceleration::<mi, h>::new(2.5).to_string(), "2.5 mi/h²");
+    }
+
+    #[test]
+    fn acceleration_to() {
+        assert_eq!((1.0 * m / s / s).to(), 12_960.0 * km / h / h);
+        assert_eq!(
+            (10.0 * ft / s / s).to(),
+            9.999999999999999e-6 * ft / ms / ms
+        );
+    }
+
+    #[test]
+    fn acceleration_ops() {
+        assert_eq!(2.0 * m / s / s + 1.5 * m / s / s, 3.5 * m / s / s);
+        assert_eq!((3.0 * m / s / s) * 2u32, 6.0 * m / s / s);
+        // Speed / Period => Acceleration
+        assert_eq!((30.0 * m / s) / (3.0 * s), 10.0 * m / s / s);
+        // Acceleration * Period => Speed
+        assert_eq!((4.0 * m / s / s) * (2.5 * s), 10.0 * m / s);
+        // Mass * Acceleration => Force
+        assert_eq!((2.0 * kg) * (9.8 * m / s / s), 19.6 * N);
+        let mut a = 2.0 * m / s / s;
+        a += 1.0 * m / s / s;
+        a -= 0.5 * m / s / s;
+        a *= 4.0;
+        a /= 5.0;
+        assert_eq!(a, 2.0 * m / s / s);
+    }
+}
@@ -0,0 +1,87 @@
+// amount.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of amount of substance.
+//!
+//! Each unit is defined relative to moles with a conversion factor.  They can
+//! be used to conveniently create Amount quantities.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::amount::{mmol, mol, umol};
+//!
+//! let a = 1.5 * mol;
+//! let b = 20 * umol;
+//!
+//! assert_eq!(a.to_string(), "1.5 mol");
+//! assert_eq!(b.to_string(), "20 μmol");
+//! assert_eq!(a.to(), 1_500 * mmol);
+//! ```
+use crate::declare_unit;
+use crate::quan::Amount;
+
+declare_unit!(
+    /** Kilomole */
+    kmol,
+    "kmol",
+    Amount,
+    1_000.0,
+    "kilomole",
+    "kilomoles",
+);
+
+declare_unit!(
+    /** Mole */
+    mol,
+    "mol",
+    Amount,
+    1.0,
+    "mole",
+    "moles",
+);
+
+declare_unit!(
+    /** Millimole */
+    mmol,
+    "mmol",
+    Amount,
+    0.001,
+    "millimole",
+    "millimoles",
+);
+
+declare_unit!(
+    /** Micromole */
+    umol,
+    "μmol",
+    Amount,
+    0.000_001,
+    "micromole",
+    "micromoles",
+);
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn amount_display() {
+        assert_eq!((2.5 * mol).to_string(), "2.5 mol");
+        assert_eq!((3 * kmol).to_string(), "3 kmol");
+        assert_eq!((40.0 * umol).to_string(), "40 μmol");
+        assert_eq!(format!("{:#}", 40.0 * umol), "40 umol");
+    }
+
+    #[test]
+    fn amount_to() {
+        assert_eq!((1.0 * kmol).to(), 1_000.0 * mol);
+        assert_eq!((250.0 * mmol).to(), 0.25 * mol);
+        assert_eq!(format!("{:.1}", (1.0 * mmol).to::<umol>()), "1000.0 μmol");
+        assert_eq!("12 mmol".parse(), Ok(12.0 * mmol));
+    }
+}
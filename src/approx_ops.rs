@@ -0,0 +1,153 @@
+// approx_ops.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Approximate equality with the `approx` crate
+//!
+//! This module requires the `approx` feature.  `AbsDiffEq`, `RelativeEq` and
+//! `UlpsEq` are implemented for all quantity types, comparing magnitudes.
+//! Both quantities must have the same units, and the epsilon is an `f64`
+//! magnitude in those units.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use approx::{assert_abs_diff_eq, assert_relative_eq};
+//! use mag::{length::{ft, In, m}, time::s};
+//!
+//! assert_relative_eq!((1.0 * ft).to::<In>(), 12.0 * In);
+//! assert_abs_diff_eq!(1.0 * m / s, 1.05 * m / s, epsilon = 0.1);
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{Information, Mass, Quantity, Unit};
+use crate::ratio::Ratio;
+use crate::{
+    length, speed, time, Acceleration, Area, Density, FlowRate, Force,
+    Frequency, InverseLength, Length, Period, Speed, Volume,
+};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Implement `approx` traits by comparing a magnitude accessor
+///
+/// Unit parameters need `PartialEq`, since it is a supertrait of `AbsDiffEq`.
+macro_rules! impl_approx {
+    ($quan:ident<$($param:ident),*>.$get:ident() $(where $($bounds:tt)+)?) => {
+        impl<$($param),*> AbsDiffEq for $quan<$($param),*>
+        where
+            $($param: PartialEq,)*
+            $($($bounds)+)?
+        {
+            type Epsilon = f64;
+
+            fn default_epsilon() -> f64 {
+                f64::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+                self.$get().abs_diff_eq(&other.$get(), epsilon)
+            }
+        }
+
+        impl<$($param),*> RelativeEq for $quan<$($param),*>
+        where
+            $($param: PartialEq,)*
+            $($($bounds)+)?
+        {
+            fn default_max_relative() -> f64 {
+                f64::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: f64,
+                max_relative: f64,
+            ) -> bool {
+                let (a, b) = (self.$get(), other.$get());
+                a.relative_eq(&b, epsilon, max_relative)
+            }
+        }
+
+        impl<$($param),*> UlpsEq for $quan<$($param),*>
+        where
+            $($param: PartialEq,)*
+            $($($bounds)+)?
+        {
+            fn default_max_ulps() -> u32 {
+                f64::default_max_ulps()
+            }
+
+            fn ulps_eq(
+                &self,
+                other: &Self,
+                epsilon: f64,
+                max_ulps: u32,
+            ) -> bool {
+                self.$get().ulps_eq(&other.$get(), epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx!(Length<U>.quantity() where U: length::Unit);
+impl_approx!(Area<U>.quantity() where U: length::Unit);
+impl_approx!(InverseLength<U>.quantity() where U: length::Unit);
+impl_approx!(Volume<U>.quantity() where U: length::Unit);
+impl_approx!(Period<U>.quantity() where U: time::Unit);
+impl_approx!(Frequency<U>.quantity() where U: time::Unit);
+impl_approx!(Speed<L, P>.quantity() where (L, P): speed::Units);
+impl_approx!(
+    Acceleration<L, P>.quantity() where L: length::Unit, P: time::Unit
+);
+impl_approx!(FlowRate<L, P>.quantity() where L: length::Unit, P: time::Unit);
+impl_approx!(
+    Density<M, L>.quantity() where M: Unit<Measure = Mass>, L: length::Unit
+);
+impl_approx!(
+    Force<M, L, T>.quantity()
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        T: time::Unit
+);
+impl_approx!(
+    Bandwidth<D, P>.quantity()
+    where
+        D: Unit<Measure = Information>,
+        P: time::Unit
+);
+impl_approx!(Quantity<U>.value() where U: Unit);
+impl_approx!(Ratio<>.value());
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{cm, ft, m, In};
+    use crate::mass::{kg, lb};
+    use crate::temp::{DegC, DegF};
+    use crate::time::{h, s};
+    use approx::{
+        assert_abs_diff_eq, assert_relative_eq, assert_relative_ne,
+        assert_ulps_eq,
+    };
+
+    #[test]
+    fn approx_struct() {
+        assert_relative_eq!((1.0 * m).to::<cm>(), 100.0 * cm);
+        assert_relative_ne!(1.0 * m, 1.001 * m);
+        assert_abs_diff_eq!(2.0 * ft * ft, 2.01 * ft * ft, epsilon = 0.1);
+        assert_ulps_eq!((6.0 * In).to::<ft>(), 0.5 * ft);
+        assert_relative_eq!((36.0 * m / s).to(), 129.6 * crate::length::km / h);
+    }
+
+    #[test]
+    fn approx_quantity() {
+        assert_relative_eq!((1.0 * lb).to::<kg>(), 0.453_592_37 * kg);
+        assert_relative_eq!(
+            (212.0 * DegF).to::<DegC>(),
+            100.0 * DegC,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(Ratio::new(0.1 + 0.2), Ratio::new(0.3));
+    }
+}
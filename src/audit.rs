@@ -0,0 +1,192 @@
+// audit.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Quantities with an audit trail of unit conversions
+//!
+//! This module requires the `audit` feature.  An [Audited] quantity records
+//! each [Conversion] applied to it, so a computed value can show its unit
+//! provenance.  The log has a fixed capacity (no allocation); conversions
+//! past that are still performed, but the log is marked as truncated.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{audit::Audited, length::{ft, In, m}, Length};
+//!
+//! let a = Audited::new(6.0 * ft).to::<Length<In>>()?.to::<Length<m>>()?;
+//! let log: Vec<String> = a.log().map(|c| c.to_string()).collect();
+//!
+//! assert_eq!(a.value(), 1.8288000000000002 * m);
+//! assert_eq!(log[0], "ft → in (×12.000000000000002)");
+//! assert_eq!(log[1], "in → m (×0.0254)");
+//! # Ok::<(), mag::dynamic::Incompatible>(())
+//! ```
+use crate::dynamic::{DynQuantity, DynUnit, Dynamic, Incompatible};
+use core::fmt;
+
+/// Capacity of an [Audited] conversion log
+pub const LOG_CAPACITY: usize = 8;
+
+/// Record of one unit conversion
+///
+/// Values are converted with `value × factor + offset`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Conversion {
+    /// Unit converted from
+    pub from: DynUnit,
+
+    /// Unit converted to
+    pub to: DynUnit,
+
+    /// Conversion factor
+    pub factor: f64,
+
+    /// Offset added after scaling (for units such as `°F`)
+    pub offset: f64,
+}
+
+impl Conversion {
+    /// Create a conversion record between two units
+    fn new(from: DynUnit, to: DynUnit) -> Self {
+        let (factor, offset) = from.linear_to(to);
+        Conversion {
+            from,
+            to,
+            factor,
+            offset,
+        }
+    }
+}
+
+impl fmt::Display for Conversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} → {} (×{}", self.from, self.to, self.factor)?;
+        if self.offset != 0.0 {
+            write!(f, ", {:+}", self.offset)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Quantity which records its unit conversions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Audited<Q>
+where
+    Q: Dynamic + Copy,
+{
+    /// Current value
+    value: Q,
+
+    /// Conversion log
+    log: [Option<Conversion>; LOG_CAPACITY],
+
+    /// Number of conversions applied
+    count: usize,
+}
+
+impl<Q> Audited<Q>
+where
+    Q: Dynamic + Copy,
+{
+    /// Create a new audited quantity, with an empty log
+    pub fn new(value: Q) -> Self {
+        Audited {
+            value,
+            log: [None; LOG_CAPACITY],
+            count: 0,
+        }
+    }
+
+    /// Get the current value
+    pub fn value(&self) -> Q {
+        self.value
+    }
+
+    /// Convert to another quantity type, recording the conversion
+    pub fn to<R>(self) -> Result<Audited<R>, Incompatible>
+    where
+        R: Dynamic + Copy,
+    {
+        let value = DynQuantity::from(self.value).to_typed::<R>()?;
+        let mut log = self.log;
+        if let Some(entry) = log.get_mut(self.count) {
+            *entry =
+                Some(Conversion::new(DynUnit::of::<Q>(), DynUnit::of::<R>()));
+        }
+        Ok(Audited {
+            value,
+            log,
+            count: self.count + 1,
+        })
+    }
+
+    /// Get the recorded conversions, oldest first
+    pub fn log(&self) -> impl Iterator<Item = Conversion> + '_ {
+        self.log.iter().map_while(|c| *c)
+    }
+
+    /// Get the number of conversions applied
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Check whether some conversions were not recorded in the log
+    pub fn is_truncated(&self) -> bool {
+        self.count > LOG_CAPACITY
+    }
+}
+
+impl<Q> fmt::Display for Audited<Q>
+where
+    Q: Dynamic + Copy + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::mass::{g, kg, lb};
+    use crate::quan::Quantity;
+    use crate::temp::{DegC, DegF};
+    use crate::Length;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn audit_log() {
+        let a = Audited::new(50.0 * DegF).to::<Quantity<DegC>>().unwrap();
+        assert_eq!(format!("{:.1}", a), "10.0 °C");
+        let c = a.log().next().unwrap();
+        assert_eq!(c.from.to_string(), "°F");
+        assert_eq!(c.to, DynUnit::of::<Quantity<DegC>>());
+        assert_eq!(
+            c.to_string(),
+            "°F → °C (×0.5555555555555556, -17.777777777777743)"
+        );
+        assert_eq!(a.count(), 1);
+        assert!(!a.is_truncated());
+        let b = Audited::new(1.0 * m);
+        assert_eq!(b.log().count(), 0);
+        assert_eq!(b.to::<Quantity<kg>>(), Err(Incompatible));
+    }
+
+    #[test]
+    fn audit_truncated() {
+        let mut a = Audited::new(1.0 * lb);
+        for _ in 0..5 {
+            a = a.to::<Quantity<g>>().unwrap().to().unwrap();
+        }
+        assert_eq!(a.value(), 1.0 * lb);
+        assert_eq!(a.count(), 10);
+        assert_eq!(a.log().count(), LOG_CAPACITY);
+        assert!(a.is_truncated());
+        let b = Audited::new(3.0 * ft).to::<Length<ft>>().unwrap();
+        assert_eq!(b.log().next().unwrap().to_string(), "ft → ft (×1)");
+    }
+}
@@ -0,0 +1,101 @@
+// bearing.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Compass bearings from typed displacements
+//!
+//! This module requires the `libm` feature.  Displacements are `(x, y)`
+//! pairs of [Length]s, with `x` to the east and `y` to the north.  Bearings
+//! are in degrees clockwise from north.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{bearing::{bearing, heading_change}, length::m};
+//!
+//! let leg1 = (10.0 * m, 10.0 * m);
+//! let leg2 = (-5.0 * m, 5.0 * m);
+//!
+//! assert_eq!(bearing(leg1), Some(45.0));
+//! assert_eq!(bearing(leg2), Some(315.0));
+//! assert_eq!(heading_change(leg1, leg2), Some(-90.0));
+//! ```
+use crate::length::Unit;
+use crate::Length;
+
+/// Calculate the compass bearing of a displacement
+///
+/// Returns degrees in the range `[0, 360)`, or `None` if the displacement
+/// is zero.
+pub fn bearing<U>(displacement: (Length<U>, Length<U>)) -> Option<f64>
+where
+    U: Unit,
+{
+    let (x, y) = (displacement.0.quantity(), displacement.1.quantity());
+    if x == 0.0 && y == 0.0 {
+        return None;
+    }
+    let deg = libm::atan2(x, y).to_degrees();
+    Some(if deg < 0.0 { deg + 360.0 } else { deg })
+}
+
+/// Calculate the heading change between two successive displacements
+///
+/// Returns degrees in the range `(-180, 180]`, positive for a clockwise
+/// (right) turn, or `None` if either displacement is zero.
+pub fn heading_change<U>(
+    a: (Length<U>, Length<U>),
+    b: (Length<U>, Length<U>),
+) -> Option<f64>
+where
+    U: Unit,
+{
+    Some(wrap_degrees(bearing(b)? - bearing(a)?))
+}
+
+/// Wrap an angle in degrees to the range `(-180, 180]`
+pub fn wrap_degrees(deg: f64) -> f64 {
+    let deg = libm::remainder(deg, 360.0);
+    if deg <= -180.0 {
+        deg + 360.0
+    } else {
+        deg
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{ft, m};
+
+    #[test]
+    fn bearings() {
+        assert_eq!(bearing((0.0 * m, 3.0 * m)), Some(0.0));
+        assert_eq!(bearing((2.0 * m, 0.0 * m)), Some(90.0));
+        assert_eq!(bearing((0.0 * ft, -1.0 * ft)), Some(180.0));
+        assert_eq!(bearing((-4.0 * ft, 0.0 * ft)), Some(270.0));
+        assert_eq!(bearing((0.0 * m, 0.0 * m)), None);
+    }
+
+    #[test]
+    fn heading() {
+        let north = (0.0 * m, 1.0 * m);
+        let east = (1.0 * m, 0.0 * m);
+        let south = (0.0 * m, -1.0 * m);
+        let nw = (-1.0 * m, 1.0 * m);
+        assert_eq!(heading_change(north, east), Some(90.0));
+        assert_eq!(heading_change(east, north), Some(-90.0));
+        assert_eq!(heading_change(north, south), Some(180.0));
+        assert_eq!(heading_change(nw, east), Some(135.0));
+        assert_eq!(heading_change(east, (0.0 * m, 0.0 * m)), None);
+    }
+
+    #[test]
+    fn wrap() {
+        assert_eq!(wrap_degrees(190.0), -170.0);
+        assert_eq!(wrap_degrees(-180.0), 180.0);
+        assert_eq!(wrap_degrees(180.0), 180.0);
+        assert_eq!(wrap_degrees(-540.0), 180.0);
+        assert_eq!(wrap_degrees(725.0), 5.0);
+    }
+}
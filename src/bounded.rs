@@ -0,0 +1,286 @@
+// bounded.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Quantities which cannot be negative
+//!
+//! Many physical quantities, such as mass, area or speed magnitude, are
+//! invalid when negative.  Wrapping them in [NonNegative] or [Positive]
+//! checks the sign once, at construction, and also rejects infinite values.
+//! Operations which preserve the invariant are implemented directly on the
+//! wrappers:
+//!
+//! * NonNegative `+` NonNegative `=>` `Option<NonNegative>`
+//! * Sum of NonNegative `=>` `Option<NonNegative>`
+//! * NonNegative `*` NonNegative `=>` `Option<NonNegative>`
+//! * NonNegative `/` Positive `=>` `Option<NonNegative>`
+//! * Positive `+` Positive `=>` `Option<Positive>`
+//!
+//! Results of very large values can overflow to infinity, so every operation
+//! checks its result, returning `None` instead of an infinite quantity.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{bounded::{NonNegative, OutOfRange}, length::m};
+//!
+//! let a = NonNegative::new(3.0 * m)?;
+//! let b = NonNegative::new(2.0 * m)?;
+//!
+//! assert_eq!((a + b).map(NonNegative::get), Some(5.0 * m));
+//! assert_eq!((a * b).map(NonNegative::get), Some(6.0 * m * m));
+//! assert_eq!(a.checked_sub(b).map(|d| d.get()), Some(1.0 * m));
+//! assert_eq!(b.checked_sub(a), None);
+//! assert_eq!(NonNegative::new(-1.0 * m), Err(OutOfRange(-1.0)));
+//! # Ok::<(), OutOfRange>(())
+//! ```
+use crate::quan::Magnitude;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Error for a quantity outside of the allowed range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutOfRange(pub f64);
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "quantity out of range: {}", self.0)
+    }
+}
+
+impl core::error::Error for OutOfRange {}
+
+/// Quantity which is zero or positive (never negative or NaN)
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct NonNegative<Q>(Q);
+
+/// Quantity which is greater than zero (never zero, negative or NaN)
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Positive<Q>(Q);
+
+impl<Q> NonNegative<Q>
+where
+    Q: Magnitude,
+{
+    /// Create a non-negative quantity, checking the sign
+    ///
+    /// Infinite values are out of range.
+    pub fn new(q: Q) -> Result<Self, OutOfRange> {
+        let val = q.magnitude();
+        if val >= 0.0 && val.is_finite() {
+            Ok(NonNegative(q))
+        } else {
+            Err(OutOfRange(val))
+        }
+    }
+
+    /// Create a non-negative quantity, clamping negative values to zero
+    ///
+    /// NaN is also clamped to zero.
+    pub fn saturating(q: Q) -> Self {
+        Self::new(q).unwrap_or_else(|_| NonNegative(Q::from_magnitude(0.0)))
+    }
+
+    /// Get the zero quantity
+    pub fn zero() -> Self {
+        NonNegative(Q::from_magnitude(0.0))
+    }
+
+    /// Subtract another quantity, if the result is non-negative
+    pub fn checked_sub(self, other: Self) -> Option<Self>
+    where
+        Q: Sub<Output = Q>,
+    {
+        Self::new(self.0 - other.0).ok()
+    }
+
+    /// Subtract another quantity, clamping to zero
+    pub fn saturating_sub(self, other: Self) -> Self
+    where
+        Q: Sub<Output = Q>,
+    {
+        Self::saturating(self.0 - other.0)
+    }
+}
+
+impl<Q> NonNegative<Q> {
+    /// Get the wrapped quantity
+    pub fn get(self) -> Q {
+        self.0
+    }
+}
+
+impl<Q> Positive<Q>
+where
+    Q: Magnitude,
+{
+    /// Create a positive quantity, checking the sign
+    ///
+    /// Infinite values are out of range.
+    pub fn new(q: Q) -> Result<Self, OutOfRange> {
+        let val = q.magnitude();
+        if val > 0.0 && val.is_finite() {
+            Ok(Positive(q))
+        } else {
+            Err(OutOfRange(val))
+        }
+    }
+}
+
+impl<Q> Positive<Q> {
+    /// Get the wrapped quantity
+    pub fn get(self) -> Q {
+        self.0
+    }
+}
+
+impl<Q> AsRef<Q> for NonNegative<Q> {
+    fn as_ref(&self) -> &Q {
+        &self.0
+    }
+}
+
+impl<Q> AsRef<Q> for Positive<Q> {
+    fn as_ref(&self) -> &Q {
+        &self.0
+    }
+}
+
+impl<Q> From<Positive<Q>> for NonNegative<Q> {
+    fn from(pos: Positive<Q>) -> Self {
+        NonNegative(pos.0)
+    }
+}
+
+impl<Q> fmt::Display for NonNegative<Q>
+where
+    Q: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<Q> fmt::Display for Positive<Q>
+where
+    Q: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// NonNegative + NonNegative => Option<NonNegative>
+impl<Q> Add for NonNegative<Q>
+where
+    Q: Magnitude + Add<Output = Q>,
+{
+    type Output = Option<Self>;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.0 + other.0).ok()
+    }
+}
+
+// Sum of NonNegative => Option<NonNegative>
+impl<Q> Sum<NonNegative<Q>> for Option<NonNegative<Q>>
+where
+    Q: Magnitude + Add<Output = Q>,
+{
+    fn sum<I>(mut iter: I) -> Self
+    where
+        I: Iterator<Item = NonNegative<Q>>,
+    {
+        iter.try_fold(NonNegative::zero(), |a, b| a + b)
+    }
+}
+
+// Positive + Positive => Option<Positive>
+impl<Q> Add for Positive<Q>
+where
+    Q: Magnitude + Add<Output = Q>,
+{
+    type Output = Option<Self>;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.0 + other.0).ok()
+    }
+}
+
+// NonNegative * NonNegative => Option<NonNegative>
+impl<Q, R> Mul<NonNegative<R>> for NonNegative<Q>
+where
+    Q: Mul<R>,
+    Q::Output: Magnitude,
+{
+    type Output = Option<NonNegative<Q::Output>>;
+    fn mul(self, other: NonNegative<R>) -> Self::Output {
+        NonNegative::new(self.0 * other.0).ok()
+    }
+}
+
+// NonNegative / Positive => Option<NonNegative>
+impl<Q, R> Div<Positive<R>> for NonNegative<Q>
+where
+    Q: Div<R>,
+    Q::Output: Magnitude,
+{
+    type Output = Option<NonNegative<Q::Output>>;
+    fn div(self, other: Positive<R>) -> Self::Output {
+        NonNegative::new(self.0 / other.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::m;
+    use crate::mass::kg;
+    use crate::time::s;
+
+    #[test]
+    fn non_negative() {
+        assert!(NonNegative::new(0.0 * m).is_ok());
+        assert_eq!(NonNegative::new(-0.5 * kg), Err(OutOfRange(-0.5)));
+        #[cfg(not(feature = "assert-finite"))]
+        assert!(NonNegative::new(f64::NAN * m).is_err());
+        assert_eq!(NonNegative::saturating(-2.0 * s).get(), 0.0 * s);
+        let a = NonNegative::new(1.5 * kg).unwrap();
+        let b = NonNegative::new(2.0 * kg).unwrap();
+        assert_eq!(a.saturating_sub(b), NonNegative::zero());
+        assert_eq!(b.saturating_sub(a).get(), 0.5 * kg);
+        let total: Option<NonNegative<_>> = [a, b, a].into_iter().sum();
+        assert_eq!(total.map(NonNegative::get), Some(5.0 * kg));
+    }
+
+    #[test]
+    #[cfg(not(feature = "assert-finite"))]
+    fn infinite() {
+        let inf = f64::INFINITY;
+        assert_eq!(NonNegative::new(inf * m), Err(OutOfRange(inf)));
+        assert_eq!(Positive::new(inf * s), Err(OutOfRange(inf)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "assert-finite"))]
+    fn overflow() {
+        let big = NonNegative::new(f64::MAX * m).unwrap();
+        assert_eq!(big + big, None);
+        let total: Option<NonNegative<_>> = [big, big].into_iter().sum();
+        assert_eq!(total, None);
+        let huge = Positive::new(f64::MAX * s).unwrap();
+        assert_eq!(huge + huge, None);
+        assert_eq!(big * big, None);
+        let tiny = Positive::new(f64::MIN_POSITIVE * s).unwrap();
+        assert!((big / tiny).is_none());
+    }
+
+    #[test]
+    fn positive() {
+        assert_eq!(Positive::new(0.0 * m), Err(OutOfRange(0.0)));
+        let p = Positive::new(4.0 * s).unwrap();
+        let d = NonNegative::new(100.0 * m).unwrap();
+        assert_eq!((d / p).unwrap().get().quantity(), 25.0);
+        let sum = NonNegative::from((p + p).unwrap());
+        assert_eq!(sum.get(), 8.0 * s);
+    }
+}
@@ -0,0 +1,137 @@
+// checked.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Checked arithmetic for quantities
+//!
+//! By default, operations which overflow or divide by zero produce infinite
+//! or NaN quantities, just like `f64`.  There are two stricter policies:
+//!
+//! * The `assert-finite` feature debug-asserts that every quantity created
+//!   is finite.
+//! * The [Checked] trait provides operations which return a [NonFinite]
+//!   error instead of a non-finite quantity.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{checked::{Checked, NonFinite}, length::m};
+//!
+//! let a = 5.0 * m;
+//!
+//! assert_eq!(a.checked_add(2.0 * m), Ok(7.0 * m));
+//! assert_eq!(a.checked_div(0.0), Err(NonFinite(f64::INFINITY)));
+//! assert_eq!((f64::MAX * m).checked_mul(2.0), Err(NonFinite(f64::INFINITY)));
+//! ```
+use crate::quan::{Magnitude, Scalable};
+use core::fmt;
+
+/// Error for an operation with an infinite or NaN result
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonFinite(pub f64);
+
+impl fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "non-finite quantity: {}", self.0)
+    }
+}
+
+impl core::error::Error for NonFinite {}
+
+/// Checked operations, which fail on non-finite results.
+///
+/// This is implemented for all quantity types.
+pub trait Checked: Magnitude + Sized {
+    /// Check that a quantity is finite
+    fn checked(self) -> Result<Self, NonFinite> {
+        let val = self.magnitude();
+        if val.is_finite() {
+            Ok(self)
+        } else {
+            Err(NonFinite(val))
+        }
+    }
+
+    /// Checked addition
+    fn checked_add(self, other: Self) -> Result<Self, NonFinite> {
+        finite(self.magnitude() + other.magnitude())
+    }
+
+    /// Checked subtraction
+    fn checked_sub(self, other: Self) -> Result<Self, NonFinite> {
+        finite(self.magnitude() - other.magnitude())
+    }
+
+    /// Checked multiplication by a scalar
+    ///
+    /// Temperatures cannot be scaled, so they do not have this method.
+    fn checked_mul(self, scalar: f64) -> Result<Self, NonFinite>
+    where
+        Self: Scalable,
+    {
+        finite(self.magnitude() * scalar)
+    }
+
+    /// Checked division by a scalar
+    ///
+    /// Temperatures cannot be scaled, so they do not have this method.
+    fn checked_div(self, scalar: f64) -> Result<Self, NonFinite>
+    where
+        Self: Scalable,
+    {
+        finite(self.magnitude() / scalar)
+    }
+}
+
+impl<Q> Checked for Q where Q: Magnitude {}
+
+/// Make a quantity from a value, if finite
+fn finite<Q: Magnitude>(val: f64) -> Result<Q, NonFinite> {
+    if val.is_finite() {
+        Ok(Q::from_magnitude(val))
+    } else {
+        Err(NonFinite(val))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{km, m};
+    use crate::mass::kg;
+    use crate::temp::DegC;
+    use crate::time::{h, s};
+    use alloc::string::ToString;
+
+    #[test]
+    fn checked_ok() {
+        assert_eq!((1.0 * m).checked(), Ok(1.0 * m));
+        assert_eq!((3.0 * kg).checked_sub(1.0 * kg), Ok(2.0 * kg));
+        assert_eq!((10.0 * km / h).checked_mul(2.0), Ok(20.0 * km / h));
+        assert_eq!((1.0 / s).checked_add(2.0 / s), Ok(3.0 / s));
+        assert_eq!((20.0 * DegC).checked_add(2.0 * DegC), Ok(22.0 * DegC));
+    }
+
+    #[test]
+    fn checked_err() {
+        assert_eq!((5.0 * s).checked_div(0.0), Err(NonFinite(f64::INFINITY)));
+        assert_eq!(
+            (f64::MAX * m * m).checked_add(f64::MAX * m * m),
+            Err(NonFinite(f64::INFINITY))
+        );
+        assert!((0.0 * kg).checked_div(0.0).is_err());
+        assert_eq!(
+            NonFinite(f64::NEG_INFINITY).to_string(),
+            "non-finite quantity: -inf"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "assert-finite")]
+    #[should_panic(expected = "non-finite quantity")]
+    fn assert_finite() {
+        let _ = (1.0 * m) / 0.0;
+    }
+}
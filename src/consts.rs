@@ -0,0 +1,58 @@
+// consts.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Physical constants.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{consts::{C, G}, length::{km, light_second}, mass::kg, time::s};
+//!
+//! assert_eq!(C.to::<km, s>().to_string(), "299792.458 km/s");
+//! assert_eq!(format!("{:.0}", (1.3 * light_second).to::<km>()), "389730 km");
+//! assert_eq!(format!("{:.1}", 75.0 * kg * G), "735.5 kg·m/s²");
+//! ```
+use crate::length::m;
+use crate::pressure::Pa;
+use crate::quan::Quantity;
+use crate::time::s;
+use crate::{Acceleration, Speed};
+
+/// Speed of light in vacuum (_c_)
+pub const C: Speed<m, s> = Speed::new(299_792_458.0);
+
+/// Standard acceleration of gravity (_g_₀)
+pub const G: Acceleration<m, s> = Acceleration::new(9.806_65);
+
+/// Standard atmosphere (`atm`)
+pub const ATM: Quantity<Pa> = Quantity::new_const(101_325.0);
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, km, light_second};
+    use crate::pressure::{atm, kPa};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn speed_of_light() {
+        assert_eq!(C.to_string(), "299792458 m/s");
+        assert_eq!((1.0 * light_second / s).to(), C);
+        assert_eq!(C.to::<km, s>(), 299_792.458 * km / s);
+    }
+
+    #[test]
+    fn gravity() {
+        assert_eq!(G.to_string(), "9.80665 m/s²");
+        assert_eq!(format!("{:.3}", G.to::<ft, s>()), "32.174 ft/s²");
+    }
+
+    #[test]
+    fn atmosphere() {
+        assert_eq!(ATM, (1.0 * atm).to());
+        assert_eq!(ATM.to::<kPa>(), 101.325 * kPa);
+    }
+}
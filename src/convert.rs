@@ -0,0 +1,160 @@
+// convert.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Conversions between units, inferred from the target type
+//!
+//! A blanket `From<Length<U>> for Length<T>` cannot be implemented, since it
+//! would overlap with core's `From<T> for T` when the units are the same.
+//! Instead, the [Convert] trait works like `Into`, calling `to` with the
+//! target units.  It is implemented for all quantity types.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{convert::Convert, length::{ft, m}, Length};
+//!
+//! fn total_feet<Q, I>(legs: I) -> Length<ft>
+//! where
+//!     Q: Convert<Length<ft>>,
+//!     I: IntoIterator<Item = Q>,
+//! {
+//!     legs.into_iter().map(Convert::convert).sum()
+//! }
+//!
+//! let a: Length<ft> = (3.0 * m).convert();
+//!
+//! assert_eq!(format!("{:.3}", a), "9.843 ft");
+//! assert_eq!(total_feet([1.5 * ft, 2.5 * ft]), 4.0 * ft);
+//! assert_eq!(format!("{:.1}", total_feet([1.0 * m, 2.0 * m])), "9.8 ft");
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{Information, Mass, Quantity, Unit};
+use crate::{
+    length, time, Acceleration, Area, Density, FlowRate, Force, Frequency,
+    InverseLength, Length, Period, Speed, Volume,
+};
+
+/// Conversion of a quantity into other units
+///
+/// The target units are inferred from the result type, like `Into`.
+pub trait Convert<T> {
+    /// Convert to the units of `T`
+    fn convert(self) -> T;
+}
+
+/// Implement `Convert` for a quantity struct, using its `to` method
+macro_rules! impl_convert {
+    ($quan:ident<$($from:ident => $to:ident),+> where $($bounds:tt)+) => {
+        impl<$($from,)+ $($to),+> Convert<$quan<$($to),+>>
+            for $quan<$($from),+>
+        where
+            $($bounds)+
+        {
+            fn convert(self) -> $quan<$($to),+> {
+                self.to()
+            }
+        }
+    };
+}
+
+impl_convert!(Length<U => T> where U: length::Unit, T: length::Unit);
+impl_convert!(Area<U => T> where U: length::Unit, T: length::Unit);
+impl_convert!(InverseLength<U => T> where U: length::Unit, T: length::Unit);
+impl_convert!(Volume<U => T> where U: length::Unit, T: length::Unit);
+impl_convert!(Period<U => T> where U: time::Unit, T: time::Unit);
+impl_convert!(Frequency<U => T> where U: time::Unit, T: time::Unit);
+impl_convert!(
+    Speed<L => N, P => R>
+    where
+        L: length::Unit,
+        P: time::Unit,
+        N: length::Unit,
+        R: time::Unit
+);
+impl_convert!(
+    Acceleration<L => N, P => R>
+    where
+        L: length::Unit,
+        P: time::Unit,
+        N: length::Unit,
+        R: time::Unit
+);
+impl_convert!(
+    FlowRate<L => N, P => R>
+    where
+        L: length::Unit,
+        P: time::Unit,
+        N: length::Unit,
+        R: time::Unit
+);
+impl_convert!(
+    Density<M => N, L => R>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        N: Unit<Measure = Mass>,
+        R: length::Unit
+);
+impl_convert!(
+    Force<M => N, L => R, T => S>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        T: time::Unit,
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+        S: time::Unit
+);
+impl_convert!(
+    Bandwidth<D => E, P => R>
+    where
+        D: Unit<Measure = Information>,
+        P: time::Unit,
+        E: Unit<Measure = Information>,
+        R: time::Unit
+);
+impl_convert!(Quantity<U => T> where U: Unit, T: Unit<Measure = U::Measure>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{ft, km, m, mi, In};
+    use crate::mass::{g, kg};
+    use crate::temp::{DegC, DegF};
+    use crate::time::{h, min, s};
+
+    fn to_meters<Q: Convert<Length<m>>>(len: Q) -> Length<m> {
+        len.convert()
+    }
+
+    #[test]
+    fn convert_length() {
+        assert_eq!(to_meters(2.5 * km), 2_500.0 * m);
+        assert_eq!(to_meters(4.0 * m), 4.0 * m);
+        let a: Length<In> = (1.0 * ft).convert();
+        assert_eq!(a, 12.0 * In);
+        let a: Area<m> = (1.0 * km * km).convert();
+        assert_eq!(a, 1_000_000.0 * m * m);
+    }
+
+    #[test]
+    fn convert_derived() {
+        let a: Period<s> = (1.5 * min).convert();
+        assert_eq!(a, 90.0 * s);
+        let a: Speed<m, s> = (36.0 * km / h).convert();
+        assert_eq!(a, 10.0 * m / s);
+        let a: Speed<mi, h> = (60.0 * mi / h).convert();
+        assert_eq!(a, 60.0 * mi / h);
+        let a: Density<g, m> = Density::<kg, m>::new(1.0).convert();
+        assert_eq!(a, Density::new(1_000.0));
+    }
+
+    #[test]
+    fn convert_quantity() {
+        let a: Quantity<g> = (2.0 * kg).convert();
+        assert_eq!(a, 2_000.0 * g);
+        let a: Quantity<DegF> = (100.0 * DegC).convert();
+        assert_eq!(a, 211.99999999999994 * DegF);
+    }
+}
@@ -0,0 +1,413 @@
+// decimal.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Fixed-point decimal quantities
+//!
+//! Some legal and metrology contexts require exact decimal values, which an
+//! `f64` cannot represent.  A [Decimal] stores an `i128` count of `10⁻ᴰ`
+//! units, so addition and subtraction are exact.  Conversions between units
+//! are exact when both unit factors are decimals (such as `0.45359237` for
+//! pounds), with an explicit [Rounding] to the target number of digits.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{decimal::Decimal, mass::{kg, lb}, rounding::Rounding};
+//!
+//! let a: Decimal<kg, 3> = "12.345 kg".parse()?;
+//! let b = Decimal::<kg, 3>::from_units(655);
+//! let total = a + b;
+//!
+//! assert_eq!(total.to_string(), "13.000 kg");
+//! let c = total.to::<lb, 2>(Rounding::Nearest).unwrap();
+//! assert_eq!(c.to_string(), "28.66 lb");
+//! # Ok::<(), mag::quan::ParseError>(())
+//! ```
+use crate::quan::{write_label, Label, ParseError, Quantity, Unit};
+use crate::rounding::Rounding;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+/// Quantity with a fixed-point decimal value
+///
+/// The value is a count of `10⁻ᴰ` units, such as hundredths for `D = 2`.
+/// Arithmetic overflows like `i128`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Decimal<U, const D: u32>
+where
+    U: Unit,
+{
+    /// Count of `10⁻ᴰ` units
+    units: i128,
+
+    /// Unit of measure
+    unit: PhantomData<U>,
+}
+
+/// Get a power of ten, if it fits in an `i128`
+const fn pow10(exp: u32) -> Option<i128> {
+    10i128.checked_pow(exp)
+}
+
+/// Divide, rounding the quotient in a direction
+///
+/// The denominator must be positive.
+fn div_round(num: i128, den: i128, rounding: Rounding) -> i128 {
+    let quot = num / den;
+    let rem = num % den;
+    if rem == 0 {
+        return quot;
+    }
+    let away = match rounding {
+        Rounding::Down => num < 0,
+        Rounding::Up => num > 0,
+        Rounding::TowardZero => false,
+        Rounding::Nearest => rem.abs() >= den - rem.abs(),
+    };
+    if away {
+        quot + num.signum()
+    } else {
+        quot
+    }
+}
+
+/// Greatest common divisor
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<U, const D: u32> Decimal<U, D>
+where
+    U: Unit,
+{
+    /// Create a decimal quantity from a count of `10⁻ᴰ` units
+    pub const fn from_units(units: i128) -> Self {
+        Decimal {
+            units,
+            unit: PhantomData,
+        }
+    }
+
+    /// Get the count of `10⁻ᴰ` units
+    pub const fn units(self) -> i128 {
+        self.units
+    }
+
+    /// Create a decimal quantity from a `Quantity`, rounding to `D` digits
+    ///
+    /// Returns `None` if the value is not finite or does not fit.
+    pub fn from_quantity(q: Quantity<U>, rounding: Rounding) -> Option<Self> {
+        let scaled = q.value() * pow10(D)? as f64;
+        if !scaled.is_finite() || scaled.abs() >= i128::MAX as f64 {
+            return None;
+        }
+        let trunc = scaled as i128;
+        let frac = scaled - trunc as f64;
+        let away = match rounding {
+            Rounding::Down => frac < 0.0,
+            Rounding::Up => frac > 0.0,
+            Rounding::TowardZero => false,
+            Rounding::Nearest => frac.abs() >= 0.5,
+        };
+        let units = if away {
+            trunc + frac.signum() as i128
+        } else {
+            trunc
+        };
+        Some(Self::from_units(units))
+    }
+
+    /// Convert to a `Quantity`, rounding to the nearest `f64`
+    pub fn to_quantity(self) -> Quantity<U> {
+        let scale = pow10(D).map_or(f64::INFINITY, |p| p as f64);
+        Quantity::new(self.units as f64 / scale)
+    }
+
+    /// Convert to other units and digits, with a rounding direction
+    ///
+    /// The conversion is exact before rounding, but only for units with
+    /// decimal factors and no zero offset.  Returns `None` for other units,
+    /// or if the result does not fit.
+    ///
+    /// ```rust
+    /// use mag::{decimal::Decimal, length::{ft, In}, rounding::Rounding};
+    ///
+    /// let a = Decimal::<ft, 0>::from_units(5);
+    /// let b = a.to::<In, 1>(Rounding::TowardZero).unwrap();
+    /// assert_eq!(b.to_string(), "60.0 in");
+    /// ```
+    pub fn to<T, const E: u32>(
+        self,
+        rounding: Rounding,
+    ) -> Option<Decimal<T, E>>
+    where
+        T: Unit<Measure = U::Measure>,
+    {
+        if U::ZERO != 0.0 || T::ZERO != 0.0 {
+            return None;
+        }
+        let (unum, uden) = crate::decimal_ratio(U::FACTOR)?;
+        let (tnum, tden) = crate::decimal_ratio(T::FACTOR)?;
+        let num = i128::from(unum)
+            .checked_mul(i128::from(tden))?
+            .checked_mul(pow10(E)?)?;
+        let den = i128::from(uden)
+            .checked_mul(i128::from(tnum))?
+            .checked_mul(pow10(D)?)?;
+        let g = gcd(num, den);
+        let units = self.units.checked_mul(num / g)?;
+        Some(Decimal::from_units(div_round(units, den / g, rounding)))
+    }
+
+    /// Add another quantity, returning `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::from_units(self.units.checked_add(other.units)?))
+    }
+
+    /// Subtract another quantity, returning `None` on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::from_units(self.units.checked_sub(other.units)?))
+    }
+}
+
+impl<U, const D: u32> fmt::Display for Decimal<U, D>
+where
+    U: Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.units < 0 {
+            f.write_str("-")?;
+        }
+        let units = self.units.unsigned_abs();
+        match pow10(D) {
+            Some(scale) if D > 0 => {
+                let scale = scale.unsigned_abs();
+                let digits = D as usize;
+                write!(f, "{}.{:0digits$}", units / scale, units % scale)?;
+            }
+            _ => write!(f, "{units}")?,
+        }
+        f.write_str(" ")?;
+        write_label::<Quantity<U>>(f)
+    }
+}
+
+impl<U, const D: u32> FromStr for Decimal<U, D>
+where
+    U: Unit,
+{
+    type Err = ParseError;
+
+    /// Parse an exact decimal value and unit label, such as `12.50 kg`
+    ///
+    /// Values with more than `D` fractional digits are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, label) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or(ParseError::Unit)?;
+        if !Quantity::<U>::is_label(label.trim_start()) {
+            return Err(ParseError::Unit);
+        }
+        let (neg, value) = match value.strip_prefix('-') {
+            Some(v) => (true, v),
+            None => (false, value),
+        };
+        let (whole, frac) = value.split_once('.').unwrap_or((value, ""));
+        let digits = frac.len() as u32;
+        let all_digits = |v: &str| v.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty()
+            || digits > D
+            || !all_digits(whole)
+            || !all_digits(frac)
+        {
+            return Err(ParseError::Value);
+        }
+        let mut units: i128 = 0;
+        for b in whole.bytes().chain(frac.bytes()) {
+            units = units
+                .checked_mul(10)
+                .and_then(|u| u.checked_add(i128::from(b - b'0')))
+                .ok_or(ParseError::Value)?;
+        }
+        let scale = pow10(D - digits).ok_or(ParseError::Value)?;
+        let units = units.checked_mul(scale).ok_or(ParseError::Value)?;
+        Ok(Self::from_units(if neg { -units } else { units }))
+    }
+}
+
+// Decimal + Decimal => Decimal
+impl<U, const D: u32> Add for Decimal<U, D>
+where
+    U: Unit,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::from_units(self.units + other.units)
+    }
+}
+
+// Decimal - Decimal => Decimal
+impl<U, const D: u32> Sub for Decimal<U, D>
+where
+    U: Unit,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::from_units(self.units - other.units)
+    }
+}
+
+// -Decimal => Decimal
+impl<U, const D: u32> Neg for Decimal<U, D>
+where
+    U: Unit,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::from_units(-self.units)
+    }
+}
+
+// Decimal += Decimal
+impl<U, const D: u32> AddAssign for Decimal<U, D>
+where
+    U: Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.units += other.units;
+    }
+}
+
+// Decimal -= Decimal
+impl<U, const D: u32> SubAssign for Decimal<U, D>
+where
+    U: Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.units -= other.units;
+    }
+}
+
+// Sum of Decimal => Decimal
+impl<U, const D: u32> Sum for Decimal<U, D>
+where
+    U: Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::from_units(0), |a, b| a + b)
+    }
+}
+
+impl<U, const D: u32> PartialEq<Quantity<U>> for Decimal<U, D>
+where
+    U: Unit,
+{
+    fn eq(&self, other: &Quantity<U>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<U, const D: u32> PartialOrd<Quantity<U>> for Decimal<U, D>
+where
+    U: Unit,
+{
+    fn partial_cmp(&self, other: &Quantity<U>) -> Option<Ordering> {
+        let q = Self::from_units(self.units).to_quantity();
+        q.value().partial_cmp(&other.value())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, km, m, mi, In};
+    use crate::mass::{g, kg, lb};
+    use crate::temp::{DegC, DegF};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn decimal_exact() {
+        let a = Decimal::<kg, 2>::from_units(10);
+        let total: Decimal<kg, 2> = (0..10).map(|_| a).sum();
+        assert_eq!(total, Decimal::from_units(100));
+        assert_eq!(total.to_string(), "1.00 kg");
+        assert_eq!((0.1 * kg + 0.2 * kg).value(), 0.30000000000000004);
+        let b: Decimal<kg, 2> = "0.10 kg".parse().unwrap();
+        let c: Decimal<kg, 2> = "0.2 kg".parse().unwrap();
+        assert_eq!((b + c).to_string(), "0.30 kg");
+        assert_eq!((b - c).to_string(), "-0.10 kg");
+        assert_eq!(b.checked_add(Decimal::from_units(i128::MAX)), None);
+    }
+
+    #[test]
+    fn decimal_convert() {
+        let a = Decimal::<mi, 0>::from_units(1);
+        assert_eq!(a.to::<m, 3>(Rounding::Nearest).unwrap().units(), 1_609_344);
+        assert_eq!(
+            a.to::<km, 1>(Rounding::Down).unwrap().to_string(),
+            "1.6 km"
+        );
+        assert_eq!(a.to::<km, 1>(Rounding::Up).unwrap().to_string(), "1.7 km");
+        let b = Decimal::<In, 2>::from_units(-1_000);
+        let c = b.to::<ft, 2>(Rounding::Nearest).unwrap();
+        assert_eq!(c.to_string(), "-0.83 ft");
+        let c = b.to::<ft, 2>(Rounding::Down).unwrap();
+        assert_eq!(c.to_string(), "-0.84 ft");
+        let c = b.to::<ft, 2>(Rounding::TowardZero).unwrap();
+        assert_eq!(c.to_string(), "-0.83 ft");
+        let d = Decimal::<lb, 3>::from_units(1_000);
+        assert_eq!(
+            d.to::<g, 5>(Rounding::Nearest).unwrap().units(),
+            45_359_237
+        );
+        let t = Decimal::<DegC, 1>::from_units(1);
+        assert_eq!(t.to::<DegF, 1>(Rounding::Nearest), None);
+    }
+
+    #[test]
+    fn decimal_quantity() {
+        let a = Decimal::<kg, 3>::from_quantity(2.5005 * kg, Rounding::Down);
+        assert_eq!(a.unwrap().units(), 2_500);
+        let a = Decimal::<kg, 3>::from_quantity(-2.5005 * kg, Rounding::Down);
+        assert_eq!(a.unwrap().units(), -2_501);
+        let a = Decimal::<kg, 1>::from_quantity(0.25 * kg, Rounding::Nearest);
+        assert_eq!(a.unwrap().units(), 3);
+        #[cfg(not(feature = "assert-finite"))]
+        assert_eq!(
+            Decimal::<kg, 1>::from_quantity(f64::NAN * kg, Rounding::Nearest),
+            None
+        );
+        let b = Decimal::<kg, 2>::from_units(1_234);
+        assert_eq!(b.to_quantity(), 12.34 * kg);
+        assert!(b == 12.34 * kg);
+        assert!(b < 12.35 * kg);
+    }
+
+    #[test]
+    fn decimal_parse() {
+        let p = |s: &str| s.parse::<Decimal<kg, 2>>();
+        assert_eq!(p("12 kg"), Ok(Decimal::from_units(1_200)));
+        assert_eq!(p("-0.05 kg"), Ok(Decimal::from_units(-5)));
+        assert_eq!(p("1.005 kg"), Err(ParseError::Value));
+        assert_eq!(p(".5 kg"), Err(ParseError::Value));
+        assert_eq!(p("1e3 kg"), Err(ParseError::Value));
+        assert_eq!(p("12 lb"), Err(ParseError::Unit));
+        assert_eq!(p("12"), Err(ParseError::Unit));
+        let a: Decimal<kg, 0> = "42 kg".parse().unwrap();
+        assert_eq!(format!("{a}"), "42 kg");
+    }
+}
@@ -0,0 +1,109 @@
+// defmt_ops.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Logging with the `defmt` crate
+//!
+//! This module requires the `defmt` feature.  `defmt::Format` is implemented
+//! for all quantity types, writing the magnitude followed by the unit label.
+//! No allocation or `core::fmt` float formatting is needed on the target;
+//! the magnitude is sent as an `f64` and formatted by the host.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use mag::{length::m, time::s};
+//!
+//! defmt::info!("speed: {}", 2.5 * m / s);
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{Information, Label, Mass, Quantity, Unit};
+use crate::ratio::Ratio;
+use crate::{
+    length, speed, time, Acceleration, Area, Density, FlowRate, Force,
+    Frequency, InverseLength, Length, Period, Speed, Volume,
+};
+use core::fmt;
+use defmt::{Format, Formatter};
+
+/// Adapter to write unit labels to a `defmt` formatter
+struct LabelWriter<'a>(Formatter<'a>);
+
+impl fmt::Write for LabelWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        defmt::write!(self.0, "{=str}", s);
+        Ok(())
+    }
+}
+
+/// Write a quantity to a `defmt` formatter
+fn format_quantity<Q: Label>(q: &Q, fmt: Formatter) {
+    defmt::write!(fmt, "{=f64} ", q.magnitude());
+    // LabelWriter never returns an error
+    let _ = Q::write_label(&mut LabelWriter(fmt));
+}
+
+/// Implement `defmt::Format` for a quantity type
+macro_rules! impl_format {
+    ($quan:ident<$($param:ident),+> where $($bounds:tt)+) => {
+        impl<$($param),+> Format for $quan<$($param),+>
+        where
+            $($bounds)+
+        {
+            fn format(&self, fmt: Formatter) {
+                format_quantity(self, fmt);
+            }
+        }
+    };
+}
+
+impl_format!(Length<U> where U: length::Unit);
+impl_format!(Area<U> where U: length::Unit);
+impl_format!(InverseLength<U> where U: length::Unit);
+impl_format!(Volume<U> where U: length::Unit);
+impl_format!(Period<U> where U: time::Unit);
+impl_format!(Frequency<U> where U: time::Unit);
+impl_format!(Speed<L, P> where (L, P): speed::Units);
+impl_format!(Acceleration<L, P> where L: length::Unit, P: time::Unit);
+impl_format!(FlowRate<L, P> where L: length::Unit, P: time::Unit);
+impl_format!(Density<M, L> where M: Unit<Measure = Mass>, L: length::Unit);
+impl_format!(
+    Force<M, L, T>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        T: time::Unit
+);
+impl_format!(
+    Bandwidth<D, P> where D: Unit<Measure = Information>, P: time::Unit
+);
+impl_format!(Quantity<U> where U: Unit);
+
+impl Format for Ratio {
+    fn format(&self, fmt: Formatter) {
+        defmt::write!(fmt, "{=f64}", self.value());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::information::MiB;
+    use crate::length::{ft, m};
+    use crate::mass::kg;
+    use crate::temp::DegC;
+    use crate::time::s;
+
+    fn assert_format<T: Format>() {}
+
+    #[test]
+    fn format_impls() {
+        assert_format::<Length<m>>();
+        assert_format::<Area<ft>>();
+        assert_format::<Speed<m, s>>();
+        assert_format::<Force<kg, m, s>>();
+        assert_format::<Bandwidth<MiB, s>>();
+        assert_format::<Quantity<DegC>>();
+        assert_format::<Ratio>();
+    }
+}
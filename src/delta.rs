@@ -0,0 +1,104 @@
+// delta.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Signed differences between quantities
+//!
+//! A [Delta] displays a difference with an explicit sign, such as
+//! `+3.2 km/h` or `-0.5 °C`.
+//!
+//! Temperature differences are intervals, not points on a scale, so they must
+//! not be converted using the unit's zero offset.  A difference of 1 °C is a
+//! difference of 1.8 °F, not 33.8 °F.  [Delta::to] converts using only the
+//! unit factor.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::delta::{delta, delta_string};
+//! use mag::temp::{DegC, DegF};
+//! use mag::{length::km, time::h, Speed};
+//!
+//! let before = Speed::<km, h>::new(52.0);
+//! let after = Speed::<km, h>::new(55.2);
+//! assert_eq!(format!("{:.1}", delta(after, before)), "+3.2 km/h");
+//! assert_eq!(delta_string(before, Speed::new(54.0)), "-2 km/h");
+//!
+//! let d = delta(20.0 * DegC, 25.0 * DegC);
+//! assert_eq!(d.to_string(), "-5 °C");
+//! assert_eq!(d.to::<DegF>().to_string(), "-9 °F");
+//! ```
+extern crate alloc;
+
+use crate::quan::{Label, Magnitude, Quantity, Unit};
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// Signed difference between two quantities
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Delta<Q>(pub Q);
+
+/// Get the difference between two quantities (`a - b`)
+pub fn delta<Q: Magnitude>(a: Q, b: Q) -> Delta<Q> {
+    Delta(Q::from_magnitude(a.magnitude() - b.magnitude()))
+}
+
+/// Format the difference between two quantities (`a - b`) with a sign
+pub fn delta_string<Q: Label>(a: Q, b: Q) -> String {
+    delta(a, b).to_string()
+}
+
+impl<U> Delta<Quantity<U>>
+where
+    U: Unit,
+{
+    /// Convert difference to specified units
+    ///
+    /// Only the unit factor is applied, ignoring any zero offset.
+    pub fn to<T>(self) -> Delta<Quantity<T>>
+    where
+        T: Unit<Measure = U::Measure>,
+    {
+        Delta(Quantity::new(self.0.value() * U::FACTOR / T::FACTOR))
+    }
+}
+
+impl<Q> fmt::Display for Delta<Q>
+where
+    Q: Label,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let val = self.0.magnitude();
+        // avoid displaying "-0"
+        let val = if val == 0.0 { 0.0 } else { val };
+        match f.precision() {
+            Some(p) => write!(f, "{val:+.p$} ")?,
+            None => write!(f, "{val:+} ")?,
+        }
+        crate::quan::write_label::<Q>(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::m;
+    use crate::temp::{DegC, DegF, DegK};
+    use alloc::format;
+
+    #[test]
+    fn delta_display() {
+        assert_eq!(delta(5.0 * m, 2.5 * m).to_string(), "+2.5 m");
+        assert_eq!(delta(2.5 * m, 5.0 * m).to_string(), "-2.5 m");
+        assert_eq!(delta(1.0 * m, 1.0 * m).to_string(), "+0 m");
+        assert_eq!(format!("{:.2}", delta(0.5 * m, 0.125 * m)), "+0.38 m");
+    }
+
+    #[test]
+    fn delta_temp() {
+        let d = delta(30.0 * DegC, 10.0 * DegC);
+        assert_eq!(d.to::<DegF>(), Delta(36.0 * DegF));
+        assert_eq!(d.to::<DegK>(), Delta(20.0 * DegK));
+        assert_eq!(delta_string(50.0 * DegF, 59.0 * DegF), "-9 °F");
+    }
+}
@@ -0,0 +1,495 @@
+// density.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Private module for density structs
+//!
+use crate::quan::{
+    fmt_quantity, parse_quantity, Label, Magnitude, Mass, ParseError, Quantity,
+    Scalable, Unit,
+};
+use crate::{length, Volume};
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Quantity of _density_ (mass per volume).
+///
+/// Density is a derived quantity with [mass unit]s and [length unit]s
+/// (cubed).
+///
+/// ## Operations
+///
+/// * Mass `/` [Volume] `=>` Density
+/// * Density `+` Density `=>` Density
+/// * Density `-` Density `=>` Density
+/// * Density `*` f64 `=>` Density
+/// * f64 `*` Density `=>` Density
+/// * Density `*` u32 `=>` Density
+/// * i32 `*` Density `=>` Density
+/// * u32 `*` Density `=>` Density
+/// * Density `*` [Volume] `=>` Mass
+/// * Density `/` f64 `=>` Density
+/// * Density `/` Density `=>` [Ratio]
+/// * Density `+=` Density
+/// * Density `-=` Density
+/// * Density `*=` f64
+/// * Density `/=` f64
+/// * Mass `/` Density `=>` [Volume]
+///
+/// Units must be the same for operations with two Density operands.  The
+/// [to] method can be used for conversion.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::{cm, m}, mass::{g, kg}, Density};
+///
+/// let water = Density::<kg, m>::new(1_000.0);
+/// let gold = 19.3 * g / (1.0 * cm * cm * cm);
+///
+/// assert_eq!(water.to_string(), "1000 kg/m³");
+/// assert_eq!(gold.to_string(), "19.3 g/cm³");
+/// assert_eq!(format!("{:.1}", gold.to::<kg, m>()), "19300.0 kg/m³");
+/// ```
+/// [Volume]: struct.Volume.html
+/// [Ratio]: ratio/struct.Ratio.html
+/// [length unit]: length/index.html
+/// [mass unit]: mass/index.html
+/// [to]: struct.Density.html#method.to
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    /// Density quantity
+    #[deprecated(note = "use the `quantity()` method")]
+    pub quantity: f64,
+
+    /// Mass unit
+    mass: PhantomData<M>,
+
+    /// Length unit
+    length: PhantomData<L>,
+}
+
+// Density + Density => Density
+impl<M, L> Add for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() + other.quantity())
+    }
+}
+
+// Density - Density => Density
+impl<M, L> Sub for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() - other.quantity())
+    }
+}
+
+// Sum of Density => Density
+impl<M, L> Sum for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
+    }
+}
+
+// Density * f64 => Density
+impl<M, L> Mul<f64> for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() * scalar)
+    }
+}
+
+// f64 * Density => Density
+impl<M, L> Mul<Density<M, L>> for f64
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Density<M, L>;
+    fn mul(self, other: Density<M, L>) -> Self::Output {
+        Density::new(self * other.quantity())
+    }
+}
+
+// i32 * Density => Density
+impl<M, L> Mul<Density<M, L>> for i32
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Density<M, L>;
+    fn mul(self, other: Density<M, L>) -> Self::Output {
+        Density::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Density * u32 => Density
+impl<M, L> Mul<u32> for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Self;
+    fn mul(self, count: u32) -> Self::Output {
+        Self::new(self.quantity() * f64::from(count))
+    }
+}
+
+// u32 * Density => Density
+impl<M, L> Mul<Density<M, L>> for u32
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Density<M, L>;
+    fn mul(self, other: Density<M, L>) -> Self::Output {
+        Density::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Density / f64 => Density
+impl<M, L> Div<f64> for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() / scalar)
+    }
+}
+
+// Density += Density
+impl<M, L> AddAssign for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() + other.quantity());
+    }
+}
+
+// Density -= Density
+impl<M, L> SubAssign for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() - other.quantity());
+    }
+}
+
+// Density *= f64
+impl<M, L> MulAssign<f64> for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() * scalar);
+    }
+}
+
+// Density /= f64
+impl<M, L> DivAssign<f64> for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() / scalar);
+    }
+}
+
+// Mass / Volume => Density
+impl<M, L> Div<Volume<L>> for Quantity<M>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Density<M, L>;
+    fn div(self, vol: Volume<L>) -> Self::Output {
+        Density::new(self.value() / vol.quantity())
+    }
+}
+
+// Density * Volume => Mass
+impl<M, L> Mul<Volume<L>> for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Quantity<M>;
+    fn mul(self, vol: Volume<L>) -> Self::Output {
+        Quantity::new(self.quantity() * vol.quantity())
+    }
+}
+
+// Mass / Density => Volume
+impl<M, L> Div<Density<M, L>> for Quantity<M>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Output = Volume<L>;
+    fn div(self, den: Density<M, L>) -> Self::Output {
+        Volume::new(self.value() / den.quantity())
+    }
+}
+
+impl_base_ops!(
+    @access Density<M, L>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit
+);
+
+impl<M, L> Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    /// Create a new density quantity
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
+        Density::<M, L> {
+            quantity: crate::check_finite(quantity),
+            mass: PhantomData,
+            length: PhantomData,
+        }
+    }
+
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.quantity() * count as f64)
+    }
+
+    /// Change the units **without converting** the quantity.
+    ///
+    /// This is an escape hatch for cases where the quantity is already known
+    /// to be in the target units, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<N, R>(self) -> Density<N, R>
+    where
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+    {
+        Density::new(self.quantity())
+    }
+
+    /// Convert to specified units
+    pub const fn to<N, R>(self) -> Density<N, R>
+    where
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+    {
+        let per = <L as length::Factor<R>>::FACTOR;
+        let factor = M::FACTOR / N::FACTOR / (per * per * per);
+        Density::new(self.quantity() * factor)
+    }
+
+    /// Convert to SI base units (`kg/m³`)
+    pub const fn to_base(self) -> Density<crate::mass::kg, length::m> {
+        self.to()
+    }
+}
+
+impl<U> Volume<U>
+where
+    U: length::Unit,
+{
+    /// Calculate the volume of a substance from its mass and density
+    ///
+    /// Units are converted as needed.
+    ///
+    /// ```rust
+    /// use mag::{length::{cm, m}, mass::{kg, t}, Density, Volume};
+    ///
+    /// let diesel = Density::<kg, m>::new(832.0);
+    /// let vol = Volume::<m>::from_mass(20.8 * t, diesel);
+    ///
+    /// assert_eq!(vol, 25.0 * m * m * m);
+    /// ```
+    pub fn from_mass<M, N, R>(mass: Quantity<M>, density: Density<N, R>) -> Self
+    where
+        M: Unit<Measure = Mass>,
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+    {
+        (mass.to::<N>() / density).to()
+    }
+}
+
+impl<M> Quantity<M>
+where
+    M: Unit<Measure = Mass>,
+{
+    /// Calculate the mass of a substance from its volume and density
+    ///
+    /// Units are converted as needed.
+    ///
+    /// ```rust
+    /// use mag::{length::{cm, m}, mass::{g, kg}, quan::Quantity, Density};
+    ///
+    /// let steel = Density::<g, cm>::new(7.85);
+    /// let mass = Quantity::<kg>::from_volume(0.5 * m * m * m, steel);
+    ///
+    /// assert_eq!(format!("{:.1}", mass), "3925.0 kg");
+    /// ```
+    pub fn from_volume<L, N, R>(
+        volume: Volume<L>,
+        density: Density<N, R>,
+    ) -> Self
+    where
+        L: length::Unit,
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+    {
+        (density * volume.to::<R>()).to()
+    }
+}
+
+impl<M, L> Magnitude for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn magnitude(&self) -> f64 {
+        self.quantity()
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<M, L> Scalable for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+}
+
+impl<M, L> Label for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(M::LABEL)?;
+        w.write_char('/')?;
+        w.write_str(L::LABEL)?;
+        w.write_char('³')
+    }
+}
+
+impl<M, L> fmt::Display for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_quantity(self, f)
+    }
+}
+
+impl<M, L> FromStr for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{cm, ft, m, In};
+    use crate::mass::{g, kg, lb, t};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn density_display() {
+        assert_eq!(Density::<kg, m>::new(997.0).to_string(), "997 kg/m³");
+        assert_eq!(Density::<lb, ft>::new(62.4).to_string(), "62.4 lb/ft³");
+        assert_eq!("1.2 g/cm³".parse(), Ok(Density::<g, cm>::new(1.2)));
+    }
+
+    #[test]
+    fn density_to() {
+        let d = Density::<g, cm>::new(1.0).to::<kg, m>();
+        assert_eq!(d, Density::new(999.9999999999999));
+        assert_eq!(
+            format!("{:.2}", Density::<kg, m>::new(1_000.0).to::<lb, ft>()),
+            "62.43 lb/ft³"
+        );
+    }
+
+    #[test]
+    fn density_ops() {
+        let d = 12.0 * kg / (4.0 * m * m * m);
+        assert_eq!(d, Density::new(3.0));
+        assert_eq!(d * (2.0 * m * m * m), 6.0 * kg);
+        assert_eq!(9.0 * kg / d, 3.0 * m * m * m);
+        assert_eq!(d + d, d * 2.0);
+        assert_eq!(d - d / 3.0, 2u32 * d / 3.0);
+        let mut e = d;
+        e += d;
+        e *= 0.5;
+        assert_eq!(e, d);
+    }
+
+    #[test]
+    fn density_convert() {
+        let water = Density::<kg, m>::new(1_000.0);
+        assert_eq!(Volume::<m>::from_mass(2.0 * t, water), 2.0 * m * m * m);
+        assert_eq!(
+            format!("{:.3}", Volume::<In>::from_mass(1.0 * lb, water)),
+            "27.680 in³"
+        );
+        let mass = Quantity::<g>::from_volume(2.0 * cm * cm * cm, water);
+        assert_eq!(format!("{:.3}", mass), "2.000 g");
+    }
+}
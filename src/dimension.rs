@@ -0,0 +1,446 @@
+// dimension.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Dimensional analysis of quantities
+//!
+//! A [Dimension] records the exponents of the base measures (length, mass,
+//! time, temperature and amount) which make up a quantity.  Every quantity
+//! type has a dimension through the [Dimensioned] trait, and dimensions can be
+//! multiplied or divided in `const` context.  This allows checking a formula
+//! at compile time, even when no operator exists for the combination.
+//!
+//! Dimensions are metadata only: they are not used by the quantity operators,
+//! which are still implemented for each supported combination of types.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::dimension::{Dimension, Dimensioned};
+//! use mag::{length::m, mass::kg, quan::Quantity, time::s, Area, Length};
+//! use mag::{Force, Speed};
+//!
+//! type Meters = Length<m>;
+//!
+//! const _: () = assert!(Meters::DIMENSION
+//!     .mul(Meters::DIMENSION)
+//!     .eq(Area::<m>::DIMENSION));
+//!
+//! let momentum = Speed::<m, s>::DIMENSION * Quantity::<kg>::DIMENSION;
+//! assert_eq!(momentum.to_string(), "L·M·T⁻¹");
+//! assert_eq!(momentum / Dimension::TIME, Force::<kg, m, s>::DIMENSION);
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{
+    Amount, Distance, Energy, Information, LandArea, LiquidVolume, Mass,
+    MolarMass, Power, Pressure, Quantity, Temperature, Time, Torque, Unit,
+};
+use crate::ratio::Ratio;
+use crate::{
+    length, speed, time, Acceleration, Area, Density, FlowRate, Force,
+    Frequency, InverseLength, Length, Period, Speed, Volume,
+};
+use core::fmt;
+use core::ops::{Div, Mul};
+
+/// Exponents of base measures
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dimension {
+    /// Length exponent
+    pub length: i8,
+
+    /// Mass exponent
+    pub mass: i8,
+
+    /// Time exponent
+    pub time: i8,
+
+    /// Temperature exponent
+    pub temperature: i8,
+
+    /// Amount of substance exponent
+    pub amount: i8,
+}
+
+/// Quantity type with a known [Dimension]
+pub trait Dimensioned {
+    /// Dimension of the quantity
+    const DIMENSION: Dimension;
+}
+
+/// Unwrap a checked exponent, panicking on overflow
+const fn exponent(exp: Option<i8>) -> i8 {
+    match exp {
+        Some(exp) => exp,
+        None => panic!("dimension exponent overflow"),
+    }
+}
+
+impl Dimension {
+    /// Dimensionless (ratio or count)
+    pub const NONE: Self = Self::new(0, 0, 0, 0);
+
+    /// Length dimension
+    pub const LENGTH: Self = Self::new(1, 0, 0, 0);
+
+    /// Mass dimension
+    pub const MASS: Self = Self::new(0, 1, 0, 0);
+
+    /// Time dimension
+    pub const TIME: Self = Self::new(0, 0, 1, 0);
+
+    /// Temperature dimension
+    pub const TEMPERATURE: Self = Self::new(0, 0, 0, 1);
+
+    /// Amount of substance dimension
+    pub const AMOUNT: Self = Self::NONE.with_amount(1);
+
+    /// Create a new dimension from base exponents
+    ///
+    /// The amount exponent is zero; use [with_amount] to set it.
+    ///
+    /// [with_amount]: #method.with_amount
+    pub const fn new(length: i8, mass: i8, time: i8, temperature: i8) -> Self {
+        Dimension {
+            length,
+            mass,
+            time,
+            temperature,
+            amount: 0,
+        }
+    }
+
+    /// Set the amount of substance exponent
+    pub const fn with_amount(self, amount: i8) -> Self {
+        Dimension { amount, ..self }
+    }
+
+    /// Multiply by another dimension (adding exponents)
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows `i8`.
+    pub const fn mul(self, other: Self) -> Self {
+        Self::new(
+            exponent(self.length.checked_add(other.length)),
+            exponent(self.mass.checked_add(other.mass)),
+            exponent(self.time.checked_add(other.time)),
+            exponent(self.temperature.checked_add(other.temperature)),
+        )
+        .with_amount(exponent(self.amount.checked_add(other.amount)))
+    }
+
+    /// Divide by another dimension (subtracting exponents)
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows `i8`.
+    pub const fn div(self, other: Self) -> Self {
+        Self::new(
+            exponent(self.length.checked_sub(other.length)),
+            exponent(self.mass.checked_sub(other.mass)),
+            exponent(self.time.checked_sub(other.time)),
+            exponent(self.temperature.checked_sub(other.temperature)),
+        )
+        .with_amount(exponent(self.amount.checked_sub(other.amount)))
+    }
+
+    /// Raise to an integer power (multiplying exponents)
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows `i8`.
+    pub const fn powi(self, n: i8) -> Self {
+        Self::new(
+            exponent(self.length.checked_mul(n)),
+            exponent(self.mass.checked_mul(n)),
+            exponent(self.time.checked_mul(n)),
+            exponent(self.temperature.checked_mul(n)),
+        )
+        .with_amount(exponent(self.amount.checked_mul(n)))
+    }
+
+    /// Check whether two dimensions are equal (usable in `const` context)
+    pub const fn eq(self, other: Self) -> bool {
+        self.length == other.length
+            && self.mass == other.mass
+            && self.time == other.time
+            && self.temperature == other.temperature
+            && self.amount == other.amount
+    }
+
+    /// Check whether the dimension is dimensionless
+    pub const fn is_none(self) -> bool {
+        self.eq(Self::NONE)
+    }
+}
+
+impl Mul for Dimension {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        Dimension::mul(self, other)
+    }
+}
+
+impl Div for Dimension {
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        Dimension::div(self, other)
+    }
+}
+
+/// Write a superscript exponent
+fn write_exponent(f: &mut fmt::Formatter, exp: i8) -> fmt::Result {
+    const DIGITS: [char; 10] =
+        ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if exp < 0 {
+        f.write_str("⁻")?;
+    }
+    let exp = exp.unsigned_abs();
+    if exp >= 100 {
+        write!(f, "{}", DIGITS[usize::from(exp / 100)])?;
+    }
+    if exp >= 10 {
+        write!(f, "{}", DIGITS[usize::from(exp / 10 % 10)])?;
+    }
+    write!(f, "{}", DIGITS[usize::from(exp % 10)])
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_none() {
+            return f.write_str("1");
+        }
+        let bases = [
+            ("L", self.length),
+            ("M", self.mass),
+            ("T", self.time),
+            ("Θ", self.temperature),
+            ("N", self.amount),
+        ];
+        let mut first = true;
+        for (sym, exp) in bases {
+            if exp != 0 {
+                if !first {
+                    f.write_str("·")?;
+                }
+                first = false;
+                f.write_str(sym)?;
+                if exp != 1 {
+                    write_exponent(f, exp)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Dimensioned for Distance {
+    const DIMENSION: Dimension = Dimension::LENGTH;
+}
+
+impl Dimensioned for Mass {
+    const DIMENSION: Dimension = Dimension::MASS;
+}
+
+impl Dimensioned for Time {
+    const DIMENSION: Dimension = Dimension::TIME;
+}
+
+impl Dimensioned for Temperature {
+    const DIMENSION: Dimension = Dimension::TEMPERATURE;
+}
+
+impl Dimensioned for LiquidVolume {
+    const DIMENSION: Dimension = Dimension::new(3, 0, 0, 0);
+}
+
+impl Dimensioned for LandArea {
+    const DIMENSION: Dimension = Dimension::new(2, 0, 0, 0);
+}
+
+impl Dimensioned for Energy {
+    const DIMENSION: Dimension = Dimension::new(2, 1, -2, 0);
+}
+
+impl Dimensioned for Power {
+    const DIMENSION: Dimension = Dimension::new(2, 1, -3, 0);
+}
+
+impl Dimensioned for Pressure {
+    const DIMENSION: Dimension = Dimension::new(-1, 1, -2, 0);
+}
+
+impl Dimensioned for Torque {
+    const DIMENSION: Dimension = Dimension::new(2, 1, -2, 0);
+}
+
+impl Dimensioned for Information {
+    const DIMENSION: Dimension = Dimension::NONE;
+}
+
+impl Dimensioned for Amount {
+    const DIMENSION: Dimension = Dimension::AMOUNT;
+}
+
+impl Dimensioned for MolarMass {
+    const DIMENSION: Dimension = Dimension::new(0, 1, 0, 0).with_amount(-1);
+}
+
+impl<U> Dimensioned for Quantity<U>
+where
+    U: Unit,
+    U::Measure: Dimensioned,
+{
+    const DIMENSION: Dimension = U::Measure::DIMENSION;
+}
+
+impl Dimensioned for Ratio {
+    const DIMENSION: Dimension = Dimension::NONE;
+}
+
+impl<U: length::Unit> Dimensioned for Length<U> {
+    const DIMENSION: Dimension = Dimension::LENGTH;
+}
+
+impl<U: length::Unit> Dimensioned for Area<U> {
+    const DIMENSION: Dimension = Dimension::new(2, 0, 0, 0);
+}
+
+impl<U: length::Unit> Dimensioned for InverseLength<U> {
+    const DIMENSION: Dimension = Dimension::new(-1, 0, 0, 0);
+}
+
+impl<U: length::Unit> Dimensioned for Volume<U> {
+    const DIMENSION: Dimension = Dimension::new(3, 0, 0, 0);
+}
+
+impl<U: time::Unit> Dimensioned for Period<U> {
+    const DIMENSION: Dimension = Dimension::TIME;
+}
+
+impl<U: time::Unit> Dimensioned for Frequency<U> {
+    const DIMENSION: Dimension = Dimension::new(0, 0, -1, 0);
+}
+
+impl<L, P> Dimensioned for Speed<L, P>
+where
+    (L, P): speed::Units,
+{
+    const DIMENSION: Dimension = Dimension::new(1, 0, -1, 0);
+}
+
+impl<L, P> Dimensioned for Acceleration<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    const DIMENSION: Dimension = Dimension::new(1, 0, -2, 0);
+}
+
+impl<M, L, P> Dimensioned for Force<M, L, P>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    P: time::Unit,
+{
+    const DIMENSION: Dimension = Dimension::new(1, 1, -2, 0);
+}
+
+impl<M, L> Dimensioned for Density<M, L>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+{
+    const DIMENSION: Dimension = Dimension::new(-3, 1, 0, 0);
+}
+
+impl<L, P> Dimensioned for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    const DIMENSION: Dimension = Dimension::new(3, 0, -1, 0);
+}
+
+impl<D, P> Dimensioned for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    const DIMENSION: Dimension = Dimension::new(0, 0, -1, 0);
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::energy::J;
+    use crate::length::{ft, m};
+    use crate::liquid::L;
+    use crate::mass::kg;
+    use crate::power::W;
+    use crate::pressure::Pa;
+    use crate::temp::DegC;
+    use crate::time::s;
+    use alloc::string::ToString;
+
+    #[test]
+    fn derived() {
+        let len = Length::<m>::DIMENSION;
+        let per = Period::<s>::DIMENSION;
+        assert_eq!(len * len, Area::<ft>::DIMENSION);
+        assert_eq!(len.powi(3), Quantity::<L>::DIMENSION);
+        assert_eq!(len / per, Speed::<m, s>::DIMENSION);
+        assert_eq!(len / per.powi(2), Acceleration::<ft, s>::DIMENSION);
+        assert_eq!(Dimension::NONE / per, Frequency::<s>::DIMENSION);
+        let force = Force::<kg, m, s>::DIMENSION;
+        assert_eq!(force, Quantity::<kg>::DIMENSION * (len / per / per));
+        assert_eq!(force * len, Quantity::<J>::DIMENSION);
+        assert_eq!(Quantity::<J>::DIMENSION / per, Quantity::<W>::DIMENSION);
+        assert_eq!(force / (len * len), Quantity::<Pa>::DIMENSION);
+        assert_eq!(len.powi(3) / per, FlowRate::<m, s>::DIMENSION);
+        assert!((len / len).is_none());
+        let mass = Quantity::<kg>::DIMENSION;
+        let amt = Quantity::<crate::amount::mol>::DIMENSION;
+        assert_eq!(mass / amt, Quantity::<crate::molar::g_mol>::DIMENSION);
+        assert_ne!(amt, Dimension::NONE);
+    }
+
+    #[test]
+    fn overflow() {
+        let max = Dimension::new(127, 0, 0, 0);
+        assert_eq!(max / Dimension::LENGTH, Dimension::new(126, 0, 0, 0));
+        assert_eq!(Dimension::TIME.powi(-128).time, -128);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn overflow_mul() {
+        let _ = Dimension::new(127, 0, 0, 0) * Dimension::LENGTH;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn overflow_div() {
+        let _ = Dimension::new(0, -128, 0, 0) / Dimension::MASS;
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension exponent overflow")]
+    fn overflow_powi() {
+        let _ = Dimension::new(0, 0, 0, 2).powi(64);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Dimension::NONE.to_string(), "1");
+        assert_eq!(Quantity::<DegC>::DIMENSION.to_string(), "Θ");
+        assert_eq!(Quantity::<J>::DIMENSION.to_string(), "L²·M·T⁻²");
+        assert_eq!(Density::<kg, m>::DIMENSION.to_string(), "L⁻³·M");
+        assert_eq!(Dimension::new(0, 0, -12, 100).to_string(), "T⁻¹²·Θ¹⁰⁰");
+        let molar = Dimension::MASS / Dimension::AMOUNT;
+        assert_eq!(molar.to_string(), "M·N⁻¹");
+    }
+}
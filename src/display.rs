@@ -0,0 +1,587 @@
+// display.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Display adapters for quantities
+//!
+//! Quantity `Display` uses core's float formatting, which prints the shortest
+//! decimal string that round-trips to the same `f64`.  That output is exact,
+//! but conversion chains often produce values like `10.763910416709724 ft²`,
+//! where the trailing digits are just floating point error.
+//!
+//! The [Tidy] adapter rounds to 12 significant digits before formatting,
+//! which hides those artifacts while keeping more precision than any real
+//! measurement.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{display::Tidy, length::{ft, m}};
+//!
+//! let a = (1.0 * m * m).to::<ft>();
+//!
+//! assert_eq!(a.to_string(), "10.763910416709724 ft²");
+//! assert_eq!(Tidy(a).to_string(), "10.7639104167 ft²");
+//! assert_eq!(format!("{:.3}", Tidy(a)), "10.764 ft²");
+//! ```
+//!
+//! The [ScaledDisplay] adapter picks the SI prefix which keeps the magnitude
+//! between 1 and 1000, for lengths, masses, periods and frequencies.
+//!
+//! ```rust
+//! use mag::{length::m, time::s};
+//!
+//! assert_eq!((1523.0 * m).display_scaled().to_string(), "1.523 km");
+//! assert_eq!((0.000_4 * s).display_scaled().to_string(), "400 μs");
+//! ```
+//!
+//! The [EngineeringDisplay] adapter keeps the unit, with an exponent which
+//! is a multiple of 3.  It is available for all quantities with the
+//! [Engineering] trait.
+//!
+//! ```rust
+//! use mag::{display::Engineering, length::m, pressure::Pa};
+//!
+//! assert_eq!((12_500.0 * m).engineering().to_string(), "12.5×10³ m");
+//! assert_eq!(format!("{:.2}", (0.000_314 * Pa).engineering()), "310×10⁻⁶ Pa");
+//! assert_eq!(format!("{:#}", (12_500.0 * m).engineering()), "12.5e3 m");
+//! ```
+//!
+//! The [SigFigDisplay] adapter rounds to a number of significant figures,
+//! keeping trailing zeros.  It is available for all quantities with the
+//! [SignificantFigures] trait.
+//!
+//! ```rust
+//! use mag::{display::SignificantFigures, length::m};
+//!
+//! assert_eq!((123.456 * m).format_sigfigs(3).to_string(), "123 m");
+//! assert_eq!((0.001_234_56 * m).format_sigfigs(3).to_string(), "0.00123 m");
+//! assert_eq!((1.5 * m).format_sigfigs(3).to_string(), "1.50 m");
+//! ```
+//!
+//! ## ASCII labels
+//!
+//! The alternate flag (`{:#}`) displays unit labels with only ASCII
+//! characters, for logs or terminals which can't handle symbols like `μ` or
+//! `㎐`.  Unit names can be displayed with [long_form](crate::LongForm).
+//!
+//! ```rust
+//! use mag::{length::{m, um}, temp::DegC, time::s};
+//!
+//! assert_eq!(format!("{:#}", 3.9 * um), "3.9 um");
+//! assert_eq!(format!("{:#.1}", 22.84 * DegC), "22.8 degC");
+//! assert_eq!(format!("{:#}", 9.8 * m / s / s), "9.8 m/s^2");
+//! assert_eq!(format!("{:#}", 2.4 / s), "2.4 Hz");
+//! assert_eq!(format!("{:#}", (2400.0 / s).display_scaled()), "2.4 kHz");
+//! assert_eq!((3 * m).long_form().to_string(), "3 meters");
+//! ```
+use crate::quan::{
+    write_label, AsciiLabel, Label, Magnitude, Mass, Quantity, Unit,
+};
+use crate::{length, time, Frequency, Length, Period};
+use core::fmt::{self, Write};
+
+/// Number of significant digits kept by [Tidy]
+pub const TIDY_DIGITS: usize = 12;
+
+/// Display adapter which hides floating point artifacts
+///
+/// If a precision is specified (`{:.2}`), it is used as normal.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Tidy<Q>(pub Q);
+
+impl<Q> fmt::Display for Tidy<Q>
+where
+    Q: Label,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let val = self.0.magnitude();
+        let val = if f.precision().is_none() && val.is_finite() {
+            round_digits(val, TIDY_DIGITS)
+        } else {
+            val
+        };
+        fmt::Display::fmt(&val, f)?;
+        f.write_str(" ")?;
+        write_label::<Q>(f)
+    }
+}
+
+/// SI prefixes, with scale factors
+const SI_PREFIXES: [(&str, f64); 9] = [
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("", 1.0),
+    ("m", 1e-3),
+    ("μ", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+];
+
+/// Quantity which can be displayed with an SI prefix
+///
+/// This is implemented for [Length], [Period], [Frequency] and mass
+/// [Quantity] types.
+pub trait Prefixed: Magnitude {
+    /// Get the magnitude in base units (`m`, `s`, `㎐` or `g`)
+    fn base_magnitude(&self) -> f64;
+
+    /// Write the base unit label with an SI prefix
+    fn write_prefixed<W: Write>(w: &mut W, prefix: &str) -> fmt::Result;
+}
+
+impl<U: length::Unit> Prefixed for Length<U> {
+    fn base_magnitude(&self) -> f64 {
+        self.quantity() * U::M_FACTOR
+    }
+
+    fn write_prefixed<W: Write>(w: &mut W, prefix: &str) -> fmt::Result {
+        w.write_str(prefix)?;
+        w.write_char('m')
+    }
+}
+
+impl<U: time::Unit> Prefixed for Period<U> {
+    fn base_magnitude(&self) -> f64 {
+        self.quantity() * U::S_FACTOR
+    }
+
+    fn write_prefixed<W: Write>(w: &mut W, prefix: &str) -> fmt::Result {
+        w.write_str(prefix)?;
+        w.write_char('s')
+    }
+}
+
+impl<U: time::Unit> Prefixed for Frequency<U> {
+    fn base_magnitude(&self) -> f64 {
+        self.quantity() / U::S_FACTOR
+    }
+
+    fn write_prefixed<W: Write>(w: &mut W, prefix: &str) -> fmt::Result {
+        // same symbols as frequency labels
+        match prefix {
+            "" => w.write_str("㎐"),
+            "k" => w.write_str("㎑"),
+            "M" => w.write_str("㎒"),
+            "G" => w.write_str("㎓"),
+            _ => {
+                w.write_str(prefix)?;
+                w.write_str("Hz")
+            }
+        }
+    }
+}
+
+impl<U> Prefixed for Quantity<U>
+where
+    U: Unit<Measure = Mass>,
+{
+    fn base_magnitude(&self) -> f64 {
+        self.value() * U::FACTOR
+    }
+
+    fn write_prefixed<W: Write>(w: &mut W, prefix: &str) -> fmt::Result {
+        w.write_str(prefix)?;
+        w.write_char('g')
+    }
+}
+
+/// Display adapter which scales to the best SI prefix
+///
+/// The prefix is chosen to keep the magnitude between 1 and 1000, if
+/// possible.  If a precision is specified (`{:.2}`), it is used as normal;
+/// otherwise the value is rounded like [Tidy].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct ScaledDisplay<Q>(pub Q);
+
+impl<Q> fmt::Display for ScaledDisplay<Q>
+where
+    Q: Prefixed,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let base = self.0.base_magnitude();
+        let mag = if base < 0.0 { -base } else { base };
+        let (prefix, factor) = SI_PREFIXES
+            .iter()
+            .find(|(_, factor)| mag >= *factor * (1.0 - 1e-12))
+            .copied()
+            .unwrap_or(match mag {
+                0.0 => ("", 1.0),
+                _ => SI_PREFIXES[SI_PREFIXES.len() - 1],
+            });
+        let val = base / factor;
+        let val = if f.precision().is_none() && val.is_finite() {
+            round_digits(val, TIDY_DIGITS)
+        } else {
+            val
+        };
+        fmt::Display::fmt(&val, f)?;
+        f.write_str(" ")?;
+        if f.alternate() {
+            Q::write_prefixed(&mut AsciiLabel(f), prefix)
+        } else {
+            Q::write_prefixed(f, prefix)
+        }
+    }
+}
+
+/// Display adapter for engineering notation
+///
+/// The exponent is a multiple of 3, so the magnitude is between 1 and 1000.
+/// Unlike [ScaledDisplay], the unit is not changed.  If a precision is
+/// specified (`{:.3}`), it is the number of significant digits; otherwise
+/// the value is rounded like [Tidy].  The alternate flag (`{:#}`) writes the
+/// exponent as `e3` instead of `×10³`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct EngineeringDisplay<Q>(pub Q);
+
+impl<Q> fmt::Display for EngineeringDisplay<Q>
+where
+    Q: Label,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let val = self.0.magnitude();
+        if !val.is_finite() {
+            fmt::Display::fmt(&val, f)?;
+            f.write_str(" ")?;
+            return write_label::<Q>(f);
+        }
+        let mut buf = StackBuf::default();
+        match f.precision() {
+            Some(digits) => {
+                write!(buf, "{:.*e}", digits.clamp(1, 17) - 1, val)?
+            }
+            None => write!(buf, "{:e}", round_digits(val, TIDY_DIGITS))?,
+        }
+        let (mant, exp) = buf.as_str().split_once('e').ok_or(fmt::Error)?;
+        let exp: i32 = exp.parse().map_err(|_| fmt::Error)?;
+        let shift = exp.rem_euclid(3);
+        if f.sign_plus() && val >= 0.0 {
+            f.write_char('+')?;
+        }
+        write_shifted(f, mant, shift as usize)?;
+        let exp = exp - shift;
+        if exp != 0 {
+            if f.alternate() {
+                write!(f, "e{exp}")?;
+            } else {
+                f.write_str("×10")?;
+                write_superscript(f, exp)?;
+            }
+        }
+        f.write_str(" ")?;
+        write_label::<Q>(f)
+    }
+}
+
+/// Write a mantissa (`d.ddd`) with the decimal point shifted right
+fn write_shifted(
+    f: &mut fmt::Formatter,
+    mant: &str,
+    shift: usize,
+) -> fmt::Result {
+    let (int, frac) = mant.split_once('.').unwrap_or((mant, ""));
+    let moved = shift.min(frac.len());
+    f.write_str(int)?;
+    f.write_str(&frac[..moved])?;
+    for _ in moved..shift {
+        f.write_char('0')?;
+    }
+    let rest = &frac[moved..];
+    if !rest.is_empty() {
+        f.write_char('.')?;
+        f.write_str(rest)?;
+    }
+    Ok(())
+}
+
+/// Write an exponent with superscript digits
+fn write_superscript(f: &mut fmt::Formatter, exp: i32) -> fmt::Result {
+    const DIGITS: [char; 10] =
+        ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if exp < 0 {
+        f.write_char('⁻')?;
+    }
+    let mut buf = StackBuf::default();
+    write!(buf, "{}", exp.unsigned_abs())?;
+    for d in buf.as_str().bytes() {
+        f.write_char(DIGITS[usize::from(d - b'0')])?;
+    }
+    Ok(())
+}
+
+/// Quantity which can be displayed in engineering notation
+///
+/// This is implemented for all quantity types.
+pub trait Engineering: Label + Sized {
+    /// Display in engineering notation
+    fn engineering(self) -> EngineeringDisplay<Self> {
+        EngineeringDisplay(self)
+    }
+}
+
+impl<Q> Engineering for Q where Q: Label {}
+
+/// Display adapter which rounds to significant figures
+///
+/// The value is written in positional notation, with trailing zeros kept to
+/// show the number of figures, such as `2.50 kg`.  Zeros before the decimal
+/// point in large values are ambiguous: `123000 m` has 3 figures if created
+/// with `format_sigfigs(3)`.  The number of figures is clamped to `1..=17`.
+///
+/// Created by [format_sigfigs](SignificantFigures::format_sigfigs).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct SigFigDisplay<Q> {
+    /// Quantity to display
+    quantity: Q,
+
+    /// Number of significant figures
+    digits: usize,
+}
+
+impl<Q> fmt::Display for SigFigDisplay<Q>
+where
+    Q: Label,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let val = self.quantity.magnitude();
+        if !val.is_finite() {
+            fmt::Display::fmt(&val, f)?;
+            f.write_str(" ")?;
+            return write_label::<Q>(f);
+        }
+        let digits = self.digits.clamp(1, 17);
+        let mut buf = StackBuf::default();
+        // scientific notation rounds to significant digits
+        write!(buf, "{:.*e}", digits - 1, val)?;
+        let exp = buf.as_str().split_once('e').ok_or(fmt::Error)?.1;
+        let exp: i32 = exp.parse().map_err(|_| fmt::Error)?;
+        let val: f64 = buf.as_str().parse().map_err(|_| fmt::Error)?;
+        let decimals = (digits as i32 - 1 - exp).max(0) as usize;
+        if f.sign_plus() && val >= 0.0 {
+            f.write_char('+')?;
+        }
+        write!(f, "{val:.decimals$} ")?;
+        write_label::<Q>(f)
+    }
+}
+
+/// Quantity which can be displayed with significant figures
+///
+/// This is implemented for all quantity types.
+pub trait SignificantFigures: Label + Sized {
+    /// Display rounded to a number of significant figures
+    fn format_sigfigs(self, digits: usize) -> SigFigDisplay<Self> {
+        SigFigDisplay {
+            quantity: self,
+            digits,
+        }
+    }
+}
+
+impl<Q> SignificantFigures for Q where Q: Label {}
+
+impl<U: length::Unit> Length<U> {
+    /// Display with an automatically scaled SI prefix
+    pub fn display_scaled(self) -> ScaledDisplay<Self> {
+        ScaledDisplay(self)
+    }
+}
+
+impl<U: time::Unit> Period<U> {
+    /// Display with an automatically scaled SI prefix
+    pub fn display_scaled(self) -> ScaledDisplay<Self> {
+        ScaledDisplay(self)
+    }
+}
+
+impl<U: time::Unit> Frequency<U> {
+    /// Display with an automatically scaled SI prefix
+    pub fn display_scaled(self) -> ScaledDisplay<Self> {
+        ScaledDisplay(self)
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: Unit<Measure = Mass>,
+{
+    /// Display with an automatically scaled SI prefix
+    pub fn display_scaled(self) -> ScaledDisplay<Self> {
+        ScaledDisplay(self)
+    }
+}
+
+/// Round a finite value to a number of significant digits
+fn round_digits(val: f64, digits: usize) -> f64 {
+    let mut buf = StackBuf::default();
+    // scientific notation rounds to significant digits
+    match write!(buf, "{:.*e}", digits - 1, val) {
+        Ok(()) => buf.as_str().parse().unwrap_or(val),
+        Err(_) => val,
+    }
+}
+
+/// Small buffer for formatting numbers without allocating
+#[derive(Default)]
+pub(crate) struct StackBuf {
+    /// Buffer bytes
+    buf: [u8; 32],
+
+    /// Length of valid bytes
+    len: usize,
+}
+
+impl StackBuf {
+    /// Get buffer contents as a string slice
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::delta::Delta;
+    use crate::length::{ft, km, m, mi, nm};
+    use crate::mass::{g, kg, lb, t, ug};
+    use crate::temp::{DegC, DegF};
+    use crate::time::{h, ms, s, us};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn tidy() {
+        let speed = (88.0 * ft / s).to::<mi, h>();
+        assert_eq!(speed.to_string(), "59.99999999999999 mi/h");
+        assert_eq!(Tidy(speed).to_string(), "60 mi/h");
+        let temp = (0.0 * DegC).to::<DegF>();
+        assert_eq!(Tidy(temp).to_string(), "32 °F");
+        assert_eq!(Tidy(0.1 * m + 0.2 * m).to_string(), "0.3 m");
+    }
+
+    #[test]
+    fn scaled() {
+        assert_eq!((0.25 * km).display_scaled().to_string(), "250 m");
+        assert_eq!((3.0 * nm).display_scaled().to_string(), "3 nm");
+        assert_eq!((-1500.0 * mi).display_scaled().to_string(), "-2.414016 Mm");
+        assert_eq!((1.0 * ft).display_scaled().to_string(), "304.8 mm");
+        assert_eq!((0.0 * m).display_scaled().to_string(), "0 m");
+        assert_eq!((2.5 * kg).display_scaled().to_string(), "2.5 kg");
+        assert_eq!((3 * t).display_scaled().to_string(), "3 Mg");
+        assert_eq!((0.001 * g).display_scaled().to_string(), "1 mg");
+        assert_eq!(format!("{:.2}", (1 * lb).display_scaled()), "453.59 g");
+        assert_eq!((1.0 * h).display_scaled().to_string(), "3.6 ks");
+        assert_eq!(
+            (999.999_999_999_999_9 * ms).display_scaled().to_string(),
+            "1 s"
+        );
+        assert_eq!((1e-15 * s).display_scaled().to_string(), "0.001 ps");
+    }
+
+    #[test]
+    fn scaled_frequency() {
+        assert_eq!((2_400.0 / s).display_scaled().to_string(), "2.4 ㎑");
+        assert_eq!((2.4e9 / s).display_scaled().to_string(), "2.4 ㎓");
+        assert_eq!((0.5 / s).display_scaled().to_string(), "500 mHz");
+        assert_eq!(
+            (60.0 / h).display_scaled().to_string(),
+            "16.6666666667 mHz"
+        );
+    }
+
+    #[test]
+    fn engineering() {
+        assert_eq!((12_500.0 * m).engineering().to_string(), "12.5×10³ m");
+        assert_eq!((1.5 * m).engineering().to_string(), "1.5 m");
+        assert_eq!((0.0 * m).engineering().to_string(), "0 m");
+        assert_eq!((-47_000.0 * g).engineering().to_string(), "-47×10³ g");
+        assert_eq!((0.000_2 * s).engineering().to_string(), "200×10⁻⁶ s");
+        assert_eq!((1e300 * km).engineering().to_string(), "1×10³⁰⁰ km");
+        let a = (88.0 * ft / s).to::<mi, h>();
+        assert_eq!(a.engineering().to_string(), "60 mi/h");
+    }
+
+    #[test]
+    fn engineering_digits() {
+        let a = 123_456.0 * m;
+        assert_eq!(format!("{:.1}", a.engineering()), "100×10³ m");
+        assert_eq!(format!("{:.2}", a.engineering()), "120×10³ m");
+        assert_eq!(format!("{:.4}", a.engineering()), "123.5×10³ m");
+        assert_eq!(
+            format!("{:.3}", (999_900.0 * m).engineering()),
+            "1.00×10⁶ m"
+        );
+        assert_eq!(format!("{:+.3}", (2.0 * kg).engineering()), "+2.00 kg");
+        assert_eq!(format!("{:#.3}", (0.001_5 * s).engineering()), "1.50e-3 s");
+        assert_eq!(format!("{:#}", (4.2e-9 * s).engineering()), "4.2e-9 s");
+    }
+
+    #[test]
+    fn sigfigs() {
+        let a = 123.456 * m;
+        assert_eq!(a.format_sigfigs(3).to_string(), "123 m");
+        assert_eq!(a.format_sigfigs(1).to_string(), "100 m");
+        assert_eq!(a.format_sigfigs(5).to_string(), "123.46 m");
+        assert_eq!(
+            (0.001_234_56 * m).format_sigfigs(3).to_string(),
+            "0.00123 m"
+        );
+        assert_eq!((2.5 * kg).format_sigfigs(3).to_string(), "2.50 kg");
+        assert_eq!((-99.96 * s).format_sigfigs(3).to_string(), "-100 s");
+        assert_eq!((0.0 * m).format_sigfigs(2).to_string(), "0.0 m");
+        assert_eq!((0.5 * m).format_sigfigs(0).to_string(), "0.5 m");
+        assert_eq!(format!("{:+}", (3.0 * m).format_sigfigs(2)), "+3.0 m");
+        assert_eq!(format!("{:#}", (3.0 * ug).format_sigfigs(2)), "3.0 ug");
+        let speed = (88.0 * ft / s).to::<mi, h>();
+        assert_eq!(speed.format_sigfigs(4).to_string(), "60.00 mi/h");
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(format!("{:#}", 1.5 * m * m), "1.5 m^2");
+        assert_eq!(format!("{:#}", 2.0 * ft * ft * ft), "2 ft^3");
+        assert_eq!(
+            format!("{:#}", crate::Force::<kg, m, s>::new(5.0)),
+            "5 kg*m/s^2"
+        );
+        assert_eq!(format!("{:#}", Tidy(0.1 * us + 0.2 * us)), "0.3 us");
+        assert_eq!(format!("{:#}", (0.000_4 * s).display_scaled()), "400 us");
+        assert_eq!(format!("{:#}", Delta(5.0 * DegF)), "+5 degF");
+        assert_eq!(format!("{:#>6}", 3 * g), "#####3 g");
+    }
+
+    #[test]
+    fn ascii_parse() {
+        assert_eq!("9.8 m/s^2".parse(), Ok(9.8 * m / s / s));
+        assert_eq!("3.9 ug".parse(), Ok(3.9 * ug));
+        assert_eq!("3.9 μg".parse(), Ok(3.9 * ug));
+        assert_eq!("20 degC".parse(), Ok(20 * DegC));
+        assert_eq!("50 Hz".parse(), Ok(50.0 / s));
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(round_digits(123.456_789_012_345, 12), 123.456_789_012);
+        assert_eq!(
+            round_digits(-0.000_123_456_789_012_9, 12),
+            -0.000_123_456_789_013
+        );
+        assert_eq!(round_digits(2.5e-300, 12), 2.5e-300);
+    }
+}
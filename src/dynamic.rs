@@ -0,0 +1,457 @@
+// dynamic.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Quantities with units chosen at runtime
+//!
+//! Static quantity types are checked at compile time, but some applications
+//! (such as a GUI with a unit picker) need to choose units at runtime.  A
+//! [DynQuantity] carries its [DynUnit] as data, and converts automatically
+//! when combined with quantities of the same kind in other units.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::dynamic::{DynQuantity, DynUnit};
+//! use mag::length::{ft, m};
+//! use mag::Length;
+//!
+//! // units selected by the user
+//! let choices = [DynUnit::of::<Length<m>>(), DynUnit::of::<Length<ft>>()];
+//!
+//! let a = DynQuantity::new(2.0, choices[0]);
+//! let b = DynQuantity::from(10.0 * ft);
+//! let sum = a.try_add(b)?;
+//!
+//! assert_eq!(sum.to_string(), "5.048 m");
+//! assert_eq!(sum.to(choices[1])?.to_string(), "16.561679790026247 ft");
+//! assert_eq!(sum.to_typed::<Length<m>>()?, 5.048 * m);
+//! # Ok::<(), mag::dynamic::Incompatible>(())
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{self, Label, Quantity};
+use crate::{length, speed, time};
+use crate::{
+    Acceleration, Area, Density, FlowRate, Force, Frequency, InverseLength,
+    Length, Period, Speed, Volume,
+};
+use core::any::TypeId;
+use core::fmt;
+use core::ops::{Div, Mul};
+
+/// Quantity type which can be used with a [DynUnit]
+///
+/// This is implemented for all quantity types.
+pub trait Dynamic: Label + 'static {
+    /// Base quantity type, identifying the kind of quantity
+    type Base: 'static;
+
+    /// Factor to convert to base units
+    fn base_factor() -> f64;
+
+    /// Zero point, in this quantity's units
+    fn zero() -> f64 {
+        0.0
+    }
+
+    /// Check if another quantity of the same kind is approximately equal
+    ///
+    /// The quantities are compared in base units, so they can have different
+    /// units.  The tolerance is a difference, which can also be in any unit
+    /// of the same kind.
+    ///
+    /// ```rust
+    /// use mag::{dynamic::Dynamic, length::{ft, mi, In}, time::{h, s}};
+    ///
+    /// assert!((1.0 * ft).approx_eq(12.0 * In, 1e-9 * ft));
+    /// assert!(!(1.0 * ft).approx_eq(12.1 * In, 1e-3 * ft));
+    /// let v = 36.0 * ft / s;
+    /// assert!(v.approx_eq(24.545454 * mi / h, 0.001 * ft / s));
+    /// ```
+    fn approx_eq<Q, T>(&self, other: Q, tol: T) -> bool
+    where
+        Q: Dynamic<Base = Self::Base>,
+        T: Dynamic<Base = Self::Base>,
+    {
+        let a = (self.magnitude() - Self::zero()) * Self::base_factor();
+        let b = (other.magnitude() - Q::zero()) * Q::base_factor();
+        (a - b).abs() <= tol.magnitude().abs() * T::base_factor()
+    }
+}
+
+impl<U> Dynamic for Length<U>
+where
+    U: length::Unit + 'static,
+{
+    type Base = quan::Distance;
+    fn base_factor() -> f64 {
+        U::M_FACTOR
+    }
+}
+
+impl<U> Dynamic for Area<U>
+where
+    U: length::Unit + 'static,
+{
+    type Base = Area<length::m>;
+    fn base_factor() -> f64 {
+        U::M_FACTOR * U::M_FACTOR
+    }
+}
+
+impl<U> Dynamic for InverseLength<U>
+where
+    U: length::Unit + 'static,
+{
+    type Base = InverseLength<length::m>;
+    fn base_factor() -> f64 {
+        1.0 / U::M_FACTOR
+    }
+}
+
+impl<U> Dynamic for Volume<U>
+where
+    U: length::Unit + 'static,
+{
+    type Base = Volume<length::m>;
+    fn base_factor() -> f64 {
+        U::M_FACTOR * U::M_FACTOR * U::M_FACTOR
+    }
+}
+
+impl<U> Dynamic for Period<U>
+where
+    U: time::Unit + 'static,
+{
+    type Base = quan::Time;
+    fn base_factor() -> f64 {
+        U::S_FACTOR
+    }
+}
+
+impl<U> Dynamic for Frequency<U>
+where
+    U: time::Unit + 'static,
+{
+    type Base = Frequency<time::s>;
+    fn base_factor() -> f64 {
+        1.0 / U::S_FACTOR
+    }
+}
+
+impl<L, P> Dynamic for Speed<L, P>
+where
+    L: 'static,
+    P: 'static,
+    (L, P): speed::Units,
+{
+    type Base = Speed<length::m, time::s>;
+    fn base_factor() -> f64 {
+        use length::Unit as _;
+        use time::Unit as _;
+        <(L, P) as speed::Units>::Length::M_FACTOR
+            / <(L, P) as speed::Units>::Time::S_FACTOR
+    }
+}
+
+impl<L, P> Dynamic for Acceleration<L, P>
+where
+    L: length::Unit + 'static,
+    P: time::Unit + 'static,
+{
+    type Base = Acceleration<length::m, time::s>;
+    fn base_factor() -> f64 {
+        L::M_FACTOR / (P::S_FACTOR * P::S_FACTOR)
+    }
+}
+
+impl<M, L, P> Dynamic for Force<M, L, P>
+where
+    M: quan::Unit<Measure = quan::Mass> + 'static,
+    L: length::Unit + 'static,
+    P: time::Unit + 'static,
+{
+    type Base = Force<crate::mass::g, length::m, time::s>;
+    fn base_factor() -> f64 {
+        M::FACTOR * L::M_FACTOR / (P::S_FACTOR * P::S_FACTOR)
+    }
+}
+
+impl<M, L> Dynamic for Density<M, L>
+where
+    M: quan::Unit<Measure = quan::Mass> + 'static,
+    L: length::Unit + 'static,
+{
+    type Base = Density<crate::mass::g, length::m>;
+    fn base_factor() -> f64 {
+        M::FACTOR / (L::M_FACTOR * L::M_FACTOR * L::M_FACTOR)
+    }
+}
+
+impl<L, P> Dynamic for FlowRate<L, P>
+where
+    L: length::Unit + 'static,
+    P: time::Unit + 'static,
+{
+    type Base = FlowRate<length::m, time::s>;
+    fn base_factor() -> f64 {
+        L::M_FACTOR * L::M_FACTOR * L::M_FACTOR / P::S_FACTOR
+    }
+}
+
+impl<D, P> Dynamic for Bandwidth<D, P>
+where
+    D: quan::Unit<Measure = quan::Information> + 'static,
+    P: time::Unit + 'static,
+{
+    type Base = Bandwidth<crate::information::B, time::s>;
+    fn base_factor() -> f64 {
+        D::FACTOR / P::S_FACTOR
+    }
+}
+
+impl<U> Dynamic for Quantity<U>
+where
+    U: quan::Unit + 'static,
+    U::Measure: 'static,
+{
+    type Base = U::Measure;
+    fn base_factor() -> f64 {
+        U::FACTOR
+    }
+    fn zero() -> f64 {
+        U::ZERO
+    }
+}
+
+/// Error for an operation on quantities of different kinds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Incompatible;
+
+impl fmt::Display for Incompatible {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "incompatible units")
+    }
+}
+
+impl core::error::Error for Incompatible {}
+
+/// Unit selected at runtime
+///
+/// Units are equal if they are the same kind, with the same conversion
+/// factor and zero point.
+#[derive(Clone, Copy, Debug)]
+pub struct DynUnit {
+    /// Kind of quantity
+    kind: TypeId,
+
+    /// Factor to convert to base units
+    factor: f64,
+
+    /// Zero point
+    zero: f64,
+
+    /// Label writer
+    label: fn(&mut dyn fmt::Write) -> fmt::Result,
+}
+
+impl PartialEq for DynUnit {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.factor == other.factor
+            && self.zero == other.zero
+    }
+}
+
+/// Write label of a quantity type
+fn write_label<Q: Label>(w: &mut dyn fmt::Write) -> fmt::Result {
+    Q::write_label(&mut &mut *w)
+}
+
+impl DynUnit {
+    /// Get the unit of a static quantity type
+    pub fn of<Q: Dynamic>() -> Self {
+        DynUnit {
+            kind: TypeId::of::<Q::Base>(),
+            factor: Q::base_factor(),
+            zero: Q::zero(),
+            label: write_label::<Q>,
+        }
+    }
+
+    /// Check whether another unit is the same kind of quantity
+    pub fn is_compatible(&self, other: DynUnit) -> bool {
+        self.kind == other.kind
+    }
+
+    /// Get the factor and offset to convert values to another unit
+    #[cfg(feature = "audit")]
+    pub(crate) fn linear_to(&self, to: DynUnit) -> (f64, f64) {
+        let factor = self.factor / to.factor;
+        (factor, to.zero - self.zero * factor)
+    }
+
+    /// Convert a value from this unit to another
+    fn convert(&self, value: f64, to: DynUnit) -> Result<f64, Incompatible> {
+        if self.is_compatible(to) {
+            let base = (value - self.zero) * self.factor;
+            Ok(base / to.factor + to.zero)
+        } else {
+            Err(Incompatible)
+        }
+    }
+}
+
+impl fmt::Display for DynUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.label)(f)
+    }
+}
+
+/// Quantity with a unit chosen at runtime
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynQuantity {
+    /// Quantity value
+    value: f64,
+
+    /// Measurement unit
+    unit: DynUnit,
+}
+
+impl<Q> From<Q> for DynQuantity
+where
+    Q: Dynamic,
+{
+    fn from(q: Q) -> Self {
+        DynQuantity::new(q.magnitude(), DynUnit::of::<Q>())
+    }
+}
+
+impl fmt::Display for DynQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        f.write_str(" ")?;
+        (self.unit.label)(f)
+    }
+}
+
+impl DynQuantity {
+    /// Create a new dynamic quantity
+    pub fn new(value: f64, unit: DynUnit) -> Self {
+        DynQuantity {
+            value: crate::check_finite(value),
+            unit,
+        }
+    }
+
+    /// Get the value, in the current unit
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the unit
+    pub fn unit(&self) -> DynUnit {
+        self.unit
+    }
+
+    /// Convert to another unit
+    pub fn to(self, unit: DynUnit) -> Result<Self, Incompatible> {
+        Ok(DynQuantity::new(self.unit.convert(self.value, unit)?, unit))
+    }
+
+    /// Convert to a static quantity type
+    pub fn to_typed<Q: Dynamic>(self) -> Result<Q, Incompatible> {
+        let value = self.unit.convert(self.value, DynUnit::of::<Q>())?;
+        Ok(Q::from_magnitude(value))
+    }
+
+    /// Add another quantity, converting to this unit
+    pub fn try_add(self, other: Self) -> Result<Self, Incompatible> {
+        let value = other.unit.convert(other.value, self.unit)?;
+        Ok(DynQuantity::new(self.value + value, self.unit))
+    }
+
+    /// Subtract another quantity, converting to this unit
+    pub fn try_sub(self, other: Self) -> Result<Self, Incompatible> {
+        let value = other.unit.convert(other.value, self.unit)?;
+        Ok(DynQuantity::new(self.value - value, self.unit))
+    }
+}
+
+// DynQuantity * f64 => DynQuantity
+impl Mul<f64> for DynQuantity {
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        DynQuantity::new(self.value * scalar, self.unit)
+    }
+}
+
+// DynQuantity / f64 => DynQuantity
+impl Div<f64> for DynQuantity {
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        DynQuantity::new(self.value / scalar, self.unit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, km, m, mi};
+    use crate::mass::{kg, lb};
+    use crate::temp::{DegC, DegF};
+    use crate::time::{h, s};
+    use alloc::string::ToString;
+
+    #[test]
+    fn dyn_unit() {
+        let meter = DynUnit::of::<Length<m>>();
+        assert_eq!(meter.to_string(), "m");
+        assert_eq!(DynUnit::of::<Area<ft>>().to_string(), "ft²");
+        assert_eq!(DynUnit::of::<Speed<km, h>>().to_string(), "km/h");
+        assert!(meter.is_compatible(DynUnit::of::<Length<mi>>()));
+        assert!(!meter.is_compatible(DynUnit::of::<Area<m>>()));
+        assert!(!meter.is_compatible(DynUnit::of::<Period<s>>()));
+        assert_ne!(meter, DynUnit::of::<Length<ft>>());
+        assert_eq!(meter, DynUnit::of::<Quantity<m>>());
+        assert!(DynUnit::of::<Period<h>>()
+            .is_compatible(DynUnit::of::<Quantity<s>>()));
+    }
+
+    #[test]
+    fn dyn_convert() {
+        let a = DynQuantity::from(2.0 * lb);
+        let b = a.to(DynUnit::of::<Quantity<kg>>()).unwrap();
+        assert_eq!(b.to_string(), "0.90718474 kg");
+        assert_eq!(a.to_typed::<Quantity<kg>>(), Ok(0.90718474 * kg));
+        assert_eq!(a.to_typed::<Length<m>>(), Err(Incompatible));
+        let t = DynQuantity::from(100.0 * DegC);
+        assert_eq!(t.to_typed(), Ok(211.99999999999994 * DegF));
+        let v = DynQuantity::from(Speed::<mi, h>::new(60.0));
+        assert_eq!(v.to_typed(), Ok(Speed::<km, h>::new(96.56063999999999)));
+    }
+
+    #[test]
+    fn dyn_ops() {
+        let a = DynQuantity::from(1.0 * km);
+        let b = DynQuantity::from(500.0 * m);
+        assert_eq!(a.try_add(b).unwrap().to_string(), "1.5 km");
+        assert_eq!(b.try_sub(a).unwrap().to_string(), "-500 m");
+        assert_eq!(a.try_add(DynQuantity::from(1.0 * s)), Err(Incompatible));
+        assert_eq!((a * 3.0).to_string(), "3 km");
+        assert_eq!((b / 4.0).value, 125.0);
+    }
+
+    #[test]
+    fn dyn_approx_eq() {
+        assert!((1.0 * mi).approx_eq(1.609344 * km, 1e-12 * mi));
+        assert!(!(1.0 * mi).approx_eq(1.6 * km, 1.0 * ft));
+        assert!((1.0 * mi).approx_eq(1.6 * km, 10.0 * m));
+        assert!((0.0 * DegC).approx_eq(32.0 * DegF, 1e-9 * DegC));
+        assert!((100.0 * DegC).approx_eq(211.9 * DegF, 0.1 * DegC));
+        assert!(!(100.0 * DegC).approx_eq(211.7 * DegF, 0.1 * DegC));
+        assert!((1.0 * kg).approx_eq(2.2046 * lb, 0.001 * kg));
+    }
+}
@@ -0,0 +1,136 @@
+// energy.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of energy.
+//!
+//! Each unit is defined relative to joules with a conversion factor.  They
+//! can be used to conveniently create Energy quantities.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::energy::{kcal, kJ, BTU, J};
+//!
+//! let a = 250.0 * kcal;
+//! let b = 3 * BTU;
+//!
+//! assert_eq!(a.to_string(), "250 kcal");
+//! assert_eq!(b.to_string(), "3 BTU");
+//! assert_eq!(a.to(), 1_046 * kJ);
+//! assert_eq!(format!("{:.1}", b.to::<J>()), "3165.2 J");
+//! ```
+use crate::declare_unit;
+use crate::quan::Energy;
+
+declare_unit!(
+    /** Joule */
+    J,
+    "J",
+    Energy,
+    1.0,
+    "joule",
+    "joules",
+);
+
+declare_unit!(
+    /** Kilojoule */
+    kJ,
+    "kJ",
+    Energy,
+    1_000.0,
+    "kilojoule",
+    "kilojoules",
+);
+
+declare_unit!(
+    /** Megajoule */
+    MJ,
+    "MJ",
+    Energy,
+    1_000_000.0,
+    "megajoule",
+    "megajoules",
+);
+
+declare_unit!(
+    /** Watt-hour */
+    Wh,
+    "Wh",
+    Energy,
+    3_600.0,
+    "watt-hour",
+    "watt-hours",
+);
+
+declare_unit!(
+    /** Kilowatt-hour */
+    kWh,
+    "kWh",
+    Energy,
+    3_600_000.0,
+    "kilowatt-hour",
+    "kilowatt-hours",
+);
+
+declare_unit!(
+    /** Calorie (thermochemical) */
+    cal,
+    "cal",
+    Energy,
+    4.184,
+    "calorie",
+    "calories",
+);
+
+declare_unit!(
+    /** Kilocalorie (thermochemical, food Calorie) */
+    kcal,
+    "kcal",
+    Energy,
+    4_184.0,
+    "kilocalorie",
+    "kilocalories",
+);
+
+declare_unit!(
+    /** British thermal unit (international table) */
+    BTU,
+    "BTU",
+    Energy,
+    1_055.055_852_62,
+    "British thermal unit",
+    "British thermal units",
+);
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn energy_display() {
+        assert_eq!((12.5 * J).to_string(), "12.5 J");
+        assert_eq!((3 * kWh).to_string(), "3 kWh");
+        assert_eq!((0.5 * MJ).to_string(), "0.5 MJ");
+        assert_eq!((100.0 * cal).to_string(), "100 cal");
+    }
+
+    #[test]
+    fn energy_to() {
+        assert_eq!((1.0 * kWh).to(), 3.6 * MJ);
+        assert_eq!((1.0 * Wh).to(), 3.6 * kJ);
+        assert_eq!((1.0 * kcal).to(), 1_000.0 * cal);
+        assert_eq!(format!("{:.4}", (1.0 * kWh).to::<BTU>()), "3412.1416 BTU");
+    }
+
+    #[test]
+    fn energy_ops() {
+        assert_eq!(1.5 * kJ + 0.5 * kJ, 2.0 * kJ);
+        assert_eq!(3.0 * Wh - 1.0 * Wh, 2.0 * Wh);
+        assert_eq!((2.0 * J) * 2.0, 4.0 * J);
+        assert_eq!((6.0 * BTU) / 2.0, 3.0 * BTU);
+    }
+}
@@ -0,0 +1,217 @@
+// fixed.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Fixed-point conversions, for targets without floating point hardware.
+//!
+//! A conversion [Factor] between two units is computed at compile time, and
+//! can be used to convert integer values, or to build a lookup table.  No
+//! floating point math is done at runtime.
+//!
+//! Offset units, such as temperatures, are not supported.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{fixed::Factor, fixed_factor, length::{km, mi}, time::h};
+//!
+//! const KPH_TO_MPH: Factor = fixed_factor!(speed: km / h => mi / h);
+//! const MPH_TABLE: [i32; 121] = KPH_TO_MPH.table();
+//!
+//! assert_eq!(KPH_TO_MPH.convert(100), 62);
+//! assert_eq!(MPH_TABLE[80], 50);
+//! ```
+
+/// Number of fractional bits in a [Factor]
+pub const FRAC_BITS: u32 = 16;
+
+/// Fixed-point conversion factor between two units.
+///
+/// The factor is stored with 16 fractional bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Factor(i64);
+
+impl Factor {
+    /// Create a factor from a floating point value (at compile time)
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the factor is too large for the fixed-point
+    /// format, or so small that it rounds to zero (such as `nm => km`).  In a
+    /// const context, this is a compile error.
+    pub const fn new(factor: f64) -> Self {
+        const LIMIT: f64 = i64::MAX as f64;
+        let scaled = factor * (1u64 << FRAC_BITS) as f64;
+        assert!(
+            scaled.is_finite() && scaled < LIMIT && scaled > -LIMIT,
+            "fixed-point factor out of range"
+        );
+        let raw = if scaled >= 0.0 {
+            (scaled + 0.5) as i64
+        } else {
+            (scaled - 0.5) as i64
+        };
+        assert!(raw != 0, "fixed-point factor rounds to zero");
+        Factor(raw)
+    }
+
+    /// Get the raw fixed-point value
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// Convert an integer value, rounding to nearest
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result does not fit in an `i32`.
+    pub const fn convert(self, value: i32) -> i32 {
+        match self.checked_convert(value) {
+            Some(val) => val,
+            None => panic!("fixed-point conversion overflow"),
+        }
+    }
+
+    /// Convert an integer value, rounding to nearest
+    ///
+    /// Returns `None` if the result does not fit in an `i32`.
+    pub const fn checked_convert(self, value: i32) -> Option<i32> {
+        let half = 1 << (FRAC_BITS - 1);
+        let Some(scaled) = (value as i64).checked_mul(self.0) else {
+            return None;
+        };
+        let Some(scaled) = scaled.checked_add(half) else {
+            return None;
+        };
+        let val = scaled >> FRAC_BITS;
+        if val >= i32::MIN as i64 && val <= i32::MAX as i64 {
+            Some(val as i32)
+        } else {
+            None
+        }
+    }
+
+    /// Build a lookup table of converted values, indexed by value
+    pub const fn table<const N: usize>(self) -> [i32; N] {
+        let mut table = [0; N];
+        let mut i = 0;
+        while i < N {
+            table[i] = self.convert(i as i32);
+            i += 1;
+        }
+        table
+    }
+}
+
+/// Compute a fixed-point conversion [Factor] between two units.
+///
+/// * `length: from => to` Length units
+/// * `area: from => to` Area (squared length units)
+/// * `time: from => to` Period units
+/// * `speed: length, time => length, time` Speed units (or with unit names
+///   only, `length / time => length / time`)
+/// * `quan: from => to` Units of [Quantity] (without zero offset)
+///
+/// [Factor]: fixed/struct.Factor.html
+/// [Quantity]: quan/struct.Quantity.html
+#[macro_export]
+macro_rules! fixed_factor {
+    (length: $from:ty => $to:ty) => {
+        $crate::fixed::Factor::new(
+            <$from as $crate::length::Unit>::M_FACTOR
+                / <$to as $crate::length::Unit>::M_FACTOR,
+        )
+    };
+    (area: $from:ty => $to:ty) => {
+        $crate::fixed::Factor::new(
+            (<$from as $crate::length::Unit>::M_FACTOR
+                / <$to as $crate::length::Unit>::M_FACTOR)
+                * (<$from as $crate::length::Unit>::M_FACTOR
+                    / <$to as $crate::length::Unit>::M_FACTOR),
+        )
+    };
+    (time: $from:ty => $to:ty) => {
+        $crate::fixed::Factor::new(
+            <$from as $crate::time::Unit>::S_FACTOR
+                / <$to as $crate::time::Unit>::S_FACTOR,
+        )
+    };
+    (speed: $fl:ident / $fp:ident => $tl:ident / $tp:ident) => {
+        $crate::fixed_factor!(speed: $fl, $fp => $tl, $tp)
+    };
+    (speed: $fl:ty, $fp:ty => $tl:ty, $tp:ty) => {
+        $crate::fixed::Factor::new(
+            (<$fl as $crate::length::Unit>::M_FACTOR
+                / <$tl as $crate::length::Unit>::M_FACTOR)
+                / (<$fp as $crate::time::Unit>::S_FACTOR
+                    / <$tp as $crate::time::Unit>::S_FACTOR),
+        )
+    };
+    (quan: $from:ty => $to:ty) => {
+        $crate::fixed::Factor::new(
+            <$from as $crate::quan::Unit>::FACTOR
+                / <$to as $crate::quan::Unit>::FACTOR,
+        )
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{cm, ft, km, m, mi, In};
+    use crate::mass::{kg, lb};
+    use crate::time::{h, min, s};
+
+    const FT_TO_IN: Factor = fixed_factor!(length: ft => In);
+    const M2_TO_CM2: Factor = fixed_factor!(area: m => cm);
+    const H_TO_MIN: Factor = fixed_factor!(time: h => min);
+    const MPS_TO_KPH: Factor = fixed_factor!(speed: m / s => km / h);
+    const LB_TO_KG: Factor = fixed_factor!(quan: lb => kg);
+
+    #[test]
+    fn factors() {
+        assert_eq!(FT_TO_IN.raw(), 12 << FRAC_BITS);
+        assert_eq!(FT_TO_IN.convert(3), 36);
+        assert_eq!(M2_TO_CM2.convert(2), 20_000);
+        assert_eq!(H_TO_MIN.convert(-2), -120);
+        assert_eq!(MPS_TO_KPH.convert(10), 36);
+        assert_eq!(LB_TO_KG.convert(100), 45);
+        assert_eq!(Factor::new(-0.5).convert(3), -1);
+        let mps =
+            fixed_factor!(speed: crate::length::m, s => km, crate::time::h);
+        assert_eq!(mps, MPS_TO_KPH);
+    }
+
+    #[test]
+    fn overflow() {
+        let big = fixed_factor!(length: km => crate::length::mm);
+        assert_eq!(big.checked_convert(2_000), Some(2_000_000_000));
+        assert_eq!(big.checked_convert(3_000), None);
+        assert_eq!(Factor::new(1e12).checked_convert(i32::MAX), None);
+        assert_eq!(LB_TO_KG.checked_convert(i32::MIN), Some(-974_094_336));
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point conversion overflow")]
+    fn overflow_panic() {
+        let _ = fixed_factor!(length: km => crate::length::mm).convert(3_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point factor rounds to zero")]
+    fn factor_zero() {
+        let _ = fixed_factor!(length: crate::length::nm => km);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point factor out of range")]
+    fn factor_range() {
+        let _ = Factor::new(f64::INFINITY);
+    }
+
+    #[test]
+    fn table() {
+        const TABLE: [i32; 5] = fixed_factor!(length: mi => km).table();
+        assert_eq!(TABLE, [0, 2, 3, 5, 6]);
+    }
+}
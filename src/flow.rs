@@ -0,0 +1,502 @@
+// flow.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Private module for flow rate structs
+//!
+use crate::quan::{
+    fmt_quantity, parse_quantity, Label, LiquidVolume, Magnitude, ParseError,
+    Quantity, Scalable, Unit,
+};
+use crate::{length, time, Area, Period, Speed, Volume};
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Quantity of volumetric _flow rate_ (volume per time).
+///
+/// FlowRate is a derived quantity with [length unit]s (cubed) and [time
+/// unit]s.
+///
+/// ## Operations
+///
+/// * [Volume] `/` [Period] `=>` FlowRate
+/// * [Area] `*` [Speed] `=>` FlowRate
+/// * FlowRate `+` FlowRate `=>` FlowRate
+/// * FlowRate `-` FlowRate `=>` FlowRate
+/// * FlowRate `*` f64 `=>` FlowRate
+/// * f64 `*` FlowRate `=>` FlowRate
+/// * FlowRate `*` u32 `=>` FlowRate
+/// * i32 `*` FlowRate `=>` FlowRate
+/// * u32 `*` FlowRate `=>` FlowRate
+/// * FlowRate `*` [Period] `=>` [Volume]
+/// * [Period] `*` FlowRate `=>` [Volume]
+/// * FlowRate `/` f64 `=>` FlowRate
+/// * FlowRate `/` FlowRate `=>` [Ratio]
+/// * FlowRate `/` [Area] `=>` [Speed]
+/// * FlowRate `+=` FlowRate
+/// * FlowRate `-=` FlowRate
+/// * FlowRate `*=` f64
+/// * FlowRate `/=` f64
+/// * [Volume] `/` FlowRate `=>` [Period]
+///
+/// Units must be the same for operations with two FlowRate operands.  The
+/// [to] method can be used for conversion.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::{ft, m}, liquid::gal_us, time::{min, s}, FlowRate};
+///
+/// let river = 120.0 * m * m * m / (1.0 * s);
+/// let pump = FlowRate::<ft, min>::from_liquid(25.0 * gal_us, 1.0 * min);
+///
+/// assert_eq!(river.to_string(), "120 m³/s");
+/// assert_eq!(format!("{:.3}", pump), "3.342 ft³/min");
+/// let gal = pump.liquid_per_period::<gal_us>();
+/// assert_eq!(format!("{:.1}", gal), "25.0 US gal");
+/// assert_eq!(river * (2.0 * s), 240.0 * m * m * m);
+/// ```
+/// [Area]: struct.Area.html
+/// [Period]: struct.Period.html
+/// [Ratio]: ratio/struct.Ratio.html
+/// [Speed]: struct.Speed.html
+/// [Volume]: struct.Volume.html
+/// [length unit]: length/index.html
+/// [time unit]: time/index.html
+/// [to]: struct.FlowRate.html#method.to
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Flow rate quantity
+    #[deprecated(note = "use the `quantity()` method")]
+    pub quantity: f64,
+
+    /// Length unit
+    length: PhantomData<L>,
+
+    /// Period unit
+    period: PhantomData<P>,
+}
+
+// FlowRate + FlowRate => FlowRate
+impl<L, P> Add for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() + other.quantity())
+    }
+}
+
+// FlowRate - FlowRate => FlowRate
+impl<L, P> Sub for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() - other.quantity())
+    }
+}
+
+// Sum of FlowRate => FlowRate
+impl<L, P> Sum for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
+    }
+}
+
+// FlowRate * f64 => FlowRate
+impl<L, P> Mul<f64> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() * scalar)
+    }
+}
+
+// f64 * FlowRate => FlowRate
+impl<L, P> Mul<FlowRate<L, P>> for f64
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = FlowRate<L, P>;
+    fn mul(self, other: FlowRate<L, P>) -> Self::Output {
+        FlowRate::new(self * other.quantity())
+    }
+}
+
+// i32 * FlowRate => FlowRate
+impl<L, P> Mul<FlowRate<L, P>> for i32
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = FlowRate<L, P>;
+    fn mul(self, other: FlowRate<L, P>) -> Self::Output {
+        FlowRate::new(f64::from(self) * other.quantity())
+    }
+}
+
+// FlowRate * u32 => FlowRate
+impl<L, P> Mul<u32> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, count: u32) -> Self::Output {
+        Self::new(self.quantity() * f64::from(count))
+    }
+}
+
+// u32 * FlowRate => FlowRate
+impl<L, P> Mul<FlowRate<L, P>> for u32
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = FlowRate<L, P>;
+    fn mul(self, other: FlowRate<L, P>) -> Self::Output {
+        FlowRate::new(f64::from(self) * other.quantity())
+    }
+}
+
+// FlowRate / f64 => FlowRate
+impl<L, P> Div<f64> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() / scalar)
+    }
+}
+
+// FlowRate += FlowRate
+impl<L, P> AddAssign for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() + other.quantity());
+    }
+}
+
+// FlowRate -= FlowRate
+impl<L, P> SubAssign for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() - other.quantity());
+    }
+}
+
+// FlowRate *= f64
+impl<L, P> MulAssign<f64> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() * scalar);
+    }
+}
+
+// FlowRate /= f64
+impl<L, P> DivAssign<f64> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() / scalar);
+    }
+}
+
+// Volume / Period => FlowRate
+impl<L, P> Div<Period<P>> for Volume<L>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = FlowRate<L, P>;
+    fn div(self, per: Period<P>) -> Self::Output {
+        FlowRate::new(self.quantity() / per.quantity())
+    }
+}
+
+// Area * Speed => FlowRate
+impl<L, P> Mul<Speed<L, P>> for Area<L>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = FlowRate<L, P>;
+    fn mul(self, speed: Speed<L, P>) -> Self::Output {
+        FlowRate::new(self.quantity() * speed.quantity())
+    }
+}
+
+// FlowRate * Period => Volume
+impl<L, P> Mul<Period<P>> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Volume<L>;
+    fn mul(self, per: Period<P>) -> Self::Output {
+        Volume::new(self.quantity() * per.quantity())
+    }
+}
+
+// Period * FlowRate => Volume
+impl<L, P> Mul<FlowRate<L, P>> for Period<P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Volume<L>;
+    fn mul(self, flow: FlowRate<L, P>) -> Self::Output {
+        Volume::new(self.quantity() * flow.quantity())
+    }
+}
+
+// FlowRate / Area => Speed
+impl<L, P> Div<Area<L>> for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Speed<L, P>;
+    fn div(self, area: Area<L>) -> Self::Output {
+        Speed::new(self.quantity() / area.quantity())
+    }
+}
+
+// Volume / FlowRate => Period
+impl<L, P> Div<FlowRate<L, P>> for Volume<L>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Output = Period<P>;
+    fn div(self, flow: FlowRate<L, P>) -> Self::Output {
+        Period::new(self.quantity() / flow.quantity())
+    }
+}
+
+impl_base_ops!(@access FlowRate<L, P> where L: length::Unit, P: time::Unit);
+
+impl<L, P> FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Create a new flow rate quantity
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
+        FlowRate::<L, P> {
+            quantity: crate::check_finite(quantity),
+            length: PhantomData,
+            period: PhantomData,
+        }
+    }
+
+    /// Create a flow rate from a liquid volume passed in a period
+    ///
+    /// Units are converted as needed.
+    pub fn from_liquid<V>(volume: Quantity<V>, period: Period<P>) -> Self
+    where
+        V: Unit<Measure = LiquidVolume>,
+    {
+        volume.to_volume::<L>() / period
+    }
+
+    /// Get the liquid volume which flows in one period unit
+    pub fn liquid_per_period<V>(self) -> Quantity<V>
+    where
+        V: Unit<Measure = LiquidVolume>,
+    {
+        Volume::<L>::new(self.quantity()).to_liquid()
+    }
+
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.quantity() * count as f64)
+    }
+
+    /// Change the units **without converting** the quantity.
+    ///
+    /// This is an escape hatch for cases where the quantity is already known
+    /// to be in the target units, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<N, R>(self) -> FlowRate<N, R>
+    where
+        N: length::Unit,
+        R: time::Unit,
+    {
+        FlowRate::new(self.quantity())
+    }
+
+    /// Convert to specified units
+    pub const fn to<N, R>(self) -> FlowRate<N, R>
+    where
+        N: length::Unit,
+        R: time::Unit,
+    {
+        let per = <L as length::Factor<N>>::FACTOR;
+        let factor = per * per * per / <P as time::Factor<R>>::FACTOR;
+        FlowRate::new(self.quantity() * factor)
+    }
+
+    /// Convert to SI base units (`m³/s`)
+    pub const fn to_base(self) -> FlowRate<length::m, time::s> {
+        self.to()
+    }
+}
+
+impl<L, P> Magnitude for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn magnitude(&self) -> f64 {
+        self.quantity()
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<L, P> Scalable for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+}
+
+impl<L, P> Label for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(L::LABEL)?;
+        w.write_str("³/")?;
+        w.write_str(P::LABEL)
+    }
+}
+
+impl<L, P> fmt::Display for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_quantity(self, f)
+    }
+}
+
+impl<L, P> FromStr for FlowRate<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{cm, ft, m};
+    use crate::liquid::{gal_us, L};
+    use crate::time::{h, min, s};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn flow_display() {
+        assert_eq!(FlowRate::<m, s>::new(3.5).to_string(), "3.5 m³/s");
+        assert_eq!(FlowRate::<ft, min>::new(12.0).to_string(), "12 ft³/min");
+        assert_eq!("2.5 cm³/s".parse(), Ok(FlowRate::<cm, s>::new(2.5)));
+    }
+
+    #[test]
+    fn flow_to() {
+        assert_eq!(
+            FlowRate::<m, s>::new(1.0).to::<m, h>(),
+            FlowRate::new(3_600.0)
+        );
+        assert_eq!(
+            format!("{:.3}", FlowRate::<m, s>::new(1.0).to::<ft, s>()),
+            "35.315 ft³/s"
+        );
+    }
+
+    #[test]
+    fn flow_ops() {
+        let q = 12.0 * m * m * m / (4.0 * s);
+        assert_eq!(q, FlowRate::new(3.0));
+        assert_eq!(q * (2.0 * s), 6.0 * m * m * m);
+        assert_eq!((2.0 * s) * q, 6.0 * m * m * m);
+        assert_eq!(9.0 * m * m * m / q, 3.0 * s);
+        assert_eq!((2.0 * m * m) * (1.5 * m / s), q);
+        assert_eq!(q / (6.0 * m * m), 0.5 * m / s);
+        assert_eq!(q + q, q * 2.0);
+        assert_eq!(q - q / 3.0, 2u32 * q / 3.0);
+        let mut r = q;
+        r += q;
+        r *= 0.5;
+        assert_eq!(r, q);
+    }
+
+    #[test]
+    fn flow_liquid() {
+        let q = FlowRate::<cm, s>::from_liquid(2.0 * L, 4.0 * s);
+        assert_eq!(q, FlowRate::new(500.0));
+        assert_eq!(q.liquid_per_period::<L>(), 0.5000000000000001 * L);
+        let q = FlowRate::<ft, min>::new(1.0);
+        assert_eq!(
+            format!("{:.3}", q.liquid_per_period::<gal_us>()),
+            "7.481 US gal"
+        );
+    }
+}
@@ -0,0 +1,450 @@
+// force.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of force.
+//!
+//! Force is derived from mass, length and time units.  The [N] (newton) unit
+//! is shorthand for `kg·m/s²`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{force::N, length::ft, mass::lb, time::s, Force};
+//!
+//! let a = 5.0 * N;
+//! let b = Force::<lb, ft, s>::new(10.0);
+//!
+//! assert_eq!(a.to_string(), "5 kg·m/s²");
+//! assert_eq!(format!("{:.4}", b.to::<_, _, _>() + a), "6.3825 kg·m/s²");
+//! ```
+use crate::mass::kg;
+use crate::quan::{
+    fmt_quantity, parse_quantity, Label, Magnitude, Mass, ParseError, Scalable,
+    Unit,
+};
+use crate::{length, time};
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Quantity of _force_.
+///
+/// Force is a derived quantity with [mass unit]s, [length unit]s and
+/// [time unit]s: mass times length per time squared.
+///
+/// ## Operations
+///
+/// * f64 `*` [N] `=>` Force
+/// * i32 `*` [N] `=>` Force
+/// * [N] `*` f64, f32, i32, u32, i64 or u64 `=>` Force
+/// * Mass `*` [Acceleration] `=>` Force
+/// * Force `+` Force `=>` Force
+/// * Force `-` Force `=>` Force
+/// * Force `*` f64 `=>` Force
+/// * f64 `*` Force `=>` Force
+/// * Force `*` u32 `=>` Force
+/// * i32 `*` Force `=>` Force
+/// * u32 `*` Force `=>` Force
+/// * Force `/` f64 `=>` Force
+/// * Force `/` Force `=>` [Ratio]
+/// * Force `+=` Force
+/// * Force `-=` Force
+/// * Force `*=` f64
+/// * Force `/=` f64
+/// * Force `/` [Area] `=>` [Pressure] (Pa)
+/// * Force `*` [Length] `=>` [Torque] (N·m)
+///
+/// Units must be the same for operations with two Force operands.  The [to]
+/// method can be used for conversion.
+///
+/// [Acceleration]: struct.Acceleration.html
+/// [Area]: struct.Area.html
+/// [Length]: struct.Length.html
+/// [Pressure]: quan/struct.Pressure.html
+/// [Ratio]: ratio/struct.Ratio.html
+/// [N]: force/struct.N.html
+/// [length unit]: length/index.html
+/// [mass unit]: mass/index.html
+/// [time unit]: time/index.html
+/// [Torque]: quan/struct.Torque.html
+/// [to]: struct.Force.html#method.to
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    /// Force quantity
+    #[deprecated(note = "use the `quantity()` method")]
+    pub quantity: f64,
+
+    /// Mass unit
+    mass: PhantomData<M>,
+
+    /// Length unit
+    length: PhantomData<L>,
+
+    /// Time unit
+    time: PhantomData<T>,
+}
+
+/// Newton (`kg·m/s²`)
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct N;
+
+// scalar * N => Force
+crate::impl_scalar_mul!(N => Force<kg, length::m, time::s>);
+
+// Force + Force => Force
+impl<M, L, T> Add for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() + other.quantity())
+    }
+}
+
+// Force - Force => Force
+impl<M, L, T> Sub for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() - other.quantity())
+    }
+}
+
+// Sum of Force => Force
+impl<M, L, T> Sum for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
+    }
+}
+
+// Force * f64 => Force
+impl<M, L, T> Mul<f64> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() * scalar)
+    }
+}
+
+// f64 * Force => Force
+impl<M, L, T> Mul<Force<M, L, T>> for f64
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Force<M, L, T>;
+    fn mul(self, other: Force<M, L, T>) -> Self::Output {
+        Force::new(self * other.quantity())
+    }
+}
+
+// i32 * Force => Force
+impl<M, L, T> Mul<Force<M, L, T>> for i32
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Force<M, L, T>;
+    fn mul(self, other: Force<M, L, T>) -> Self::Output {
+        Force::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Force * u32 => Force
+impl<M, L, T> Mul<u32> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, count: u32) -> Self::Output {
+        Self::new(self.quantity() * f64::from(count))
+    }
+}
+
+// u32 * Force => Force
+impl<M, L, T> Mul<Force<M, L, T>> for u32
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Force<M, L, T>;
+    fn mul(self, other: Force<M, L, T>) -> Self::Output {
+        Force::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Force / f64 => Force
+impl<M, L, T> Div<f64> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() / scalar)
+    }
+}
+
+// Force += Force
+impl<M, L, T> AddAssign for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() + other.quantity());
+    }
+}
+
+// Force -= Force
+impl<M, L, T> SubAssign for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() - other.quantity());
+    }
+}
+
+// Force *= f64
+impl<M, L, T> MulAssign<f64> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() * scalar);
+    }
+}
+
+// Force /= f64
+impl<M, L, T> DivAssign<f64> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() / scalar);
+    }
+}
+
+impl_base_ops!(
+    @access Force<M, L, T>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        T: time::Unit
+);
+
+impl<M, L, T> Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    /// Create a new force quantity
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
+        Force::<M, L, T> {
+            quantity: crate::check_finite(quantity),
+            mass: PhantomData,
+            length: PhantomData,
+            time: PhantomData,
+        }
+    }
+
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.quantity() * count as f64)
+    }
+
+    /// Change the units **without converting** the quantity.
+    ///
+    /// This is an escape hatch for cases where the quantity is already known
+    /// to be in the target units, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<N, R, S>(self) -> Force<N, R, S>
+    where
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+        S: time::Unit,
+    {
+        Force::new(self.quantity())
+    }
+
+    /// Convert to specified units
+    pub const fn to<N, R, S>(self) -> Force<N, R, S>
+    where
+        N: Unit<Measure = Mass>,
+        R: length::Unit,
+        S: time::Unit,
+    {
+        let per = <T as time::Factor<S>>::FACTOR;
+        let factor = M::FACTOR / N::FACTOR * <L as length::Factor<R>>::FACTOR
+            / (per * per);
+        Force::new(self.quantity() * factor)
+    }
+
+    /// Convert to SI base units (`kg·m/s²`, or newtons)
+    pub const fn to_base(self) -> Force<kg, length::m, time::s> {
+        self.to()
+    }
+}
+
+impl<M, L, T> Magnitude for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn magnitude(&self) -> f64 {
+        self.quantity()
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<M, L, T> Scalable for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+}
+
+impl<M, L, T> Label for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(M::LABEL)?;
+        w.write_char('·')?;
+        w.write_str(L::LABEL)?;
+        w.write_char('/')?;
+        w.write_str(T::LABEL)?;
+        w.write_char('²')
+    }
+}
+
+impl<M, L, T> fmt::Display for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_quantity(self, f)
+    }
+}
+
+impl<M, L, T> FromStr for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{cm, ft, m};
+    use crate::mass::{g, lb};
+    use crate::time::{ms, s};
+    use alloc::string::ToString;
+
+    #[test]
+    fn force_display() {
+        assert_eq!((9.8 * N).to_string(), "9.8 kg·m/s²");
+        assert_eq!(Force::<g, cm, s>::new(2.5).to_string(), "2.5 g·cm/s²");
+        assert_eq!(Force::<lb, ft, s>::new(1.0).to_string(), "1 lb·ft/s²");
+    }
+
+    #[test]
+    fn force_to() {
+        // 1 N = 100,000 dyn
+        assert_eq!((1 * N).to::<g, cm, s>(), Force::new(100_000.0));
+        assert_eq!((2 * N).to::<kg, m, ms>(), Force::new(0.000_002));
+        assert_eq!(
+            Force::<lb, ft, s>::new(1.0).to::<kg, m, s>(),
+            0.13825495437600002 * N
+        );
+    }
+
+    #[test]
+    fn force_ops() {
+        assert_eq!(2.5 * N + 1.5 * N, 4.0 * N);
+        assert_eq!(2.5 * N - 1.5 * N, 1.0 * N);
+        assert_eq!((2.5 * N) * 2.0, 5.0 * N);
+        assert_eq!(3u32 * (2.0 * N), 6.0 * N);
+        assert_eq!(-3_i32 * (2.0 * N), -6.0 * N);
+        assert_eq!((9.0 * N) / 3.0, 3.0 * N);
+        assert_eq!(
+            [1.0 * N, 2.0 * N].into_iter().sum::<Force<_, _, _>>(),
+            3 * N
+        );
+        let mut f = 1.0 * N;
+        f += 2.0 * N;
+        f -= 0.5 * N;
+        f *= 2.0;
+        f /= 5.0;
+        assert_eq!(f, 1.0 * N);
+    }
+}
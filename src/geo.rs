@@ -0,0 +1,97 @@
+// geo.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Great-circle distances and bearings between coordinates
+//!
+//! This module requires the `libm` feature.  Coordinates are `(latitude,
+//! longitude)` pairs in degrees, with north and east positive.  The earth is
+//! treated as a sphere with the IUGG mean radius, so distances can be off by
+//! up to about 0.5%.  Bearings are in degrees clockwise from north, like the
+//! [bearing](crate::bearing) module.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{geo::{distance, initial_bearing}, length::mi};
+//!
+//! let msp = (44.8848, -93.2223);
+//! let ord = (41.9786, -87.9048);
+//!
+//! let dist = distance(msp, ord);
+//! assert_eq!(format!("{:.0}", dist), "537 km");
+//! assert_eq!(format!("{:.0}", dist.to::<mi>()), "334 mi");
+//! assert_eq!(format!("{:.1}", initial_bearing(msp, ord).unwrap()), "125.1");
+//! ```
+use crate::bearing::wrap_degrees;
+use crate::length::km;
+use crate::Length;
+
+/// Mean radius of the earth (IUGG)
+pub const EARTH_RADIUS: Length<km> = Length::new(6_371.008_8);
+
+/// Calculate the great-circle distance between two coordinates
+///
+/// This uses the haversine formula, which is accurate for small distances.
+pub fn distance(a: (f64, f64), b: (f64, f64)) -> Length<km> {
+    let (lat1, lat2) = (a.0.to_radians(), b.0.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (b.1 - a.1).to_radians();
+    let sin_lat = libm::sin(dlat / 2.0);
+    let sin_lon = libm::sin(dlon / 2.0);
+    let h = sin_lat * sin_lat
+        + libm::cos(lat1) * libm::cos(lat2) * sin_lon * sin_lon;
+    let angle = 2.0 * libm::asin(libm::sqrt(h.min(1.0)));
+    EARTH_RADIUS * angle
+}
+
+/// Calculate the initial bearing of the great circle between coordinates
+///
+/// Returns degrees in the range `[0, 360)`, or `None` if the coordinates
+/// are the same.  The bearing changes along the path, except when following
+/// a meridian or the equator.
+pub fn initial_bearing(a: (f64, f64), b: (f64, f64)) -> Option<f64> {
+    if a == b {
+        return None;
+    }
+    let (lat1, lat2) = (a.0.to_radians(), b.0.to_radians());
+    let dlon = (b.1 - a.1).to_radians();
+    let y = libm::sin(dlon) * libm::cos(lat2);
+    let x = libm::cos(lat1) * libm::sin(lat2)
+        - libm::sin(lat1) * libm::cos(lat2) * libm::cos(dlon);
+    let deg = wrap_degrees(libm::atan2(y, x).to_degrees());
+    Some(if deg < 0.0 { deg + 360.0 } else { deg })
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::NM;
+    use alloc::format;
+
+    #[test]
+    fn distances() {
+        assert_eq!(distance((45.0, 10.0), (45.0, 10.0)), 0.0 * km);
+        // one minute of latitude is about one nautical mile
+        let d = distance((0.0, 0.0), (1.0 / 60.0, 0.0)).to::<NM>();
+        assert_eq!(format!("{:.3}", d), "1.001 NM");
+        // half way around the equator
+        let d = distance((0.0, 0.0), (0.0, 180.0));
+        assert_eq!(format!("{:.1}", d), "20015.1 km");
+        let d = distance((51.5007, -0.1246), (40.6892, -74.0445));
+        assert_eq!(format!("{:.0}", d), "5575 km");
+    }
+
+    #[test]
+    fn bearings() {
+        assert_eq!(initial_bearing((0.0, 0.0), (10.0, 0.0)), Some(0.0));
+        assert_eq!(initial_bearing((0.0, 0.0), (0.0, 10.0)), Some(90.0));
+        assert_eq!(initial_bearing((10.0, 0.0), (0.0, 0.0)), Some(180.0));
+        assert_eq!(initial_bearing((0.0, 10.0), (0.0, 0.0)), Some(270.0));
+        assert_eq!(initial_bearing((5.0, 5.0), (5.0, 5.0)), None);
+        let b = initial_bearing((51.5007, -0.1246), (40.6892, -74.0445));
+        assert_eq!(format!("{:.1}", b.unwrap()), "288.3");
+    }
+}
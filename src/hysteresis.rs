@@ -0,0 +1,130 @@
+// hysteresis.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Alarm hysteresis for measurements
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::hysteresis::{Hysteresis, State};
+//! use mag::{length::mi, time::h};
+//!
+//! let mut alarm = Hysteresis::new(70.0 * mi / h, 65.0 * mi / h);
+//!
+//! assert_eq!(alarm.check(68.0 * mi / h), State::Clear);
+//! assert_eq!(alarm.check(72.0 * mi / h), State::Set);
+//! assert_eq!(alarm.check(68.0 * mi / h), State::Set);
+//! assert_eq!(alarm.check(64.0 * mi / h), State::Clear);
+//! ```
+
+/// Alarm state
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum State {
+    /// Alarm is clear
+    #[default]
+    Clear,
+
+    /// Alarm is set
+    Set,
+}
+
+/// Debounced alarm with separate set and clear thresholds.
+///
+/// If the set threshold is above the clear threshold, the alarm is set when a
+/// sample rises to the set threshold and cleared when it falls to the clear
+/// threshold.  Otherwise, the alarm is set on falling samples and cleared on
+/// rising samples.
+///
+/// Both thresholds must be the same quantity type, including units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hysteresis<Q> {
+    /// Threshold to set alarm
+    set: Q,
+
+    /// Threshold to clear alarm
+    clear: Q,
+
+    /// Current state
+    state: State,
+}
+
+impl<Q> Hysteresis<Q>
+where
+    Q: Copy + PartialOrd,
+{
+    /// Create a new hysteresis alarm, initially clear
+    pub fn new(set: Q, clear: Q) -> Self {
+        Hysteresis {
+            set,
+            clear,
+            state: State::Clear,
+        }
+    }
+
+    /// Get the set threshold
+    pub fn set_threshold(&self) -> Q {
+        self.set
+    }
+
+    /// Get the clear threshold
+    pub fn clear_threshold(&self) -> Q {
+        self.clear
+    }
+
+    /// Get the current state
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Check a sample, updating the alarm state
+    pub fn check(&mut self, sample: Q) -> State {
+        let rising = self.set >= self.clear;
+        self.state = match self.state {
+            State::Clear if rising && sample >= self.set => State::Set,
+            State::Clear if !rising && sample <= self.set => State::Set,
+            State::Set if rising && sample <= self.clear => State::Clear,
+            State::Set if !rising && sample >= self.clear => State::Clear,
+            state => state,
+        };
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::temp::{DegC, DegF};
+
+    #[test]
+    fn rising() {
+        let mut alarm = Hysteresis::new(30.0 * DegC, 28.0 * DegC);
+        assert_eq!(alarm.check(29.0 * DegC), State::Clear);
+        assert_eq!(alarm.check(30.0 * DegC), State::Set);
+        assert_eq!(alarm.check(29.0 * DegC), State::Set);
+        assert_eq!(alarm.check(28.5 * DegC), State::Set);
+        assert_eq!(alarm.check(28.0 * DegC), State::Clear);
+        assert_eq!(alarm.check(29.9 * DegC), State::Clear);
+        assert_eq!(alarm.state(), State::Clear);
+    }
+
+    #[test]
+    fn falling() {
+        let mut alarm = Hysteresis::new(32.0 * DegF, 35.0 * DegF);
+        assert_eq!(alarm.check(33.0 * DegF), State::Clear);
+        assert_eq!(alarm.check(31.0 * DegF), State::Set);
+        assert_eq!(alarm.check(34.0 * DegF), State::Set);
+        assert_eq!(alarm.check(35.5 * DegF), State::Clear);
+        assert_eq!(alarm.set_threshold(), 32.0 * DegF);
+        assert_eq!(alarm.clear_threshold(), 35.0 * DegF);
+    }
+
+    #[test]
+    #[cfg(not(feature = "assert-finite"))]
+    fn nan_sample() {
+        let mut alarm = Hysteresis::new(30.0 * DegC, 28.0 * DegC);
+        assert_eq!(alarm.check(f64::NAN * DegC), State::Clear);
+        assert_eq!(alarm.check(31.0 * DegC), State::Set);
+        assert_eq!(alarm.check(f64::NAN * DegC), State::Set);
+    }
+}
@@ -0,0 +1,623 @@
+// information.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of information (data size) and bandwidth.
+//!
+//! Each unit is defined relative to bytes with a conversion factor.  They can
+//! be used to conveniently create [DataSize] quantities.  Decimal prefixes
+//! (`kB`, `MB`, `Mbit`) are powers of 1000, and binary prefixes (`KiB`,
+//! `MiB`) are powers of 1024.
+//!
+//! ## Operations
+//!
+//! * DataSize `/` [Period] `=>` [Bandwidth]
+//! * DataSize `/` [Bandwidth] `=>` [Period]
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::information::{Bandwidth, GB, MiB, Mbit};
+//! use mag::time::{min, s};
+//!
+//! let a = (90.0 * MiB) / (60.0 * s);
+//! let link = Bandwidth::<Mbit, s>::new(100.0);
+//!
+//! assert_eq!(a.to_string(), "1.5 MiB/s");
+//! assert_eq!(format!("{:.2}", a.to::<Mbit, s>()), "12.58 Mbit/s");
+//! assert_eq!(format!("{:.1}", (1.0 * GB / link).to::<min>()), "1.3 min");
+//! ```
+//!
+//! [Period]: ../struct.Period.html
+use crate::declare_unit;
+use crate::quan::{
+    fmt_quantity, parse_quantity, Information, Label, Magnitude, ParseError,
+    Quantity, Scalable, Unit,
+};
+use crate::{time, Period};
+use core::fmt;
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+/// Quantity of information, such as a file or message size
+pub type DataSize<U> = Quantity<U>;
+
+declare_unit!(
+    /** Bit */
+    bit,
+    "bit",
+    Information,
+    0.125,
+    "bit",
+    "bits",
+);
+
+declare_unit!(
+    /** Kilobit (1000 bits) */
+    kbit,
+    "kbit",
+    Information,
+    125.0,
+    "kilobit",
+    "kilobits",
+);
+
+declare_unit!(
+    /** Megabit (1000² bits) */
+    Mbit,
+    "Mbit",
+    Information,
+    125_000.0,
+    "megabit",
+    "megabits",
+);
+
+declare_unit!(
+    /** Gigabit (1000³ bits) */
+    Gbit,
+    "Gbit",
+    Information,
+    125_000_000.0,
+    "gigabit",
+    "gigabits",
+);
+
+declare_unit!(
+    /** Byte (8 bits) */
+    B,
+    "B",
+    Information,
+    1.0,
+    "byte",
+    "bytes",
+);
+
+declare_unit!(
+    /** Kilobyte (1000 bytes) */
+    kB,
+    "kB",
+    Information,
+    1_000.0,
+    "kilobyte",
+    "kilobytes",
+);
+
+declare_unit!(
+    /** Megabyte (1000² bytes) */
+    MB,
+    "MB",
+    Information,
+    1_000_000.0,
+    "megabyte",
+    "megabytes",
+);
+
+declare_unit!(
+    /** Gigabyte (1000³ bytes) */
+    GB,
+    "GB",
+    Information,
+    1_000_000_000.0,
+    "gigabyte",
+    "gigabytes",
+);
+
+declare_unit!(
+    /** Terabyte (1000⁴ bytes) */
+    TB,
+    "TB",
+    Information,
+    1_000_000_000_000.0,
+    "terabyte",
+    "terabytes",
+);
+
+declare_unit!(
+    /** Kibibyte (1024 bytes) */
+    KiB,
+    "KiB",
+    Information,
+    1_024.0,
+    "kibibyte",
+    "kibibytes",
+);
+
+declare_unit!(
+    /** Mebibyte (1024² bytes) */
+    MiB,
+    "MiB",
+    Information,
+    1_048_576.0,
+    "mebibyte",
+    "mebibytes",
+);
+
+declare_unit!(
+    /** Gibibyte (1024³ bytes) */
+    GiB,
+    "GiB",
+    Information,
+    1_073_741_824.0,
+    "gibibyte",
+    "gibibytes",
+);
+
+declare_unit!(
+    /** Tebibyte (1024⁴ bytes) */
+    TiB,
+    "TiB",
+    Information,
+    1_099_511_627_776.0,
+    "tebibyte",
+    "tebibytes",
+);
+
+/// Quantity of _bandwidth_ (information per time), or data rate.
+///
+/// Bandwidth is a derived quantity with information units and [time unit]s.
+///
+/// ## Operations
+///
+/// * [DataSize] `/` [Period] `=>` Bandwidth
+/// * Bandwidth `+` Bandwidth `=>` Bandwidth
+/// * Bandwidth `-` Bandwidth `=>` Bandwidth
+/// * Bandwidth `*` f64 `=>` Bandwidth
+/// * f64 `*` Bandwidth `=>` Bandwidth
+/// * Bandwidth `*` u32 `=>` Bandwidth
+/// * i32 `*` Bandwidth `=>` Bandwidth
+/// * u32 `*` Bandwidth `=>` Bandwidth
+/// * Bandwidth `*` [Period] `=>` [DataSize]
+/// * Bandwidth `/` f64 `=>` Bandwidth
+/// * Bandwidth `/` Bandwidth `=>` [Ratio]
+/// * Bandwidth `+=` Bandwidth
+/// * Bandwidth `-=` Bandwidth
+/// * Bandwidth `*=` f64
+/// * Bandwidth `/=` f64
+/// * [DataSize] `/` Bandwidth `=>` [Period]
+///
+/// Units must be the same for operations with two Bandwidth operands.  The
+/// [to] method can be used for conversion.  Dividing a [DataSize] by a
+/// Bandwidth converts the information units as needed.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{information::{Bandwidth, kB, Mbit}, time::{ms, s}};
+///
+/// let a = Bandwidth::<Mbit, s>::new(8.0);
+/// let b = a * (250.0 * ms).to::<s>();
+///
+/// assert_eq!(a.to_string(), "8 Mbit/s");
+/// assert_eq!(b.to::<kB>(), 250.0 * kB);
+/// ```
+/// [Period]: ../struct.Period.html
+/// [Ratio]: ../ratio/struct.Ratio.html
+/// [time unit]: ../time/index.html
+/// [to]: #method.to
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    /// Bandwidth quantity
+    #[deprecated(note = "use the `quantity()` method")]
+    pub quantity: f64,
+
+    /// Information unit
+    data: PhantomData<D>,
+
+    /// Time unit
+    period: PhantomData<P>,
+}
+
+// Bandwidth + Bandwidth => Bandwidth
+impl<D, P> Add for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() + other.quantity())
+    }
+}
+
+// Bandwidth - Bandwidth => Bandwidth
+impl<D, P> Sub for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.quantity() - other.quantity())
+    }
+}
+
+// Sum of Bandwidth => Bandwidth
+impl<D, P> Sum for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
+    }
+}
+
+// Bandwidth * f64 => Bandwidth
+impl<D, P> Mul<f64> for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() * scalar)
+    }
+}
+
+// f64 * Bandwidth => Bandwidth
+impl<D, P> Mul<Bandwidth<D, P>> for f64
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Bandwidth<D, P>;
+    fn mul(self, other: Bandwidth<D, P>) -> Self::Output {
+        Bandwidth::new(self * other.quantity())
+    }
+}
+
+// i32 * Bandwidth => Bandwidth
+impl<D, P> Mul<Bandwidth<D, P>> for i32
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Bandwidth<D, P>;
+    fn mul(self, other: Bandwidth<D, P>) -> Self::Output {
+        Bandwidth::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Bandwidth * u32 => Bandwidth
+impl<D, P> Mul<u32> for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn mul(self, count: u32) -> Self::Output {
+        Self::new(self.quantity() * f64::from(count))
+    }
+}
+
+// u32 * Bandwidth => Bandwidth
+impl<D, P> Mul<Bandwidth<D, P>> for u32
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Bandwidth<D, P>;
+    fn mul(self, other: Bandwidth<D, P>) -> Self::Output {
+        Bandwidth::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Bandwidth / f64 => Bandwidth
+impl<D, P> Div<f64> for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        Self::new(self.quantity() / scalar)
+    }
+}
+
+// Bandwidth += Bandwidth
+impl<D, P> AddAssign for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() + other.quantity());
+    }
+}
+
+// Bandwidth -= Bandwidth
+impl<D, P> SubAssign for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() - other.quantity());
+    }
+}
+
+// Bandwidth *= f64
+impl<D, P> MulAssign<f64> for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() * scalar);
+    }
+}
+
+// Bandwidth /= f64
+impl<D, P> DivAssign<f64> for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() / scalar);
+    }
+}
+
+// DataSize / Period => Bandwidth
+//
+// A generic impl would overlap with Energy / Period, so this is implemented
+// for each information unit.
+macro_rules! impl_size_div_period {
+    ($($unit:ident),+) => {
+        $(
+            impl<P> Div<Period<P>> for Quantity<$unit>
+            where
+                P: time::Unit,
+            {
+                type Output = Bandwidth<$unit, P>;
+                fn div(self, per: Period<P>) -> Self::Output {
+                    Bandwidth::new(self.value() / per.quantity())
+                }
+            }
+        )+
+    };
+}
+
+impl_size_div_period!(
+    bit, kbit, Mbit, Gbit, B, kB, MB, GB, TB, KiB, MiB, GiB, TiB
+);
+
+// Bandwidth * Period => DataSize
+impl<D, P> Mul<Period<P>> for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Quantity<D>;
+    fn mul(self, per: Period<P>) -> Self::Output {
+        Quantity::new(self.quantity() * per.quantity())
+    }
+}
+
+// DataSize / Bandwidth => Period
+impl<D, E, P> Div<Bandwidth<E, P>> for Quantity<D>
+where
+    D: Unit<Measure = Information>,
+    E: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Output = Period<P>;
+    fn div(self, bw: Bandwidth<E, P>) -> Self::Output {
+        Period::new(self.to::<E>().value() / bw.quantity())
+    }
+}
+
+impl_base_ops!(
+    @access Bandwidth<D, P>
+    where
+        D: Unit<Measure = Information>,
+        P: time::Unit
+);
+
+impl<D, P> Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    /// Create a new bandwidth quantity
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
+        Bandwidth::<D, P> {
+            quantity: crate::check_finite(quantity),
+            data: PhantomData,
+            period: PhantomData,
+        }
+    }
+
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.quantity() * count as f64)
+    }
+
+    /// Change the units **without converting** the quantity.
+    ///
+    /// This is an escape hatch for cases where the quantity is already known
+    /// to be in the target units, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<E, R>(self) -> Bandwidth<E, R>
+    where
+        E: Unit<Measure = Information>,
+        R: time::Unit,
+    {
+        Bandwidth::new(self.quantity())
+    }
+
+    /// Convert to specified units
+    pub const fn to<E, R>(self) -> Bandwidth<E, R>
+    where
+        E: Unit<Measure = Information>,
+        R: time::Unit,
+    {
+        let factor = (D::FACTOR / E::FACTOR) / <P as time::Factor<R>>::FACTOR;
+        Bandwidth::new(self.quantity() * factor)
+    }
+
+    /// Convert to base units (`B/s`)
+    pub const fn to_base(self) -> Bandwidth<B, time::s> {
+        self.to()
+    }
+}
+
+impl<D, P> Magnitude for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn magnitude(&self) -> f64 {
+        self.quantity()
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<D, P> Scalable for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+}
+
+impl<D, P> Label for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(D::LABEL)?;
+        w.write_char('/')?;
+        w.write_str(P::LABEL)
+    }
+}
+
+impl<D, P> fmt::Display for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_quantity(self, f)
+    }
+}
+
+impl<D, P> FromStr for Bandwidth<D, P>
+where
+    D: Unit<Measure = Information>,
+    P: time::Unit,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::time::{h, ms, s};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn data_display() {
+        assert_eq!((512 * B).to_string(), "512 B");
+        assert_eq!((1.5 * MiB).to_string(), "1.5 MiB");
+        assert_eq!((100.0 * Mbit).to_string(), "100 Mbit");
+        assert_eq!("2 TB".parse(), Ok(2 * TB));
+    }
+
+    #[test]
+    fn data_to() {
+        assert_eq!((1.0 * KiB).to(), 1_024.0 * B);
+        assert_eq!((1.0 * GiB).to(), 1_024.0 * MiB);
+        assert_eq!((1.0 * TiB).to(), 1_024.0 * GiB);
+        assert_eq!((1.0 * GB).to(), 1_000.0 * MB);
+        assert_eq!((1.0 * B).to(), 8.0 * bit);
+        assert_eq!((1.0 * Gbit).to(), 1_000_000.0 * kbit);
+        assert_eq!(format!("{:.3}", (1.0 * GiB).to::<GB>()), "1.074 GB");
+        assert_eq!(format!("{:.3}", (1.0 * TB).to::<TiB>()), "0.909 TiB");
+    }
+
+    #[test]
+    fn bandwidth_display() {
+        let bw = Bandwidth::<MiB, s>::new(1.5);
+        assert_eq!(bw.to_string(), "1.5 MiB/s");
+        assert_eq!(Bandwidth::<Mbit, s>::new(54.0).to_string(), "54 Mbit/s");
+        assert_eq!("1.5 MiB/s".parse(), Ok(bw));
+    }
+
+    #[test]
+    fn bandwidth_to() {
+        let bw = Bandwidth::<Mbit, s>::new(8.0);
+        assert_eq!(bw.to::<MB, s>(), Bandwidth::new(1.0));
+        assert_eq!(bw.to::<GB, h>(), Bandwidth::new(3.6));
+        assert_eq!(bw.to_base(), Bandwidth::new(1_000_000.0));
+        assert_eq!(
+            format!("{:.4}", Bandwidth::<MiB, s>::new(1.0).to::<Mbit, s>()),
+            "8.3886 Mbit/s"
+        );
+    }
+
+    #[test]
+    fn bandwidth_ops() {
+        let bw = (300.0 * kB) / (2.0 * s);
+        assert_eq!(bw, Bandwidth::new(150.0));
+        assert_eq!(bw * (4.0 * s), 600.0 * kB);
+        assert_eq!((450.0 * kB) / bw, 3.0 * s);
+        assert_eq!((1.0 * GB) / Bandwidth::<Mbit, s>::new(100.0), 80.0 * s);
+        assert_eq!(bw + bw, 2.0 * bw);
+        assert_eq!(bw - bw / 3.0, 2u32 * bw / 3.0);
+        let mut x = bw;
+        x += bw;
+        x *= 0.5;
+        assert_eq!(x, bw);
+        assert_eq!(
+            (1.0 * MB / (10.0 * ms)).to::<MB, s>(),
+            Bandwidth::new(100.0)
+        );
+    }
+}
@@ -0,0 +1,369 @@
+// json.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! JSON fragments for quantities, without serde
+//!
+//! A quantity is written as an object with `value` and `unit` members, where
+//! the unit is the same label used by `Display`.  Non-finite values are
+//! written as `null`, which is read back as NaN.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{json::Json, length::km, time::h, Length, Speed};
+//!
+//! let a = 2.5 * km;
+//! assert_eq!(a.to_json_fragment(), r#"{"value":2.5,"unit":"km"}"#);
+//!
+//! let b = Speed::<km, h>::from_json_fragment(r#"{"value":88,"unit":"km/h"}"#);
+//! assert_eq!(b, Ok(88.0 * km / h));
+//!
+//! let c = Length::<km>::from_json_fragment(r#"{"unit":"mi","value":5}"#);
+//! assert!(c.is_err());
+//! ```
+extern crate alloc;
+
+use crate::quan::Label;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+use core::str::Chars;
+
+/// Error parsing a JSON fragment
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JsonError {
+    /// Invalid JSON syntax, or unexpected members
+    Syntax,
+
+    /// Missing or invalid `value` member
+    Value,
+
+    /// Missing `unit` member, or wrong unit label
+    Unit,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonError::Syntax => write!(f, "invalid JSON syntax"),
+            JsonError::Value => write!(f, "invalid JSON value"),
+            JsonError::Unit => write!(f, "invalid JSON unit"),
+        }
+    }
+}
+
+impl core::error::Error for JsonError {}
+
+/// JSON fragment display of a quantity.
+///
+/// This writes the fragment without allocating.
+#[derive(Clone, Copy, Debug)]
+pub struct JsonFragment<'a, Q>(&'a Q);
+
+impl<Q> fmt::Display for JsonFragment<'_, Q>
+where
+    Q: Label,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.0.magnitude();
+        f.write_str("{\"value\":")?;
+        if value.is_finite() {
+            write!(f, "{value}")?;
+        } else {
+            f.write_str("null")?;
+        }
+        f.write_str(",\"unit\":\"")?;
+        Q::write_label(&mut Escape(f))?;
+        f.write_str("\"}")
+    }
+}
+
+/// Quantity which can be written to and read from JSON fragments.
+///
+/// This is implemented for all quantity types.
+pub trait Json: Label + Sized {
+    /// Get JSON fragment display, which does not allocate
+    fn json_fragment(&self) -> JsonFragment<'_, Self> {
+        JsonFragment(self)
+    }
+
+    /// Write as a JSON fragment string
+    fn to_json_fragment(&self) -> String {
+        self.json_fragment().to_string()
+    }
+
+    /// Read from a JSON fragment
+    fn from_json_fragment(json: &str) -> Result<Self, JsonError> {
+        let (value, unit) = Parser::new(json).parse_fragment()?;
+        let value = value.ok_or(JsonError::Value)?;
+        let unit = unit.ok_or(JsonError::Unit)?;
+        let mut chars = Unescape(unit.chars());
+        let mut matcher = Match(&mut chars);
+        if Self::write_label(&mut matcher).is_ok() && chars.next().is_none() {
+            Ok(Self::from_magnitude(value))
+        } else {
+            Err(JsonError::Unit)
+        }
+    }
+}
+
+impl<Q> Json for Q where Q: Label {}
+
+/// Writer which escapes JSON string characters
+struct Escape<'a, W: Write>(&'a mut W);
+
+impl<W: Write> Write for Escape<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' => self.0.write_str("\\\"")?,
+                '\\' => self.0.write_str("\\\\")?,
+                c if u32::from(c) < 0x20 => {
+                    write!(self.0, "\\u{:04x}", u32::from(c))?
+                }
+                c => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator of unescaped JSON string characters (`None` on invalid escape)
+struct Unescape<'a>(Chars<'a>);
+
+impl Iterator for Unescape<'_> {
+    type Item = Option<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.0.next()?;
+        if c != '\\' {
+            return Some(Some(c));
+        }
+        Some(match self.0.next() {
+            Some('"') => Some('"'),
+            Some('\\') => Some('\\'),
+            Some('/') => Some('/'),
+            Some('b') => Some('\u{8}'),
+            Some('f') => Some('\u{c}'),
+            Some('n') => Some('\n'),
+            Some('r') => Some('\r'),
+            Some('t') => Some('\t'),
+            Some('u') => {
+                let hex = self.0.as_str().get(..4);
+                let code = hex.and_then(|h| u32::from_str_radix(h, 16).ok());
+                self.0.nth(3);
+                code.and_then(char::from_u32)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Writer which matches written text against unescaped characters
+struct Match<'a, 'b>(&'a mut Unescape<'b>);
+
+impl Write for Match<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.0.next() != Some(Some(c)) {
+                return Err(fmt::Error);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parser for JSON fragments
+struct Parser<'a> {
+    /// Remaining input
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    /// Create a new parser
+    fn new(json: &'a str) -> Self {
+        Parser { rest: json }
+    }
+
+    /// Skip whitespace
+    fn skip_ws(&mut self) {
+        self.rest = self.rest.trim_start_matches([' ', '\t', '\n', '\r']);
+    }
+
+    /// Expect a character, after whitespace
+    fn expect(&mut self, c: char) -> Result<(), JsonError> {
+        self.skip_ws();
+        self.rest = self.rest.strip_prefix(c).ok_or(JsonError::Syntax)?;
+        Ok(())
+    }
+
+    /// Parse a string, returning it still escaped
+    fn parse_string(&mut self) -> Result<&'a str, JsonError> {
+        self.expect('"')?;
+        let mut escaped = false;
+        for (i, c) in self.rest.char_indices() {
+            match c {
+                '"' if !escaped => {
+                    let s = &self.rest[..i];
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(s);
+                }
+                '\\' => escaped = !escaped,
+                _ => escaped = false,
+            }
+        }
+        Err(JsonError::Syntax)
+    }
+
+    /// Parse a number or null
+    fn parse_value(&mut self) -> Result<f64, JsonError> {
+        self.skip_ws();
+        if let Some(rest) = self.rest.strip_prefix("null") {
+            self.rest = rest;
+            return Ok(f64::NAN);
+        }
+        let len = self
+            .rest
+            .find(|c: char| {
+                !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E')
+            })
+            .unwrap_or(self.rest.len());
+        let value = self.rest[..len].parse().map_err(|_| JsonError::Value)?;
+        self.rest = &self.rest[len..];
+        Ok(value)
+    }
+
+    /// Parse a fragment object
+    fn parse_fragment(
+        mut self,
+    ) -> Result<(Option<f64>, Option<&'a str>), JsonError> {
+        let mut value = None;
+        let mut unit = None;
+        self.expect('{')?;
+        loop {
+            match self.parse_string()? {
+                "value" if value.is_none() => {
+                    self.expect(':')?;
+                    value = Some(self.parse_value()?);
+                }
+                "unit" if unit.is_none() => {
+                    self.expect(':')?;
+                    unit = Some(self.parse_string()?);
+                }
+                _ => return Err(JsonError::Syntax),
+            }
+            self.skip_ws();
+            if let Some(rest) = self.rest.strip_prefix(',') {
+                self.rest = rest;
+            } else {
+                break;
+            }
+        }
+        self.expect('}')?;
+        self.skip_ws();
+        if self.rest.is_empty() {
+            Ok((value, unit))
+        } else {
+            Err(JsonError::Syntax)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{cm, m, um};
+    use crate::temp::DegC;
+    use crate::time::s;
+    use crate::{Area, Frequency, Length, Volume};
+
+    #[test]
+    fn to_json() {
+        assert_eq!(
+            (-1.5 * DegC).to_json_fragment(),
+            r#"{"value":-1.5,"unit":"°C"}"#
+        );
+        assert_eq!(
+            (2.0 * cm * cm).to_json_fragment(),
+            r#"{"value":2,"unit":"cm²"}"#
+        );
+        assert_eq!(
+            (60.0 / s).to_json_fragment(),
+            r#"{"value":60,"unit":"㎐"}"#
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "assert-finite"))]
+    fn json_null() {
+        use crate::mass::kg;
+        use crate::time::ms;
+        use crate::Period;
+
+        assert_eq!(
+            (f64::INFINITY * kg).to_json_fragment(),
+            r#"{"value":null,"unit":"kg"}"#
+        );
+        let nan =
+            Period::<ms>::from_json_fragment(r#"{"value":null,"unit":"ms"}"#);
+        assert!(nan.unwrap().quantity().is_nan());
+    }
+
+    #[test]
+    fn escape() {
+        crate::length_unit!(quote, "\"q\\", 1.0);
+        let json = (1.0 * quote).to_json_fragment();
+        assert_eq!(json, r#"{"value":1,"unit":"\"q\\"}"#);
+        assert_eq!(Length::from_json_fragment(&json), Ok(1.0 * quote));
+    }
+
+    #[test]
+    fn from_json() {
+        assert_eq!(
+            Length::<um>::from_json_fragment(r#"{"value":3.5,"unit":"μm"}"#),
+            Ok(3.5 * um)
+        );
+        assert_eq!(
+            Volume::<m>::from_json_fragment(
+                " { \"unit\" : \"m\\u00b3\" , \"value\" : 1e3 } "
+            ),
+            Ok(1_000.0 * m * m * m)
+        );
+        assert_eq!(
+            Frequency::<s>::from_json_fragment(r#"{"value":5,"unit":"㎐"}"#),
+            Ok(5.0 / s)
+        );
+    }
+
+    #[test]
+    fn json_errors() {
+        assert_eq!(
+            Area::<m>::from_json_fragment(r#"{"value":1,"unit":"m"}"#),
+            Err(JsonError::Unit)
+        );
+        assert_eq!(
+            Area::<m>::from_json_fragment(r#"{"value":1,"unit":"m²x"}"#),
+            Err(JsonError::Unit)
+        );
+        assert_eq!(
+            Length::<m>::from_json_fragment(r#"{"unit":"m"}"#),
+            Err(JsonError::Value)
+        );
+        assert_eq!(
+            Length::<m>::from_json_fragment(r#"{"value":"1","unit":"m"}"#),
+            Err(JsonError::Value)
+        );
+        assert_eq!(
+            Length::<m>::from_json_fragment(r#"{"value":1,"unit":"m"} x"#),
+            Err(JsonError::Syntax)
+        );
+        assert_eq!(
+            Length::<m>::from_json_fragment(r#"{"value":1,"value":2}"#),
+            Err(JsonError::Syntax)
+        );
+        assert_eq!(
+            Length::<m>::from_json_fragment(r#"{"value":1,"unit":"m"#),
+            Err(JsonError::Syntax)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// kalman.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! One-dimensional Kalman filter for typed quantities
+//!
+//! A [Kalman] filter estimates a quantity and its rate of change (such as
+//! position and speed) from noisy measurements.  All inputs and outputs are
+//! typed, so units are checked at the filter boundary.
+//!
+//! The model assumes a constant rate, which drifts as a random walk.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{kalman::Kalman, length::m, time::s};
+//!
+//! let mut filter = Kalman::new(0.0 * m, 0.0 * m / s, 0.1 * m / s, 2.0 * m);
+//! let noise = [1.5, -2.0, 0.5, 1.0, -1.5, 2.0, -0.5, 0.0];
+//! for (t, n) in (1..).zip(noise) {
+//!     // true speed is 10 m/s
+//!     let pos = (10.0 * f64::from(t) + n) * m;
+//!     filter.step(1.0 * s, pos);
+//! }
+//! assert_eq!(format!("{:.0}", filter.rate()), "10 m/s");
+//! ```
+use crate::quan::Magnitude;
+use crate::sqrt;
+use crate::{length, time, Acceleration, Length, Period, Speed};
+use core::marker::PhantomData;
+
+/// Quantity with a rate of change over time
+pub trait Rate<P>: Magnitude
+where
+    P: time::Unit,
+{
+    /// Rate quantity type (per `P` time unit)
+    type Rate: Magnitude;
+}
+
+impl<L, P> Rate<P> for Length<L>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Rate = Speed<L, P>;
+}
+
+impl<L, P> Rate<P> for Speed<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Rate = Acceleration<L, P>;
+}
+
+/// Kalman filter estimating a quantity and its rate
+#[derive(Clone, Debug)]
+pub struct Kalman<Q, P>
+where
+    Q: Rate<P>,
+    P: time::Unit,
+{
+    /// Estimated value
+    value: f64,
+
+    /// Estimated rate
+    rate: f64,
+
+    /// Estimate covariance matrix
+    cov: [[f64; 2]; 2],
+
+    /// Process noise (rate variance per time unit)
+    process: f64,
+
+    /// Measurement noise variance
+    measurement: f64,
+
+    /// Quantity type
+    quantity: PhantomData<Q>,
+
+    /// Period unit
+    period: PhantomData<P>,
+}
+
+impl<Q, P> Kalman<Q, P>
+where
+    Q: Rate<P>,
+    P: time::Unit,
+{
+    /// Create a new Kalman filter
+    ///
+    /// * `value` Initial value estimate
+    /// * `rate` Initial rate estimate
+    /// * `process_noise` Standard deviation of rate drift per time unit
+    /// * `measurement_noise` Standard deviation of measurements
+    ///
+    /// The initial estimates are given an uncertainty of one measurement
+    /// noise (value) and 10 measurement noises per time unit (rate).
+    pub fn new(
+        value: Q,
+        rate: Q::Rate,
+        process_noise: Q::Rate,
+        measurement_noise: Q,
+    ) -> Self {
+        let process = process_noise.magnitude();
+        let measurement = measurement_noise.magnitude();
+        let measurement = measurement * measurement;
+        Kalman {
+            value: value.magnitude(),
+            rate: rate.magnitude(),
+            cov: [[measurement, 0.0], [0.0, 100.0 * measurement]],
+            process: process * process,
+            measurement,
+            quantity: PhantomData,
+            period: PhantomData,
+        }
+    }
+
+    /// Get the estimated value
+    pub fn value(&self) -> Q {
+        Q::from_magnitude(self.value)
+    }
+
+    /// Get the estimated rate
+    pub fn rate(&self) -> Q::Rate {
+        Q::Rate::from_magnitude(self.rate)
+    }
+
+    /// Get the standard deviation of the value estimate
+    pub fn value_uncertainty(&self) -> Q {
+        Q::from_magnitude(sqrt(self.cov[0][0]))
+    }
+
+    /// Predict the state after a time step
+    pub fn predict(&mut self, dt: Period<P>) {
+        let dt = dt.quantity();
+        self.value += self.rate * dt;
+        let [[p00, p01], [p10, p11]] = self.cov;
+        self.cov = [
+            [p00 + dt * (p10 + p01) + dt * dt * p11, p01 + dt * p11],
+            [p10 + dt * p11, p11 + self.process * dt],
+        ];
+    }
+
+    /// Update the state with a measurement
+    pub fn update(&mut self, measurement: Q) {
+        let [[p00, p01], [p10, p11]] = self.cov;
+        let innovation = measurement.magnitude() - self.value;
+        let s = p00 + self.measurement;
+        let (k0, k1) = (p00 / s, p10 / s);
+        self.value += k0 * innovation;
+        self.rate += k1 * innovation;
+        self.cov = [
+            [(1.0 - k0) * p00, (1.0 - k0) * p01],
+            [p10 - k1 * p00, p11 - k1 * p01],
+        ];
+    }
+
+    /// Predict after a time step, then update with a measurement
+    ///
+    /// Returns the new value estimate.
+    pub fn step(&mut self, dt: Period<P>, measurement: Q) -> Q {
+        self.predict(dt);
+        self.update(measurement);
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::time::s;
+    use approx::assert_abs_diff_eq;
+
+    /// Tolerance for square roots
+    const EPS: f64 = 1e-12;
+
+    #[test]
+    fn kalman_exact() {
+        let mut f =
+            Kalman::new(0.0 * ft, 0.0 * ft / s, 0.01 * ft / s, 0.5 * ft);
+        for i in 1..=50 {
+            f.step(0.5 * s, (3.0 * f64::from(i)) * ft);
+        }
+        assert_abs_diff_eq!(f.rate().quantity(), 6.0, epsilon = 0.01);
+        assert_abs_diff_eq!(f.value().quantity(), 150.0, epsilon = 0.01);
+        assert!(f.value_uncertainty() < 0.5 * ft);
+    }
+
+    #[test]
+    fn kalman_speed() {
+        // estimate speed and acceleration
+        let mut f = Kalman::new(
+            0.0 * m / s,
+            0.0 * m / s / s,
+            0.1 * m / s / s,
+            1.0 * m / s,
+        );
+        let noise = [0.8, -0.6, 0.3, -0.9, 0.5, 0.1, -0.4, 0.7, -0.2, -0.3];
+        for (i, n) in noise.iter().cycle().take(40).enumerate() {
+            let t = f64::from(i as u32 + 1);
+            f.step(1.0 * s, (2.0 * t + n) * m / s);
+        }
+        assert_abs_diff_eq!(f.rate().quantity(), 2.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn kalman_predict() {
+        let mut f = Kalman::new(10.0 * m, 2.0 * m / s, 0.0 * m / s, 1.0 * m);
+        f.predict(3.0 * s);
+        assert_eq!(f.value(), 16.0 * m);
+        assert_eq!(f.rate(), 2.0 * m / s);
+    }
+
+    #[test]
+    fn square_root() {
+        assert_eq!(sqrt(0.0), 0.0);
+        assert_eq!(sqrt(4.0), 2.0);
+        assert_eq!(sqrt(0.25), 0.5);
+        assert_abs_diff_eq!(
+            sqrt(2.0),
+            core::f64::consts::SQRT_2,
+            epsilon = EPS
+        );
+        assert_abs_diff_eq!(sqrt(1e10), 1e5, epsilon = EPS);
+        assert!(sqrt(-1.0).is_nan());
+    }
+}
@@ -0,0 +1,151 @@
+// key.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Quantities as map keys
+//!
+//! Quantity types only implement `PartialEq` and `PartialOrd`, since their
+//! magnitudes are `f64`.  A [Key] wraps a quantity and compares its magnitude
+//! by bit pattern, implementing `Eq`, `Ord` and `Hash`.  This allows using
+//! quantities as keys in a `HashMap` or `BTreeMap`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{key::Key, length::m};
+//! use std::collections::HashMap;
+//!
+//! let mut cache = HashMap::new();
+//! cache.insert(Key(2.5 * m), "first");
+//! cache.insert(Key(2.5 * m), "second");
+//!
+//! assert_eq!(cache.len(), 1);
+//! assert_eq!(cache[&Key(2.5 * m)], "second");
+//! ```
+use crate::quan::Magnitude;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Quantity wrapper which is hashed and compared by bit pattern
+///
+/// Magnitudes are normalized first: `-0.0` is equal to `0.0`, and all NaN
+/// values are equal to each other.  Keys are ordered with [f64::total_cmp],
+/// so NaN is greater than infinity.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Key<Q>(pub Q)
+where
+    Q: Magnitude;
+
+impl<Q> Key<Q>
+where
+    Q: Magnitude,
+{
+    /// Get the wrapped quantity
+    pub fn into_inner(self) -> Q {
+        self.0
+    }
+
+    /// Get the bit pattern of the normalized magnitude
+    pub fn to_bits(&self) -> u64 {
+        normalize(self.0.magnitude()).to_bits()
+    }
+}
+
+/// Normalize a magnitude for comparison by bit pattern
+fn normalize(value: f64) -> f64 {
+    if value.is_nan() {
+        f64::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+impl<Q> From<Q> for Key<Q>
+where
+    Q: Magnitude,
+{
+    fn from(quan: Q) -> Self {
+        Key(quan)
+    }
+}
+
+impl<Q> PartialEq for Key<Q>
+where
+    Q: Magnitude,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl<Q> Eq for Key<Q> where Q: Magnitude {}
+
+impl<Q> PartialOrd for Key<Q>
+where
+    Q: Magnitude,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Q> Ord for Key<Q>
+where
+    Q: Magnitude,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let a = normalize(self.0.magnitude());
+        let b = normalize(other.0.magnitude());
+        a.total_cmp(&b)
+    }
+}
+
+impl<Q> Hash for Key<Q>
+where
+    Q: Magnitude,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::mass::kg;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn key_eq() {
+        assert_eq!(Key(1.5 * m), Key(1.5 * m));
+        assert_ne!(Key(1.5 * m), Key(1.6 * m));
+        assert_eq!(Key(-0.0 * ft), Key(0.0 * ft));
+        assert_eq!(Key(0.0 * ft).to_bits(), Key(-0.0 * ft).to_bits());
+        assert_eq!(Key(2.0 * kg).into_inner(), 2.0 * kg);
+    }
+
+    #[test]
+    #[cfg(not(feature = "assert-finite"))]
+    fn key_nan() {
+        let nan = Key(f64::NAN * m);
+        assert_eq!(nan, Key(-f64::NAN * m));
+        assert!(nan > Key(f64::INFINITY * m));
+    }
+
+    #[test]
+    fn key_map() {
+        let mut map = BTreeMap::new();
+        map.insert(Key(3.0 * m), 'c');
+        map.insert(Key(-1.0 * m), 'a');
+        map.insert(Key(0.0 * m), 'b');
+        map.insert(Key(-0.0 * m), 'z');
+        let keys: alloc::vec::Vec<_> = map.keys().map(|k| k.0).collect();
+        assert_eq!(keys, [-1.0 * m, 0.0 * m, 3.0 * m]);
+        assert_eq!(map[&Key(0.0 * m)], 'z');
+    }
+}
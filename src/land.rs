@@ -0,0 +1,140 @@
+// land.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of land area.
+//!
+//! Each unit is defined relative to square meters with a conversion factor.
+//! They can be used to conveniently create LandArea quantities.  Named units
+//! such as hectares and acres are displayed with their own symbols, not as
+//! squared lengths.
+//!
+//! Land areas can be converted to and from [Area] quantities of squared
+//! length units.  The [m2] unit is a shortcut for square meters.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::land::{ac, ha};
+//! use mag::length::{ft, m};
+//!
+//! let a = 5.0 * ha;
+//! let b = 2 * ac;
+//!
+//! assert_eq!(a.to_string(), "5 ha");
+//! assert_eq!(b.to_string(), "2 ac");
+//! assert_eq!(a.to_area::<m>(), 50_000.0 * m * m);
+//! assert_eq!(format!("{:.1}", b.to_area::<ft>()), "87120.0 ft²");
+//! assert_eq!(format!("{:.4}", (100.0 * m * m).to_hectares()), "0.0100 ha");
+//! ```
+//!
+//! [Area]: ../struct.Area.html
+use crate::declare_unit;
+use crate::length::{self, m};
+use crate::quan::{LandArea, Quantity, Unit};
+use crate::Area;
+
+declare_unit!(
+    /** Square meter / Square metre */
+    m2,
+    "m²",
+    LandArea,
+    1.0,
+    "square meter",
+    "square meters",
+);
+
+declare_unit!(
+    /** Hectare (10,000 m²) */
+    ha,
+    "ha",
+    LandArea,
+    10_000.0,
+    "hectare",
+    "hectares",
+);
+
+declare_unit!(
+    /** Acre (international, 43,560 ft²) */
+    ac,
+    "ac",
+    LandArea,
+    4_046.856_422_4,
+    "acre",
+    "acres",
+);
+
+unit_fns!(
+    hectares => Quantity<ha>::new_const,
+    acres => Quantity<ac>::new_const,
+);
+
+impl<U> Quantity<U>
+where
+    U: Unit<Measure = LandArea>,
+{
+    /// Convert to an area of squared length units
+    pub fn to_area<T>(self) -> Area<T>
+    where
+        T: length::Unit,
+    {
+        Area::<m>::new(self.to::<m2>().value()).to()
+    }
+}
+
+impl<T> Area<T>
+where
+    T: length::Unit,
+{
+    /// Convert to a land area quantity
+    pub fn to_land<U>(self) -> Quantity<U>
+    where
+        U: Unit<Measure = LandArea>,
+    {
+        Quantity::<m2>::new(self.to::<m>().quantity()).to()
+    }
+
+    /// Convert to a quantity of hectares
+    pub fn to_hectares(self) -> Quantity<ha> {
+        self.to_land()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, km, mi};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn land_display() {
+        assert_eq!((5.0 * ha).to_string(), "5 ha");
+        assert_eq!((0.25 * ac).to_string(), "0.25 ac");
+        assert_eq!((120 * m2).to_string(), "120 m²");
+        assert_eq!(format!("{:#}", 120 * m2), "120 m^2");
+        assert_eq!(hectares(1.5), 1.5 * ha);
+        assert_eq!("640 ac".parse(), Ok(acres(640.0)));
+    }
+
+    #[test]
+    fn land_to() {
+        assert_eq!((1.0 * ha).to(), 10_000.0 * m2);
+        assert_eq!(format!("{:.4}", (1.0 * ac).to::<ha>()), "0.4047 ha");
+        assert_eq!(format!("{:.3}", (1.0 * ha).to::<ac>()), "2.471 ac");
+    }
+
+    #[test]
+    fn land_area() {
+        assert_eq!((1.0 * ha).to_area(), 10_000.0 * m * m);
+        assert_eq!((100.0 * ha).to_area(), 1.0 * km * km);
+        assert_eq!((1.0 * ac).to_area::<ft>(), 43_560.00000000001 * ft * ft);
+        assert_eq!(
+            format!("{:.1}", (1.0 * mi * mi).to_land::<ac>()),
+            "640.0 ac"
+        );
+        assert_eq!((25.0 * m * m).to_land(), 25.0 * m2);
+        assert_eq!((1.0 * km * km).to_hectares(), 100.0 * ha);
+    }
+}
@@ -5,7 +5,8 @@
 //
 //! Private module for length structs
 //!
-use crate::length::Unit;
+use crate::length::{Factor, Unit};
+use crate::quan::{fmt_quantity, Label, Quantity};
 use core::fmt;
 use core::marker::PhantomData;
 use core::ops::{Add, Div, Mul, Sub};
@@ -18,13 +19,23 @@ use core::ops::{Add, Div, Mul, Sub};
 ///
 /// * f64 `*` [unit] `=>` Length
 /// * i32 `*` [unit] `=>` Length
+/// * [unit] `*` f64, f32, i32, u32, i64 or u64 `=>` Length
 /// * Length `+` Length `=>` Length
 /// * Length `-` Length `=>` Length
 /// * Length `*` f64 `=>` Length
+/// * Length `*` u32 `=>` Length
+/// * i32 `*` Length `=>` Length
+/// * u32 `*` Length `=>` Length
 /// * f64 `*` Length `=>` Length
 /// * Length `*` Length `=>` [Area]
 /// * Length `*` [unit] `=>` [Area]
 /// * Length `/` f64 `=>` Length
+/// * Length `/` Length `=>` [Ratio]
+/// * Length `/` [Area] `=>` [InverseLength]
+/// * Length `+=` Length
+/// * Length `-=` Length
+/// * Length `*=` f64
+/// * Length `/=` f64
 ///
 /// Units must be the same for operations with two Length operands.  The [to]
 /// method can be used for conversion.
@@ -41,6 +52,8 @@ use core::ops::{Add, Div, Mul, Sub};
 /// assert_eq!((a + b).to(), 0.1 * m);
 /// ```
 /// [Area]: struct.Area.html
+/// [InverseLength]: struct.InverseLength.html
+/// [Ratio]: ratio/struct.Ratio.html
 /// [unit]: length/index.html
 /// [to]: struct.Length.html#method.to
 ///
@@ -50,6 +63,7 @@ where
     U: Unit,
 {
     /// Length quantity
+    #[deprecated(note = "use the `quantity()` method")]
     pub quantity: f64,
 
     /// Measurement unit
@@ -65,9 +79,18 @@ where
 /// * Area `+` Area `=>` Area
 /// * Area `-` Area `=>` Area
 /// * Area `*` f64 `=>` Area
+/// * Area `*` u32 `=>` Area
+/// * i32 `*` Area `=>` Area
+/// * u32 `*` Area `=>` Area
 /// * Area `*` [Length] `=>` [Volume]
 /// * Area `/` f64 `=>` Area
+/// * Area `+=` Area
+/// * Area `-=` Area
+/// * Area `*=` f64
+/// * Area `/=` f64
 /// * Area `/` [Length] `=>` [Length]
+/// * Area `/` Area `=>` [Ratio]
+/// * Area `/` [Volume] `=>` [InverseLength]
 ///
 /// ## Example
 ///
@@ -81,8 +104,10 @@ where
 /// assert_eq!(a.to_string(), "150 m²");
 /// assert_eq!(a / (5.0 * m), 30.0 * m);
 /// ```
-/// [unit]: length/index.html
+/// [InverseLength]: struct.InverseLength.html
 /// [Length]: struct.Length.html
+/// [Ratio]: ratio/struct.Ratio.html
+/// [unit]: length/index.html
 /// [Volume]: struct.Volume.html
 ///
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -91,6 +116,7 @@ where
     U: Unit,
 {
     /// Area quantity
+    #[deprecated(note = "use the `quantity()` method")]
     pub quantity: f64,
 
     /// Measurement unit
@@ -106,9 +132,19 @@ where
 /// * Volume `+` Volume `=>` Volume
 /// * Volume `-` Volume `=>` Volume
 /// * Volume `*` f64 `=>` Volume
+/// * Volume `*` u32 `=>` Volume
+/// * i32 `*` Volume `=>` Volume
+/// * u32 `*` Volume `=>` Volume
 /// * Volume `/` f64 `=>` Volume
+/// * Volume `+=` Volume
+/// * Volume `-=` Volume
+/// * Volume `*=` f64
+/// * Volume `/=` f64
 /// * Volume `/` [Length] `=>` [Area]
 /// * Volume `/` [Area] `=>` [Length]
+/// * Volume `/` Volume `=>` [Ratio]
+/// * Volume `/` [Period] `=>` [FlowRate]
+/// * Volume `/` [FlowRate] `=>` [Period]
 ///
 /// ## Example
 ///
@@ -122,8 +158,11 @@ where
 /// assert_eq!(b.to_string(), "1.25 yd²");
 /// ```
 /// [Area]: struct.Area.html
+/// [FlowRate]: struct.FlowRate.html
 /// [unit]: length/index.html
 /// [Length]: struct.Length.html
+/// [Period]: struct.Period.html
+/// [Ratio]: ratio/struct.Ratio.html
 ///
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub struct Volume<U>
@@ -131,33 +170,119 @@ where
     U: Unit,
 {
     /// Volume quantity
+    #[deprecated(note = "use the `quantity()` method")]
     pub quantity: f64,
 
     /// Measurement unit
     unit: PhantomData<U>,
 }
 
-impl_base_ops!(Length, Unit);
-impl_base_ops!(Area, Unit);
-impl_base_ops!(Volume, Unit);
+/// Spatial frequency, or _inverse length_.
+///
+/// InverseLength is a derived quantity with a specific [unit], such as a
+/// wavenumber or the power of a lens in diopters (`/m`).
+///
+/// ## Operations
+///
+/// * InverseLength `+` InverseLength `=>` InverseLength
+/// * InverseLength `-` InverseLength `=>` InverseLength
+/// * InverseLength `*` f64 `=>` InverseLength
+/// * InverseLength `*` u32 `=>` InverseLength
+/// * i32 `*` InverseLength `=>` InverseLength
+/// * u32 `*` InverseLength `=>` InverseLength
+/// * f64 `*` InverseLength `=>` InverseLength
+/// * f64 `/` [unit] `=>` InverseLength
+/// * i32 `/` [unit] `=>` InverseLength
+/// * f64 `/` [Length] `=>` InverseLength
+/// * f64 `/` InverseLength `=>` [Length]
+/// * [Length] `/` [Area] `=>` InverseLength
+/// * [Area] `/` [Volume] `=>` InverseLength
+/// * InverseLength `/` InverseLength `=>` [Ratio]
+/// * InverseLength `*` [Length] `=>` f64
+/// * InverseLength `+=` InverseLength
+/// * InverseLength `-=` InverseLength
+/// * InverseLength `*=` f64
+/// * InverseLength `/=` f64
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::length::{cm, m};
+///
+/// let focal = 25.0 * cm;
+/// let power = 1.0 / focal.to::<m>(); // InverseLength<m>
+///
+/// assert_eq!(power.to_string(), "4 /m");
+/// assert_eq!((2.0 * cm) / (8.0 * cm * cm), 0.25 / cm);
+/// ```
+/// [Area]: struct.Area.html
+/// [Length]: struct.Length.html
+/// [Ratio]: ratio/struct.Ratio.html
+/// [unit]: length/index.html
+/// [Volume]: struct.Volume.html
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct InverseLength<U>
+where
+    U: Unit,
+{
+    /// Inverse length quantity
+    #[deprecated(note = "use the `quantity()` method")]
+    pub quantity: f64,
+
+    /// Measurement unit
+    unit: PhantomData<U>,
+}
+
+impl_base_ops!(Length, Unit, super::m);
+impl_base_ops!(Area, Unit, super::m);
+impl_base_ops!(Volume, Unit, super::m);
+impl_base_ops!(InverseLength, Unit, super::m);
 
 impl<U> Length<U>
 where
     U: Unit,
 {
     /// Create a new length quantity
-    pub fn new(quantity: f64) -> Self {
+    ///
+    /// This is a `const fn`, as is [to], so constants can be defined and
+    /// converted at compile time.
+    ///
+    /// ```rust
+    /// use mag::{length::{km, mi}, Length};
+    ///
+    /// const MARATHON: Length<km> = Length::new(42.195);
+    /// const MARATHON_MI: Length<mi> = MARATHON.to();
+    ///
+    /// assert_eq!(format!("{:.3}", MARATHON_MI), "26.219 mi");
+    /// ```
+    ///
+    /// [to]: #method.to
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
         Length::<U> {
-            quantity,
+            quantity: crate::check_finite(quantity),
             unit: PhantomData,
         }
     }
 
     /// Convert to specified units
-    pub fn to<T: Unit>(self) -> Length<T> {
-        let quantity = self.quantity * U::factor::<T>();
+    pub const fn to<T: Unit>(self) -> Length<T> {
+        let quantity = self.quantity() * <U as Factor<T>>::FACTOR;
         Length::new(quantity)
     }
+
+    /// Get the reciprocal inverse length (spatial frequency)
+    ///
+    /// ```rust
+    /// use mag::length::{cm, m};
+    ///
+    /// assert_eq!((0.5 * m).recip(), 2.0 / m);
+    /// assert_eq!((20.0 * cm).recip().to::<m>(), 5.0 / m);
+    /// ```
+    pub fn recip(self) -> InverseLength<U> {
+        InverseLength::new(1.0 / self.quantity())
+    }
 }
 
 impl<U> Area<U>
@@ -165,19 +290,44 @@ where
     U: Unit,
 {
     /// Create a new area quantity
-    pub fn new(quantity: f64) -> Self {
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
         Area::<U> {
-            quantity,
+            quantity: crate::check_finite(quantity),
             unit: PhantomData,
         }
     }
 
     /// Convert to specified units
-    pub fn to<T: Unit>(self) -> Area<T> {
-        let factor = U::factor::<T>() * U::factor::<T>();
-        let quantity = self.quantity * factor;
+    pub const fn to<T: Unit>(self) -> Area<T> {
+        let per = <U as Factor<T>>::FACTOR;
+        let factor = per * per;
+        let quantity = self.quantity() * factor;
         Area::new(quantity)
     }
+
+    /// Calculate the area of a simple polygon
+    ///
+    /// Vertices are `(x, y)` pairs, in either winding order.  The polygon is
+    /// closed automatically (the last vertex connects to the first).
+    ///
+    /// ```rust
+    /// use mag::{length::m, Area};
+    ///
+    /// let triangle = [(0.0 * m, 0.0 * m), (4.0 * m, 0.0 * m), (0.0 * m, 3.0 * m)];
+    /// assert_eq!(Area::polygon(&triangle), 6.0 * m * m);
+    /// ```
+    pub fn polygon(vertices: &[(Length<U>, Length<U>)]) -> Self {
+        // shoelace formula
+        let mut twice = 0.0;
+        for (i, (x0, y0)) in vertices.iter().enumerate() {
+            let (x1, y1) = &vertices[(i + 1) % vertices.len()];
+            twice +=
+                x0.quantity() * y1.quantity() - x1.quantity() * y0.quantity();
+        }
+        let twice = if twice < 0.0 { -twice } else { twice };
+        Area::new(twice / 2.0)
+    }
 }
 
 impl<U> Volume<U>
@@ -185,28 +335,100 @@ where
     U: Unit,
 {
     /// Create a new volume quantity
-    pub fn new(quantity: f64) -> Self {
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
         Volume::<U> {
-            quantity,
+            quantity: crate::check_finite(quantity),
             unit: PhantomData,
         }
     }
 
     /// Convert to specified units
-    pub fn to<T: Unit>(self) -> Volume<T> {
-        let factor = U::factor::<T>() * U::factor::<T>() * U::factor::<T>();
-        let quantity = self.quantity * factor;
+    pub const fn to<T: Unit>(self) -> Volume<T> {
+        let per = <U as Factor<T>>::FACTOR;
+        let factor = per * per * per;
+        let quantity = self.quantity() * factor;
         Volume::new(quantity)
     }
 }
 
+impl<U> InverseLength<U>
+where
+    U: Unit,
+{
+    /// Create a new inverse length quantity
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
+        InverseLength::<U> {
+            quantity: crate::check_finite(quantity),
+            unit: PhantomData,
+        }
+    }
+
+    /// Convert to specified units
+    pub const fn to<T: Unit>(self) -> InverseLength<T> {
+        let quantity = self.quantity() / <U as Factor<T>>::FACTOR;
+        InverseLength::new(quantity)
+    }
+
+    /// Get the reciprocal length (wavelength)
+    ///
+    /// ```rust
+    /// use mag::length::{cm, nm};
+    ///
+    /// assert_eq!((2.0 / cm).recip(), 0.5 * cm);
+    /// assert_eq!((0.002 / nm).recip(), 500.0 * nm);
+    /// ```
+    pub fn recip(self) -> Length<U> {
+        Length::new(1.0 / self.quantity())
+    }
+}
+
+// Quantity => Length
+impl<U> From<Quantity<U>> for Length<U>
+where
+    U: Unit,
+{
+    fn from(q: Quantity<U>) -> Self {
+        Length::new(q.value())
+    }
+}
+
+// Length => Quantity
+impl<U> From<Length<U>> for Quantity<U>
+where
+    U: Unit,
+{
+    fn from(q: Length<U>) -> Self {
+        Quantity::new(q.quantity())
+    }
+}
+
+impl<U> Label for Length<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::LABEL)
+    }
+}
+
 impl<U> fmt::Display for Length<U>
 where
     U: Unit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.quantity.fmt(f)?;
-        write!(f, " {}", U::LABEL)
+        fmt_quantity(self, f)
+    }
+}
+
+impl<U> Label for Area<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::LABEL)?;
+        w.write_char('²')
     }
 }
 
@@ -215,8 +437,17 @@ where
     U: Unit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.quantity.fmt(f)?;
-        write!(f, " {}²", U::LABEL)
+        fmt_quantity(self, f)
+    }
+}
+
+impl<U> Label for Volume<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::LABEL)?;
+        w.write_char('³')
     }
 }
 
@@ -225,8 +456,7 @@ where
     U: Unit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.quantity.fmt(f)?;
-        write!(f, " {}³", U::LABEL)
+        fmt_quantity(self, f)
     }
 }
 
@@ -237,7 +467,7 @@ where
 {
     type Output = Area<U>;
     fn mul(self, other: Self) -> Self::Output {
-        Area::new(self.quantity * other.quantity)
+        Area::new(self.quantity() * other.quantity())
     }
 }
 
@@ -248,7 +478,7 @@ where
 {
     type Output = Volume<U>;
     fn mul(self, other: Length<U>) -> Self::Output {
-        Volume::new(self.quantity * other.quantity)
+        Volume::new(self.quantity() * other.quantity())
     }
 }
 
@@ -259,7 +489,7 @@ where
 {
     type Output = Length<U>;
     fn div(self, other: Length<U>) -> Self::Output {
-        Length::new(self.quantity / other.quantity)
+        Length::new(self.quantity() / other.quantity())
     }
 }
 
@@ -270,7 +500,7 @@ where
 {
     type Output = Area<U>;
     fn div(self, other: Length<U>) -> Self::Output {
-        Area::new(self.quantity / other.quantity)
+        Area::new(self.quantity() / other.quantity())
     }
 }
 
@@ -281,6 +511,91 @@ where
 {
     type Output = Length<U>;
     fn div(self, other: Area<U>) -> Self::Output {
-        Length::new(self.quantity / other.quantity)
+        Length::new(self.quantity() / other.quantity())
+    }
+}
+
+impl<U> Label for InverseLength<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_char('/')?;
+        w.write_str(U::LABEL)
+    }
+}
+
+impl<U> fmt::Display for InverseLength<U>
+where
+    U: Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_quantity(self, f)
+    }
+}
+
+// f64 / Length => InverseLength
+impl<U> Div<Length<U>> for f64
+where
+    U: Unit,
+{
+    type Output = InverseLength<U>;
+    fn div(self, other: Length<U>) -> Self::Output {
+        Self::Output::new(self / other.quantity())
+    }
+}
+
+// f64 / InverseLength => Length
+impl<U> Div<InverseLength<U>> for f64
+where
+    U: Unit,
+{
+    type Output = Length<U>;
+    fn div(self, other: InverseLength<U>) -> Self::Output {
+        Self::Output::new(self / other.quantity())
+    }
+}
+
+// Length / Area => InverseLength
+impl<U> Div<Area<U>> for Length<U>
+where
+    U: Unit,
+{
+    type Output = InverseLength<U>;
+    fn div(self, other: Area<U>) -> Self::Output {
+        InverseLength::new(self.quantity() / other.quantity())
+    }
+}
+
+// Area / Volume => InverseLength
+impl<U> Div<Volume<U>> for Area<U>
+where
+    U: Unit,
+{
+    type Output = InverseLength<U>;
+    fn div(self, other: Volume<U>) -> Self::Output {
+        InverseLength::new(self.quantity() / other.quantity())
+    }
+}
+
+// InverseLength * Length => f64
+impl<U> Mul<Length<U>> for InverseLength<U>
+where
+    U: Unit,
+{
+    type Output = f64;
+    fn mul(self, len: Length<U>) -> Self::Output {
+        self.quantity() * len.quantity()
+    }
+}
+
+// Length * InverseLength => f64
+impl<U> Mul<InverseLength<U>> for Length<U>
+where
+    U: Unit,
+{
+    type Output = f64;
+    fn mul(self, inv: InverseLength<U>) -> Self::Output {
+        self.quantity() * inv.quantity()
     }
 }
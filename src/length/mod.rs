@@ -24,6 +24,20 @@
 //! assert_eq!(c.to_string(), "1.2 m²");
 //! assert_eq!(format!("{:.2}", d), "5.26 yd³");
 //! ```
+//!
+//! Functions named after units can be used instead of multiplication, even in
+//! const contexts:
+//!
+//! ```rust
+//! use mag::length::{feet, ft, m, meters, square_meters};
+//! use mag::Length;
+//!
+//! const WIDTH: Length<m> = meters(2.5);
+//!
+//! assert_eq!(WIDTH, 2.5 * m);
+//! assert_eq!(feet(6.0), 6.0 * ft);
+//! assert_eq!(square_meters(4.0), 4.0 * m * m);
+//! ```
 //! [Area]: ../struct.Area.html
 //! [Length]: ../struct.Length.html
 //! [Volume]: ../struct.Volume.html
@@ -32,27 +46,58 @@ extern crate alloc;
 
 pub(crate) mod lenpriv;
 
+use crate::quan::{self, Distance};
+use crate::{Area, Length, Volume};
+
 /// Unit definition for [Length]
 ///
+/// Every length unit is a [quan::Unit] of [Distance], which provides the
+/// label, names and runtime identifier.
+///
 /// [Length]: ../struct.Length.html
-pub trait Unit {
-    /// Unit label
-    const LABEL: &'static str;
-
+pub trait Unit: quan::Unit<Measure = Distance> {
     /// Multiplication factor to convert to meters
-    const M_FACTOR: f64;
+    const M_FACTOR: f64 = <Self as quan::Unit>::FACTOR;
+
+    /// Exact factor to convert to meters, as a `(numerator, denominator)`
+    ///
+    /// By default, this is found from a decimal [M_FACTOR], such as `0.3048`.
+    /// Conversions between two units with exact ratios are rounded only once.
+    ///
+    /// [M_FACTOR]: #associatedconstant.M_FACTOR
+    const M_RATIO: Option<(u64, u64)> = crate::decimal_ratio(Self::M_FACTOR);
 
     /// Multiplication factor to convert to another unit
     fn factor<T: Unit>() -> f64 {
-        Self::M_FACTOR / T::M_FACTOR
+        <Self as Factor<T>>::FACTOR
     }
 }
 
+/// Conversion factor to another unit, evaluated when compiling
+pub(crate) trait Factor<T> {
+    /// Multiplication factor to convert to `T`
+    const FACTOR: f64;
+}
+
+impl<U, T> Factor<T> for U
+where
+    U: Unit + ?Sized,
+    T: Unit,
+{
+    const FACTOR: f64 =
+        crate::exact_factor(U::M_RATIO, T::M_RATIO, U::M_FACTOR / T::M_FACTOR);
+}
+
 /// Define a custom [unit] of [length]
 ///
 /// * `unit` Unit struct name
 /// * `label` Standard unit label
 /// * `m_factor` Factor to convert to meters
+/// * `name` Unit name, singular (optional)
+/// * `plural` Unit name, plural (optional)
+///
+/// The unit can be used to create [Length], [Area] and [Volume] quantities,
+/// and with time units to create [Speed]s, just like built-in units.
 ///
 /// # Example: Football Field
 /// ```rust
@@ -63,35 +108,84 @@ pub trait Unit {
 /// assert_eq!((1 * FootballField).to(), 100 * yd);
 /// ```
 ///
+/// # Example: Smoot
+/// ```rust
+/// use mag::{length_unit, length::m, time::s};
+///
+/// length_unit!(
+///     /** Smoot (height of Oliver Smoot) */
+///     smoot,
+///     "smoot",
+///     1.7018,
+///     "smoot",
+///     "smoots"
+/// );
+///
+/// let bridge = 364.4 * smoot;
+///
+/// assert_eq!(bridge.to_string(), "364.4 smoot");
+/// assert_eq!(format!("{:.1}", bridge.to::<m>()), "620.1 m");
+/// assert_eq!((2.0 * smoot * smoot).to_string(), "2 smoot²");
+/// assert_eq!((3.0 * smoot / s).to_string(), "3 smoot/s");
+/// ```
+///
+/// [Area]: struct.Area.html
+/// [Speed]: struct.Speed.html
+/// [Volume]: struct.Volume.html
+///
 /// [Length]: struct.Length.html
 /// [Unit]: length/trait.Unit.html
 #[macro_export]
 macro_rules! length_unit {
-    ($(#[$doc:meta])* $unit:ident, $label:expr, $m_factor:expr) => {
+    (
+        $(#[$doc:meta])* $unit:ident,
+        $label:expr,
+        $m_factor:expr
+        $(, $name:expr, $plural:expr)?
+    ) => {
 
         $(#[$doc])*
         #[allow(non_camel_case_types)]
-        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
         pub struct $unit;
 
-        impl $crate::length::Unit for $unit {
+        impl $crate::length::Unit for $unit {}
+
+        impl $crate::quan::Unit for $unit {
+            type Measure = $crate::quan::Distance;
             const LABEL: &'static str = $label;
-            const M_FACTOR: f64 = $m_factor;
+            const FACTOR: f64 = $m_factor;
+            const ZERO: f64 = 0.0;
+            $(
+                const NAME: &'static str = $name;
+                const NAME_PLURAL: &'static str = $plural;
+            )?
         }
 
-        // f64 * <unit> => Length
-        impl core::ops::Mul<$unit> for f64 {
-            type Output = $crate::Length<$unit>;
-            fn mul(self, _unit: $unit) -> Self::Output {
-                $crate::Length::new(self)
+        // scalar * <unit> => Length
+        $crate::impl_scalar_mul!($unit => $crate::Length<$unit>);
+
+        // "<label>" => <unit>, for quantity!
+        impl $crate::lit::Lookup<$unit>
+            for $crate::lit::Label<{ $crate::lit::hash($label) }>
+        {
+            type Part = $unit;
+            const PART: $unit = $unit;
+        }
+
+        // f64 / <unit> => InverseLength
+        impl core::ops::Div<$unit> for f64 {
+            type Output = $crate::InverseLength<$unit>;
+            fn div(self, _unit: $unit) -> Self::Output {
+                $crate::InverseLength::new(self)
             }
         }
 
-        // i32 * <unit> => Length
-        impl core::ops::Mul<$unit> for i32 {
-            type Output = $crate::Length<$unit>;
-            fn mul(self, _unit: $unit) -> Self::Output {
-                $crate::Length::new(f64::from(self))
+        // i32 / <unit> => InverseLength
+        impl core::ops::Div<$unit> for i32 {
+            type Output = $crate::InverseLength<$unit>;
+            fn div(self, _unit: $unit) -> Self::Output {
+                $crate::InverseLength::new(f64::from(self))
             }
         }
 
@@ -99,7 +193,7 @@ macro_rules! length_unit {
         impl core::ops::Mul<$unit> for $crate::Length<$unit> {
             type Output = $crate::Area<$unit>;
             fn mul(self, _unit: $unit) -> Self::Output {
-                $crate::Area::new(self.quantity)
+                $crate::Area::new(self.quantity())
             }
         }
 
@@ -107,7 +201,7 @@ macro_rules! length_unit {
         impl core::ops::Mul<$unit> for $crate::Area<$unit> {
             type Output = $crate::Volume<$unit>;
             fn mul(self, _unit: $unit) -> Self::Output {
-                $crate::Volume::new(self.quantity)
+                $crate::Volume::new(self.quantity())
             }
         }
     };
@@ -117,112 +211,249 @@ length_unit!(
     /** Kilometer / Kilometre */
     km,
     "km",
-    1_000.0
+    1_000.0,
+    "kilometer",
+    "kilometers"
 );
 
 length_unit!(
     /** Meter / Metre */
     m,
     "m",
-    1.0
+    1.0,
+    "meter",
+    "meters"
 );
 
 length_unit!(
     /** Decimeter / Decimetre */
     dm,
     "dm",
-    0.1
+    0.1,
+    "decimeter",
+    "decimeters"
 );
 
 length_unit!(
     /** Centimeter / Centimetre */
     cm,
     "cm",
-    0.01
+    0.01,
+    "centimeter",
+    "centimeters"
 );
 
 length_unit!(
     /** Millimeter / Millimetre */
     mm,
     "mm",
-    0.001
+    0.001,
+    "millimeter",
+    "millimeters"
 );
 
 length_unit!(
     /** Micrometer / Micrometre */
     um,
     "μm",
-    0.000_001
+    0.000_001,
+    "micrometer",
+    "micrometers"
 );
 
 length_unit!(
     /** Nanometer / Nanometre */
     nm,
     "nm",
-    0.000_000_001
+    0.000_000_001,
+    "nanometer",
+    "nanometers"
 );
 
 length_unit!(
     /** Mile */
     mi,
     "mi",
-    1_609.344
+    1_609.344,
+    "mile",
+    "miles"
 );
 
 length_unit!(
     /** Foot (international) */
     ft,
     "ft",
-    0.304_8
+    0.304_8,
+    "foot",
+    "feet"
 );
 
 length_unit!(
     /** Inch (capitalized to avoid clashing with `in` keyword) */
     In,
     "in",
-    0.025_4
+    0.025_4,
+    "inch",
+    "inches"
 );
 
 length_unit!(
     /** Yard (international) */
     yd,
     "yd",
-    0.914_4
+    0.914_4,
+    "yard",
+    "yards"
 );
 
 length_unit!(
     /** League (3 mi) */
     league,
     "league",
-    4_828.032
+    4_828.032,
+    "league",
+    "leagues"
 );
 
 length_unit!(
     /** Rod (16.5 ft) */
     rod,
     "rod",
-    5.029_2
+    5.029_2,
+    "rod",
+    "rods"
 );
 
 length_unit!(
     /** Furlong (220 yd) */
     furlong,
     "furlong",
-    201.168
+    201.168,
+    "furlong",
+    "furlongs"
 );
 
 length_unit!(
     /** Fathom (6 ft) */
     fathom,
     "fathom",
-    1.828_8
+    1.828_8,
+    "fathom",
+    "fathoms"
+);
+
+length_unit!(
+    /** Nautical mile (international) */
+    NM,
+    "NM",
+    1_852.0,
+    "nautical mile",
+    "nautical miles"
+);
+
+length_unit!(
+    /** Light-second (distance light travels in vacuum in 1 s) */
+    light_second,
+    "ls",
+    299_792_458.0,
+    "light-second",
+    "light-seconds"
+);
+
+length_unit!(
+    /** Light-minute (distance light travels in vacuum in 1 min) */
+    light_minute,
+    "lmin",
+    17_987_547_480.0,
+    "light-minute",
+    "light-minutes"
+);
+
+length_unit!(
+    /** Astronomical unit (IAU 2012, exact) */
+    au,
+    "au",
+    149_597_870_700.0,
+    "astronomical unit",
+    "astronomical units"
+);
+
+length_unit!(
+    /** Light-year (Julian year of 365.25 days, exact) */
+    ly,
+    "ly",
+    9_460_730_472_580_800.0,
+    "light-year",
+    "light-years"
+);
+
+length_unit!(
+    /** Parsec (IAU 2015, 648000/π au) */
+    pc,
+    "pc",
+    648_000.0 / core::f64::consts::PI * 149_597_870_700.0,
+    "parsec",
+    "parsecs"
+);
+
+unit_fns!(
+    kilometers => Length<km>::new,
+    meters => Length<m>::new,
+    centimeters => Length<cm>::new,
+    millimeters => Length<mm>::new,
+    miles => Length<mi>::new,
+    feet => Length<ft>::new,
+    inches => Length<In>::new,
+    yards => Length<yd>::new,
+    square_meters => Area<m>::new,
+    square_feet => Area<ft>::new,
+    cubic_meters => Volume<m>::new,
+    cubic_feet => Volume<ft>::new,
 );
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::ratio::Ratio;
     use alloc::{format, string::ToString};
 
+    const MARATHON: Length<km> = kilometers(42.195);
+
+    #[test]
+    fn len_fns() {
+        assert_eq!(MARATHON, 42.195 * km);
+        assert_eq!(meters(5.0), 5.0 * m);
+        assert_eq!(inches(2.5), 2.5 * In);
+        assert_eq!(square_feet(20.0), 20.0 * ft * ft);
+        assert_eq!(cubic_meters(1.5), 1.5 * m * m * m);
+    }
+
+    #[test]
+    fn len_scalars() {
+        let count: u32 = 1_250;
+        assert_eq!(mm * count, 1_250.0 * mm);
+        assert_eq!(km * 2.5_f32, 2.5 * km);
+        assert_eq!(ft * -3_i64, -3.0 * ft);
+        assert_eq!(m * u64::MAX, 18_446_744_073_709_551_615.0 * m);
+        assert_eq!(count * (1.0 * mm), 1_250.0 * mm);
+        assert_eq!(-2_i32 * (1.5 * ft * ft), -3.0 * ft * ft);
+        let reading: i32 = -4;
+        assert_eq!(reading * km, -4.0 * km);
+        let reading = f64::from(count);
+        assert_eq!(reading * km, 1_250.0 * km);
+    }
+
+    #[test]
+    fn inverse_length() {
+        assert_eq!(2.0 / (0.5 * m), 4.0 / m);
+        assert_eq!((3.0 * m) / (6.0 * m * m), 0.5 / m);
+        assert_eq!((6.0 * ft * ft) / (3.0 * ft * ft * ft), 2.0 / ft);
+        assert_eq!((100.0 / m).to::<cm>(), 1.0 / cm);
+        assert_eq!((4.0 / m) * (2.0 * m), 8.0);
+        assert_eq!((5.0 / km).to_string(), "5 /km");
+        assert_eq!("5 /km".parse(), Ok(5.0 / km));
+    }
+
     #[test]
     fn len_display() {
         assert_eq!((2.5 * km).to_string(), "2.5 km");
@@ -235,6 +466,8 @@ mod test {
         assert_eq!((0.5 * ft).to_string(), "0.5 ft");
         assert_eq!((6. * In).to_string(), "6 in");
         assert_eq!((100.0 * yd).to_string(), "100 yd");
+        assert_eq!((1.3 * light_second).to_string(), "1.3 ls");
+        assert_eq!((8.3 * light_minute).to_string(), "8.3 lmin");
     }
 
     #[test]
@@ -250,20 +483,75 @@ mod test {
         assert_eq!((54.3 * In * In * In).to_string(), "54.3 in³");
     }
 
+    #[test]
+    fn unit_id() {
+        use crate::quan::Unit as _;
+        use alloc::collections::BTreeMap;
+
+        assert_eq!(m, m);
+        assert!(m.max(m) == m);
+        assert_ne!(km::id(), m::id());
+        assert_eq!(In::id().label(), "in");
+        let mut map = BTreeMap::new();
+        map.insert(km::id(), "kilometer");
+        map.insert(mi::id(), "mile");
+        assert_eq!(map.get(&mi::id()), Some(&"mile"));
+        assert_eq!(map.get(&ft::id()), None);
+    }
+
     #[test]
     fn len_to() {
-        assert_eq!((1.0 * ft).to(), (12.000000000000002 * In));
+        assert_eq!((1.0 * ft).to(), (12.0 * In));
         assert_eq!((1.0 * yd).to(), (3.0 * ft));
         assert_eq!((1.0 * yd).to(), (36.0 * In));
         assert_eq!((1.0 * mi).to(), (5280.0 * ft));
         assert_eq!((1.0 * m).to(), (0.001 * km));
         assert_eq!((110.0 * cm).to(), (1.1 * m));
         assert_eq!((1.0 * cm).to(), 0.393_700_787_401_574_8 * In);
+        assert_eq!((1.0 * light_minute).to(), 60.0 * light_second);
+        assert_eq!((1.0 * light_second).to(), 299_792.458 * km);
+        assert_eq!((2.0 * NM).to(), 3.704 * km);
+        assert_eq!((1.0 * ly).to(), 31_557_600.0 * light_second);
+        assert_eq!((1.0 * au).to(), 149_597_870.7 * km);
+        assert_eq!(format!("{:.6}", (1.0 * pc).to::<ly>()), "3.261564 ly");
+        assert_eq!(format!("{:.3}", (1.0 * pc).to::<au>()), "206264.806 au");
+        assert_eq!(format!("{:.4}", (1.0 * NM).to::<mi>()), "1.1508 mi");
+    }
+
+    #[test]
+    fn len_accessors() {
+        assert_eq!((2.5 * km).quantity(), 2.5);
+        assert_eq!((-3.0 * m).map(|q| q.max(0.0)), 0.0 * m);
+        assert_eq!((2.0 * ft * ft).map(|q| q * 3.0), 6.0 * ft * ft);
+    }
+
+    #[test]
+    fn len_exact() {
+        assert_eq!(ft::M_RATIO, Some((3_048, 10_000)));
+        assert_eq!(mi::M_RATIO, Some((1_609_344, 1_000)));
+        assert_eq!(pc::M_RATIO, None);
+        assert_eq!(In::factor::<ft>(), 1.0 / 12.0);
+        assert_eq!((5.0 * mi).to(), 8_800.0 * yd);
+        assert_eq!((10.0 * In).to(), 254.0 * mm);
+        assert_eq!((3.0 * fathom).to(), 18.0 * ft);
+        // terms above 2⁵³ are still rounded only once
+        let big = Some((9_007_199_254_740_993, 7));
+        let exact = crate::exact_factor(big, Some((1, 1)), 0.0);
+        assert_eq!(exact - 1_286_742_750_677_284.0, 0.75);
+        let from = Some((5_267_993_464_630_784_501, 1));
+        let to = Some((7_417_603_515_346_938_591, 1));
+        let exact = crate::exact_factor(from, to, 0.0);
+        assert_eq!(exact, 0.710_201_543_359_841_9);
+        assert_eq!(crate::exact_factor(Some((1, 3)), Some((2, 3)), 0.0), 0.5);
+        assert_eq!(
+            crate::exact_factor(Some((1, 1)), Some((3, 1)), 0.0),
+            1.0 / 3.0
+        );
     }
 
     #[test]
     fn area_to() {
-        assert_eq!((1.0 * ft * ft).to(), 144.00000000000006 * In * In);
+        assert_eq!((1.0 * ft * ft).to(), 144.0 * In * In);
         assert_eq!((1.0 * m * m).to(), 10_000.0 * cm * cm);
     }
 
@@ -318,6 +606,10 @@ mod test {
         assert_eq!((3.0 * nm) * 3.0, 9.0 * nm);
         assert_eq!(3.0 * (3.0 * m), 9.0 * m);
         assert_eq!((10.0 * In) * (5.0 * In), 50.0 * In * In);
+        assert_eq!((2.0 * m) * 3, 6.0 * m);
+        assert_eq!((2.0 * m) * 3u32, 6.0 * m);
+        assert_eq!(3u32 * (2.0 * m), 6.0 * m);
+        assert_eq!((0.5 * m).times(10), 5.0 * m);
     }
 
     #[test]
@@ -337,12 +629,14 @@ mod test {
     #[test]
     fn len_div() {
         assert_eq!((5.0 * ft) / 5.0, 1.0 * ft);
+        assert_eq!((3.0 * km) / (12.0 * km), Ratio::new(0.25));
     }
 
     #[test]
     fn area_div() {
         assert_eq!((500.0 * cm * cm) / 5.0, 100.0 * cm * cm);
         assert_eq!(40.0 * nm * nm / (10.0 * nm), 4.0 * nm);
+        assert_eq!((30.0 * m * m) / (120.0 * m * m), Ratio::new(0.25));
     }
 
     #[test]
@@ -350,5 +644,102 @@ mod test {
         assert_eq!((50.0 * mm * mm * mm) / 10.0, 5.0 * mm * mm * mm);
         assert_eq!((40.0 * yd * yd * yd) / (2.0 * yd), 20.0 * yd * yd);
         assert_eq!((25.0 * In * In * In) / (5.0 * In * In), 5.0 * In);
+        assert_eq!(
+            (6.0 * ft * ft * ft) / (8.0 * ft * ft * ft),
+            Ratio::new(0.75)
+        );
+    }
+
+    #[test]
+    fn len_assign() {
+        let mut total = 0.0 * m;
+        for x in [1.5 * m, 2.5 * m, 4.0 * m] {
+            total += x;
+        }
+        assert_eq!(total, 8.0 * m);
+        total -= 3.0 * m;
+        assert_eq!(total, 5.0 * m);
+        total *= 3.0;
+        assert_eq!(total, 15.0 * m);
+        total /= 5.0;
+        assert_eq!(total, 3.0 * m);
+        let mut a = 2.0 * ft * ft;
+        a += 1.0 * ft * ft;
+        a *= 2.0;
+        assert_eq!(a, 6.0 * ft * ft);
+        let mut v = 9.0 * cm * cm * cm;
+        v -= 1.0 * cm * cm * cm;
+        v /= 4.0;
+        assert_eq!(v, 2.0 * cm * cm * cm);
+    }
+
+    #[test]
+    fn area_polygon() {
+        let square = [
+            (0.0 * ft, 0.0 * ft),
+            (0.0 * ft, 10.0 * ft),
+            (10.0 * ft, 10.0 * ft),
+            (10.0 * ft, 0.0 * ft),
+        ];
+        assert_eq!(Area::polygon(&square), 100.0 * ft * ft);
+        let ell = [
+            (0.0 * m, 0.0 * m),
+            (2.0 * m, 0.0 * m),
+            (2.0 * m, 1.0 * m),
+            (1.0 * m, 1.0 * m),
+            (1.0 * m, 3.0 * m),
+            (0.0 * m, 3.0 * m),
+        ];
+        assert_eq!(Area::polygon(&ell), 4.0 * m * m);
+        assert_eq!(Area::polygon(&ell[..2]), 0.0 * m * m);
+        assert_eq!(Area::<m>::polygon(&[]), 0.0 * m * m);
+    }
+
+    #[test]
+    fn len_quantize() {
+        assert_eq!(
+            (10.3 * mm).quantize(0.25 * mm),
+            (41, 0.05000000000000071 * mm)
+        );
+        assert_eq!((-7.5 * m).quantize(1.0 * m), (-8, 0.5 * m));
+        assert_eq!((1.0 * km).quantize(3.0 * km), (0, 1.0 * km));
+    }
+
+    #[test]
+    fn length_parse() {
+        use crate::quan::ParseError;
+
+        assert_eq!("25.5 cm".parse(), Ok(25.5 * cm));
+        assert_eq!(" 3e3  m ".parse(), Ok(3_000.0 * m));
+        assert_eq!("-12ft".parse(), Ok(-12.0 * ft));
+        assert_eq!("150 m²".parse(), Ok(150.0 * m * m));
+        assert_eq!("2 km³".parse(), Ok(2.0 * km * km * km));
+        assert_eq!("25.5 mm".parse::<Length<cm>>(), Err(ParseError::Unit));
+        assert_eq!("25.5".parse::<Length<cm>>(), Err(ParseError::Unit));
+        assert_eq!("2,5 cm".parse::<Length<cm>>(), Err(ParseError::Value));
+        assert_eq!("10 m".parse::<Area<m>>(), Err(ParseError::Unit));
+    }
+
+    #[test]
+    fn len_const() {
+        const A: Length<ft> = Length::new(3.0);
+        const B: Area<yd> = Area::<ft>::new(9.0).to();
+        const C: Volume<m> = Volume::<cm>::new(1_000_000.0).to();
+        assert_eq!(A, 3.0 * ft);
+        assert_eq!(B, (9.0 * ft * ft).to());
+        assert_eq!(C, (1_000_000.0 * cm * cm * cm).to());
+    }
+
+    #[test]
+    fn len_base() {
+        assert_eq!((2.5 * km).to_base(), 2_500.0 * m);
+        assert_eq!((3.0 * cm * cm).to_base(), 0.00030000000000000003 * m * m);
+        assert_eq!((1.0 * In).to_base(), 0.0254 * m);
+    }
+
+    #[test]
+    fn len_reinterpret() {
+        assert_eq!((2.5 * ft).reinterpret_unit::<m>(), 2.5 * m);
+        assert_eq!((4.0 * In * In).reinterpret_unit(), 4.0 * cm * cm);
     }
 }
@@ -0,0 +1,87 @@
+// lerp.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Linear interpolation between quantities
+//!
+//! The [Lerp] trait is implemented for all quantity types, including
+//! temperatures.  Both quantities must have the same unit.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{lerp::Lerp, length::m, temp::DegC, time::s, Length};
+//!
+//! let a = 2.0 * m;
+//! let b = 6.0 * m;
+//!
+//! assert_eq!(a.lerp(b, 0.25), 3.0 * m);
+//! assert_eq!(Length::lerp(a, b, 1.5), 8.0 * m);
+//! assert_eq!(a.inverse_lerp(b, 5.0 * m), 0.75);
+//! assert_eq!((20.0 * DegC).lerp(30.0 * DegC, 0.5), 25.0 * DegC);
+//! assert_eq!((10.0 * m / s).lerp(20.0 * m / s, 0.1), 11.0 * m / s);
+//! ```
+use crate::quan::Magnitude;
+
+/// Linear interpolation between two quantities
+pub trait Lerp: Magnitude + Sized {
+    /// Interpolate between `self` (at `t = 0`) and `other` (at `t = 1`)
+    ///
+    /// Values of `t` outside `[0, 1]` extrapolate.  The result is exact at
+    /// both end points.
+    fn lerp(self, other: Self, t: f64) -> Self {
+        let a = self.magnitude();
+        let b = other.magnitude();
+        Self::from_magnitude((1.0 - t) * a + t * b)
+    }
+
+    /// Find the interpolation factor `t` of a value between two quantities
+    ///
+    /// This is the inverse of [lerp](Lerp::lerp).  If `self` and `other`
+    /// are equal, the result is not finite.
+    fn inverse_lerp(self, other: Self, value: Self) -> f64 {
+        let a = self.magnitude();
+        let b = other.magnitude();
+        (value.magnitude() - a) / (b - a)
+    }
+}
+
+impl<Q> Lerp for Q where Q: Magnitude {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::mass::kg;
+    use crate::quan::Quantity;
+    use crate::temp::DegF;
+    use crate::time::{min, s};
+    use crate::{Length, Period};
+
+    #[test]
+    fn lerp_ends() {
+        let a = 0.1 * m;
+        let b = 0.7 * m;
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(Length::lerp(3.0 * ft, 5.0 * ft, 0.5), 4.0 * ft);
+        assert_eq!(Period::lerp(1.0 * min, 3.0 * min, -0.5), 0.0 * min);
+    }
+
+    #[test]
+    fn lerp_quantity() {
+        assert_eq!((2.0 * kg).lerp(4.0 * kg, 0.75), 3.5 * kg);
+        assert_eq!(
+            Quantity::lerp(32.0 * DegF, 212.0 * DegF, 0.5),
+            122.0 * DegF
+        );
+        assert_eq!((40.0 * m / s).lerp(0.0 * m / s, 0.25), 30.0 * m / s);
+    }
+
+    #[test]
+    fn lerp_inverse() {
+        assert_eq!((2.0 * s).inverse_lerp(4.0 * s, 3.0 * s), 0.5);
+        assert_eq!((10.0 * kg).inverse_lerp(20.0 * kg, 25.0 * kg), 1.5);
+        assert!((1.0 * m).inverse_lerp(1.0 * m, 1.0 * m).is_nan());
+    }
+}
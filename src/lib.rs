@@ -7,9 +7,161 @@
 #![forbid(unsafe_code)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Check that a quantity value is finite.
+///
+/// With the `assert-finite` feature, this debug-asserts that the value is
+/// not infinite or NaN.  Otherwise, non-finite values are propagated.
+#[inline]
+const fn check_finite(value: f64) -> f64 {
+    #[cfg(feature = "assert-finite")]
+    debug_assert!(value.is_finite(), "non-finite quantity");
+    value
+}
+
+/// Quantize a value to a multiple of a scale step.
+///
+/// Returns the nearest step count (rounding half away from zero, saturating
+/// at the `i64` limits) and the residual error.
+fn quantize(value: f64, scale: f64) -> (i64, f64) {
+    let steps = value / scale;
+    let count = if steps < 0.0 {
+        (steps - 0.5) as i64
+    } else {
+        (steps + 0.5) as i64
+    };
+    (count, value - count as f64 * scale)
+}
+
+/// Find an exact decimal ratio for a conversion factor.
+///
+/// Returns a `(numerator, denominator)` pair, such as `(3_048, 10_000)` for
+/// `0.3048`, if one rounds to exactly the same `f64` as the factor.
+const fn decimal_ratio(factor: f64) -> Option<(u64, u64)> {
+    let mut den = 1;
+    while den <= 1_000_000_000_000_000 {
+        let num = factor * den as f64 + 0.5;
+        if num >= 1.0 && num < 9_007_199_254_740_992.0 {
+            let num = num as u64;
+            if num as f64 / den as f64 == factor {
+                return Some((num, den));
+            }
+        }
+        den *= 10;
+    }
+    None
+}
+
+/// Combine two ratios into a conversion factor, with a single rounding.
+///
+/// If either ratio is not exact, the approximate factor is used instead.
+const fn exact_factor(
+    from: Option<(u64, u64)>,
+    to: Option<(u64, u64)>,
+    approx: f64,
+) -> f64 {
+    match (from, to) {
+        (Some((fnum, fden)), Some((tnum, tden))) => {
+            let mut num = fnum as u128 * tden as u128;
+            let mut den = fden as u128 * tnum as u128;
+            // reduce by greatest common divisor
+            let (mut a, mut b) = (num, den);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            num /= a;
+            den /= a;
+            div_rounded(num, den)
+        }
+        _ => approx,
+    }
+}
+
+/// Divide two integers, rounding to the nearest `f64` (ties to even).
+///
+/// Converting each term to `f64` before dividing would round up to three
+/// times when they are above 2⁵³.
+const fn div_rounded(num: u128, den: u128) -> f64 {
+    let mut m = num / den;
+    let mut r = num % den;
+    let mut exp: i32 = 0;
+    let mut sticky = false;
+    if m >= 1 << 54 {
+        // keep 53 significant bits and a guard bit
+        let shift = 128 - m.leading_zeros() - 54;
+        sticky = m & ((1 << shift) - 1) != 0;
+        m >>= shift;
+        exp = shift as i32;
+    } else {
+        // long division, one bit at a time (without overflowing)
+        while m < 1 << 53 {
+            let bit = r >= den - r;
+            r = if bit { r - (den - r) } else { r + r };
+            m = (m << 1) | bit as u128;
+            exp -= 1;
+        }
+    }
+    let guard = m & 1 != 0;
+    m >>= 1;
+    exp += 1;
+    if guard && (sticky || r != 0 || m & 1 != 0) {
+        m += 1;
+    }
+    let mut val = m as f64;
+    while exp > 0 {
+        val *= 2.0;
+        exp -= 1;
+    }
+    while exp < 0 {
+        val /= 2.0;
+        exp += 1;
+    }
+    val
+}
+
+/// Square root by Newton's method (no `std` required)
+fn sqrt(val: f64) -> f64 {
+    if val <= 0.0 || !val.is_finite() {
+        return if val == 0.0 { 0.0 } else { f64::NAN };
+    }
+    let mut x = if val > 1.0 { val } else { 1.0 };
+    loop {
+        let next = (x + val / x) / 2.0;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
 // Implement basic ops for a quantity struct
 macro_rules! impl_base_ops {
-    ($quan:ident, $unit:path) => {
+    // Accessors, also used by structs with more than one unit
+    (@access $quan:ident<$($param:ident),+> where $($bounds:tt)+) => {
+        impl<$($param),+> $quan<$($param),+>
+        where
+            $($bounds)+
+        {
+            /// Get the quantity, in the current units
+            #[allow(deprecated)]
+            pub const fn quantity(&self) -> f64 {
+                self.quantity
+            }
+
+            /// Apply a function to the quantity, keeping the units
+            pub fn map<F>(self, f: F) -> Self
+            where
+                F: FnOnce(f64) -> f64,
+            {
+                Self::new(f(self.quantity()))
+            }
+        }
+    };
+    ($quan:ident, $unit:path, $base:path) => {
+        impl_base_ops!(@access $quan<U> where U: $unit);
+
         // <quan> + <quan> => <quan>
         impl<U> Add for $quan<U>
         where
@@ -17,7 +169,7 @@ macro_rules! impl_base_ops {
         {
             type Output = Self;
             fn add(self, other: Self) -> Self::Output {
-                Self::new(self.quantity + other.quantity)
+                Self::new(self.quantity() + other.quantity())
             }
         }
 
@@ -28,7 +180,20 @@ macro_rules! impl_base_ops {
         {
             type Output = Self;
             fn sub(self, other: Self) -> Self::Output {
-                Self::new(self.quantity - other.quantity)
+                Self::new(self.quantity() - other.quantity())
+            }
+        }
+
+        // Sum of <quan> => <quan>
+        impl<U> core::iter::Sum for $quan<U>
+        where
+            U: $unit,
+        {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                iter.fold(Self::new(0.0), |a, b| a + b)
             }
         }
 
@@ -39,7 +204,7 @@ macro_rules! impl_base_ops {
         {
             type Output = Self;
             fn mul(self, scalar: f64) -> Self::Output {
-                Self::new(self.quantity * scalar)
+                Self::new(self.quantity() * scalar)
             }
         }
 
@@ -50,7 +215,40 @@ macro_rules! impl_base_ops {
         {
             type Output = Self;
             fn mul(self, scalar: i32) -> Self::Output {
-                Self::new(self.quantity * f64::from(scalar))
+                Self::new(self.quantity() * f64::from(scalar))
+            }
+        }
+
+        // <quan> * u32 => <quan>
+        impl<U> Mul<u32> for $quan<U>
+        where
+            U: $unit,
+        {
+            type Output = Self;
+            fn mul(self, count: u32) -> Self::Output {
+                Self::new(self.quantity() * f64::from(count))
+            }
+        }
+
+        // i32 * <quan> => <quan>
+        impl<U> Mul<$quan<U>> for i32
+        where
+            U: $unit,
+        {
+            type Output = $quan<U>;
+            fn mul(self, other: $quan<U>) -> Self::Output {
+                Self::Output::new(f64::from(self) * other.quantity())
+            }
+        }
+
+        // u32 * <quan> => <quan>
+        impl<U> Mul<$quan<U>> for u32
+        where
+            U: $unit,
+        {
+            type Output = $quan<U>;
+            fn mul(self, other: $quan<U>) -> Self::Output {
+                Self::Output::new(f64::from(self) * other.quantity())
             }
         }
 
@@ -61,7 +259,7 @@ macro_rules! impl_base_ops {
         {
             type Output = $quan<U>;
             fn mul(self, other: $quan<U>) -> Self::Output {
-                Self::Output::new(self * other.quantity)
+                Self::Output::new(self * other.quantity())
             }
         }
 
@@ -72,19 +270,267 @@ macro_rules! impl_base_ops {
         {
             type Output = Self;
             fn div(self, scalar: f64) -> Self::Output {
-                Self::new(self.quantity / scalar)
+                Self::new(self.quantity() / scalar)
             }
         }
+
+        // <quan> += <quan>
+        impl<U> core::ops::AddAssign for $quan<U>
+        where
+            U: $unit,
+        {
+            fn add_assign(&mut self, other: Self) {
+                *self = Self::new(self.quantity() + other.quantity());
+            }
+        }
+
+        // <quan> -= <quan>
+        impl<U> core::ops::SubAssign for $quan<U>
+        where
+            U: $unit,
+        {
+            fn sub_assign(&mut self, other: Self) {
+                *self = Self::new(self.quantity() - other.quantity());
+            }
+        }
+
+        // <quan> *= f64
+        impl<U> core::ops::MulAssign<f64> for $quan<U>
+        where
+            U: $unit,
+        {
+            fn mul_assign(&mut self, scalar: f64) {
+                *self = Self::new(self.quantity() * scalar);
+            }
+        }
+
+        // <quan> /= f64
+        impl<U> core::ops::DivAssign<f64> for $quan<U>
+        where
+            U: $unit,
+        {
+            fn div_assign(&mut self, scalar: f64) {
+                *self = Self::new(self.quantity() / scalar);
+            }
+        }
+
+        impl<U> $quan<U>
+        where
+            U: $unit,
+        {
+            /// Multiply by an item count.
+            ///
+            /// Counts above 2⁵³ are rounded to the nearest `f64`.
+            pub fn times(self, count: u64) -> Self {
+                Self::new(self.quantity() * count as f64)
+            }
+
+            /// Quantize to an integer multiple of a scale step.
+            ///
+            /// Returns the nearest step count and the residual error (the
+            /// quantity minus the quantized value).
+            pub fn quantize(self, scale: Self) -> (i64, Self) {
+                let (count, err) =
+                    $crate::quantize(self.quantity(), scale.quantity());
+                (count, Self::new(err))
+            }
+
+            /// Change the unit **without converting** the quantity.
+            ///
+            /// This is an escape hatch for cases where the quantity is
+            /// already known to be in the target unit, such as at an FFI
+            /// boundary.  Use `to` for conversion.
+            pub fn reinterpret_unit<T: $unit>(self) -> $quan<T> {
+                $quan::new(self.quantity())
+            }
+
+            /// Convert to the SI base unit
+            pub const fn to_base(self) -> $quan<$base> {
+                self.to()
+            }
+        }
+
+        impl<U> core::str::FromStr for $quan<U>
+        where
+            U: $unit,
+        {
+            type Err = $crate::quan::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::quan::parse_quantity(s)
+            }
+        }
+
+        impl<U> $crate::quan::Magnitude for $quan<U>
+        where
+            U: $unit,
+        {
+            fn magnitude(&self) -> f64 {
+                self.quantity()
+            }
+
+            fn from_magnitude(magnitude: f64) -> Self {
+                Self::new(magnitude)
+            }
+        }
+
+        impl<U> $crate::quan::Scalable for $quan<U> where U: $unit {}
     };
 }
 
+// Define const constructor functions named after units
+macro_rules! unit_fns {
+    ($($name:ident => $quan:ident<$unit:ty>::$ctor:ident,)*) => {
+        $(
+            #[doc = concat!(
+                "Create a [", stringify!($quan), "] in ",
+                stringify!($name), " (`", stringify!($unit), "`)"
+            )]
+            ///
+            /// This can be used in const contexts.
+            pub const fn $name(value: f64) -> $quan<$unit> {
+                $quan::$ctor(value)
+            }
+        )*
+    };
+}
+
+/// Create a quantity from a string literal.
+///
+/// The literal is a value and unit label, in the same format as `Display`
+/// output.  It is parsed when compiling, so an unknown unit fails the build.
+/// The resulting expression is the same as using the unit structs directly,
+/// with no runtime parsing.
+///
+/// * `quantity!("`value unit`")` `=>` [Length], [Period], Mass, etc.
+/// * `quantity!("`value unit`²")` `=>` [Area]
+/// * `quantity!("`value unit`³")` `=>` [Volume]
+/// * `quantity!("`value` /`unit`")` `=>` [InverseLength], [Frequency]
+/// * `quantity!("`value unit`/`unit`")` `=>` [Speed], etc.
+/// * `quantity!("`value unit`/`unit`²")` `=>` [Acceleration]
+/// * `quantity!("`value unit`/`unit`³")` `=>` [Density]
+/// * `quantity!("`value unit`³/`unit`")` `=>` [FlowRate]
+/// * `quantity!("`value unit`·`unit`/`unit`²")` `=>` [Force]
+///
+/// A label which belongs to a single unit, such as `m²` or `g/mol`, is used
+/// as that unit rather than split into parts.  Labels of custom units work
+/// the same way as built-in units.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{declare_unit, quan::Mass, quantity};
+///
+/// let a = quantity!("55 mi/h");
+/// let b = quantity!("2.5 km²");
+/// let c = quantity!("60 ㎐");
+/// let d = quantity!("-5.2 °F");
+/// let e = quantity!("9.81 m/s²");
+///
+/// assert_eq!(a.to_string(), "55 mi/h");
+/// assert_eq!(b.to_string(), "2.5 km²");
+/// assert_eq!(c.to_string(), "60 ㎐");
+/// assert_eq!(d.to_string(), "-5.2 °F");
+/// assert_eq!(e.to_string(), "9.81 m/s²");
+///
+/// declare_unit!(M, "M☉", Mass, 1.988_47e33,);
+///
+/// assert_eq!(quantity!("1.5 M☉"), 1.5 * M);
+/// ```
+///
+/// Unknown units are rejected:
+///
+/// ```compile_fail
+/// let a = mag::quantity!("12 parsec");
+/// ```
+#[macro_export]
+macro_rules! quantity {
+    ($lit:literal) => {{
+        #[allow(unused_imports)]
+        use $crate::lit::{Split as _, Whole as _};
+        const LIT: &str = $lit;
+        const VAL: f64 = $crate::lit::value(LIT);
+        (&$crate::lit::Probe::<
+            { $crate::lit::whole(LIT) },
+            { $crate::lit::shape(LIT) },
+            { $crate::lit::part(LIT, 0) },
+            { $crate::lit::part(LIT, 1) },
+            { $crate::lit::part(LIT, 2) },
+        >(VAL))
+            .build()
+    }};
+}
+
+mod accel;
+pub mod amount;
+#[cfg(feature = "approx")]
+mod approx_ops;
+#[cfg(feature = "audit")]
+pub mod audit;
+#[cfg(feature = "libm")]
+pub mod bearing;
+pub mod bounded;
+pub mod checked;
+pub mod consts;
+pub mod convert;
+pub mod decimal;
+#[cfg(feature = "defmt")]
+mod defmt_ops;
+pub mod delta;
+mod density;
+pub mod dimension;
+pub mod display;
+pub mod dynamic;
+pub mod energy;
+pub mod fixed;
+mod flow;
+pub mod force;
+#[cfg(feature = "libm")]
+pub mod geo;
+pub mod hysteresis;
+pub mod information;
+pub mod json;
+pub mod kalman;
+pub mod key;
+pub mod land;
 pub mod length;
+pub mod lerp;
+pub mod liquid;
+#[doc(hidden)]
+pub mod lit;
+pub mod long;
 pub mod mass;
+pub mod maxspeed;
+pub mod molar;
+pub mod motion;
+pub mod power;
+#[cfg(feature = "double-double")]
+pub mod precise;
+pub mod pressure;
 pub mod quan;
-mod speed;
+#[cfg(feature = "rand")]
+mod rand_ops;
+pub mod ratio;
+pub mod rect;
+pub mod rounding;
+pub mod scale;
+pub mod slice;
+pub mod speed;
+pub mod stats;
+pub mod step;
+pub mod sum;
+pub mod synonym;
 pub mod temp;
 pub mod time;
+pub mod torque;
+#[cfg(feature = "uom")]
+mod uom_ops;
 
-pub use length::lenpriv::{Area, Length, Volume};
-pub use speed::Speed;
+pub use accel::Acceleration;
+pub use density::Density;
+pub use flow::FlowRate;
+pub use force::Force;
+pub use length::lenpriv::{Area, InverseLength, Length, Volume};
+pub use long::{LongForm, Named};
+pub use speed::{Knots, Speed};
 pub use time::timepriv::{Frequency, Period};
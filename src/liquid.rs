@@ -0,0 +1,344 @@
+// liquid.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of liquid volume.
+//!
+//! Each unit is defined relative to liters with a conversion factor.  They can
+//! be used to conveniently create LiquidVolume quantities.
+//!
+//! Customary and culinary units are defined differently by region, so each one
+//! has a suffix naming its definition: `_us` (US customary), `_metric`, `_imp`
+//! (imperial) or `_au` (Australian).
+//!
+//! Liquid volumes can be converted to and from [Volume] quantities of cubed
+//! length units.  The [m3] unit is a shortcut for cubic meters.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::length::{cm, m};
+//! use mag::liquid::{gal_us, tbsp_metric, tbsp_us, L};
+//!
+//! let a = 1.5 * L;
+//! let b = 3 * tbsp_metric;
+//!
+//! assert_eq!(a.to_string(), "1.5 L");
+//! assert_eq!(b.to_string(), "3 tbsp");
+//! assert_eq!(format!("{:.2}", b.to::<tbsp_us>()), "3.04 US tbsp");
+//! assert_eq!(a.to_volume::<cm>(), 1_500.0 * cm * cm * cm);
+//! assert_eq!((1.0 * m * m * m).to_liters(), 1_000.0 * L);
+//! assert_eq!(format!("{:.3}", (2.0 * gal_us).to::<L>()), "7.571 L");
+//! ```
+use crate::declare_unit;
+use crate::length::{self, m};
+use crate::quan::{LiquidVolume, Quantity, Unit};
+use crate::Volume;
+
+declare_unit!(
+    /** Liter / Litre */
+    L,
+    "L",
+    LiquidVolume,
+    1.0,
+    "liter",
+    "liters",
+);
+
+declare_unit!(
+    /** Milliliter / Millilitre */
+    mL,
+    "mL",
+    LiquidVolume,
+    0.001,
+    "milliliter",
+    "milliliters",
+);
+
+declare_unit!(
+    /** Cup (US customary, 8 US fl oz) */
+    cup_us,
+    "US cup",
+    LiquidVolume,
+    0.236_588_236_5,
+    "US cup",
+    "US cups",
+);
+
+declare_unit!(
+    /** Cup (metric, 250 mL) */
+    cup_metric,
+    "cup",
+    LiquidVolume,
+    0.25,
+    "metric cup",
+    "metric cups",
+);
+
+declare_unit!(
+    /** Cup (imperial, 10 imp fl oz) */
+    cup_imp,
+    "imp cup",
+    LiquidVolume,
+    0.284_130_625,
+    "imperial cup",
+    "imperial cups",
+);
+
+declare_unit!(
+    /** Tablespoon (US customary, 1/16 US cup) */
+    tbsp_us,
+    "US tbsp",
+    LiquidVolume,
+    0.014_786_764_781_25,
+    "US tablespoon",
+    "US tablespoons",
+);
+
+declare_unit!(
+    /** Tablespoon (metric, 15 mL) */
+    tbsp_metric,
+    "tbsp",
+    LiquidVolume,
+    0.015,
+    "metric tablespoon",
+    "metric tablespoons",
+);
+
+declare_unit!(
+    /** Tablespoon (Australian, 20 mL) */
+    tbsp_au,
+    "AU tbsp",
+    LiquidVolume,
+    0.02,
+    "Australian tablespoon",
+    "Australian tablespoons",
+);
+
+declare_unit!(
+    /** Teaspoon (US customary, 1/3 US tbsp) */
+    tsp_us,
+    "US tsp",
+    LiquidVolume,
+    0.004_928_921_593_75,
+    "US teaspoon",
+    "US teaspoons",
+);
+
+declare_unit!(
+    /** Teaspoon (metric, 5 mL) */
+    tsp_metric,
+    "tsp",
+    LiquidVolume,
+    0.005,
+    "metric teaspoon",
+    "metric teaspoons",
+);
+
+declare_unit!(
+    /** Cubic meter / Cubic metre (1000 L) */
+    m3,
+    "m³",
+    LiquidVolume,
+    1_000.0,
+    "cubic meter",
+    "cubic meters",
+);
+
+declare_unit!(
+    /** Gallon (US customary, 231 in³) */
+    gal_us,
+    "US gal",
+    LiquidVolume,
+    3.785_411_784,
+    "US gallon",
+    "US gallons",
+);
+
+declare_unit!(
+    /** Gallon (imperial) */
+    gal_imp,
+    "imp gal",
+    LiquidVolume,
+    4.546_09,
+    "imperial gallon",
+    "imperial gallons",
+);
+
+declare_unit!(
+    /** Quart (US customary, 1/4 US gal) */
+    qt_us,
+    "US qt",
+    LiquidVolume,
+    0.946_352_946,
+    "US quart",
+    "US quarts",
+);
+
+declare_unit!(
+    /** Quart (imperial, 1/4 imp gal) */
+    qt_imp,
+    "imp qt",
+    LiquidVolume,
+    1.136_522_5,
+    "imperial quart",
+    "imperial quarts",
+);
+
+declare_unit!(
+    /** Pint (US customary, 1/8 US gal) */
+    pt_us,
+    "US pt",
+    LiquidVolume,
+    0.473_176_473,
+    "US pint",
+    "US pints",
+);
+
+declare_unit!(
+    /** Pint (imperial, 1/8 imp gal) */
+    pt_imp,
+    "imp pt",
+    LiquidVolume,
+    0.568_261_25,
+    "imperial pint",
+    "imperial pints",
+);
+
+declare_unit!(
+    /** Fluid ounce (US customary, 1/128 US gal) */
+    floz_us,
+    "US fl oz",
+    LiquidVolume,
+    0.029_573_529_562_5,
+    "US fluid ounce",
+    "US fluid ounces",
+);
+
+declare_unit!(
+    /** Fluid ounce (imperial, 1/160 imp gal) */
+    floz_imp,
+    "imp fl oz",
+    LiquidVolume,
+    0.028_413_062_5,
+    "imperial fluid ounce",
+    "imperial fluid ounces",
+);
+
+unit_fns!(
+    liters => Quantity<L>::new_const,
+    milliliters => Quantity<mL>::new_const,
+);
+
+/// Liters per cubic meter
+const L_PER_M3: f64 = 1_000.0;
+
+impl<U> Quantity<U>
+where
+    U: Unit<Measure = LiquidVolume>,
+{
+    /// Convert to a volume of cubed length units
+    pub fn to_volume<T>(self) -> Volume<T>
+    where
+        T: length::Unit,
+    {
+        Volume::<m>::new(self.to::<L>().value() / L_PER_M3).to()
+    }
+}
+
+impl<T> Volume<T>
+where
+    T: length::Unit,
+{
+    /// Convert to a liquid volume quantity
+    pub fn to_liquid<U>(self) -> Quantity<U>
+    where
+        U: Unit<Measure = LiquidVolume>,
+    {
+        Quantity::<L>::new(self.to::<m>().quantity() * L_PER_M3).to()
+    }
+
+    /// Convert to a quantity of liters
+    pub fn to_liters(self) -> Quantity<L> {
+        self.to_liquid()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{cm, In};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn liquid_display() {
+        assert_eq!((2.5 * L).to_string(), "2.5 L");
+        assert_eq!((250.0 * mL).to_string(), "250 mL");
+        assert_eq!((1.0 * cup_us).to_string(), "1 US cup");
+        assert_eq!((1.0 * cup_metric).to_string(), "1 cup");
+        assert_eq!((0.5 * cup_imp).to_string(), "0.5 imp cup");
+        assert_eq!((2 * tbsp_au).to_string(), "2 AU tbsp");
+        assert_eq!((3 * tsp_metric).to_string(), "3 tsp");
+        assert_eq!((1.0 * gal_us).to_string(), "1 US gal");
+        assert_eq!((2 * pt_imp).to_string(), "2 imp pt");
+        assert_eq!((8.0 * floz_us).to_string(), "8 US fl oz");
+        assert_eq!((0.5 * m3).to_string(), "0.5 m³");
+    }
+
+    #[test]
+    fn liquid_to() {
+        assert_eq!((1.0 * cup_metric).to(), 250.0 * mL);
+        assert_eq!((1.0 * tbsp_au).to(), 4.0 * tsp_metric);
+        assert_eq!(
+            format!("{:.6}", (1.0 * cup_us).to::<tbsp_us>()),
+            "16.000000 US tbsp"
+        );
+        assert_eq!(
+            format!("{:.6}", (1.0 * tbsp_us).to::<tsp_us>()),
+            "3.000000 US tsp"
+        );
+        assert_eq!(format!("{:.3}", (1.0 * cup_imp).to::<mL>()), "284.131 mL");
+        assert_eq!((1.0 * gal_us).to::<qt_us>(), 4.0 * qt_us);
+        assert_eq!((1.0 * qt_imp).to::<pt_imp>(), 2.0 * pt_imp);
+        assert_eq!(
+            (1.0 * pt_imp).to::<floz_imp>(),
+            20.000000000000004 * floz_imp
+        );
+        assert_eq!((1.0 * cup_us).to::<floz_us>(), 8.0 * floz_us);
+        assert_eq!((1.0 * m3).to::<L>(), 1_000.0 * L);
+    }
+
+    #[test]
+    fn liquid_us_metric() {
+        assert_eq!(format!("{:.2}", (1.0 * cup_us).to::<mL>()), "236.59 mL");
+        assert_eq!(format!("{:.2}", (1.0 * tbsp_us).to::<mL>()), "14.79 mL");
+        assert_eq!(format!("{:.2}", (1.0 * tsp_us).to::<mL>()), "4.93 mL");
+        assert_eq!(format!("{:.2}", (1.0 * floz_us).to::<mL>()), "29.57 mL");
+        assert_eq!((1.0 * floz_us).to::<tbsp_us>(), 2.0 * tbsp_us);
+    }
+
+    #[test]
+    fn liquid_volume() {
+        assert_eq!((1.0 * L).to_volume(), 1_000.0 * cm * cm * cm);
+        assert_eq!((1.0 * cm * cm * cm).to_liquid(), 1.0000000000000002 * mL);
+        assert_eq!(
+            format!("{:.3}", (1.0 * cup_us).to_volume::<In>()),
+            "14.438 in³"
+        );
+        assert_eq!((1.0 * m3).to_volume(), 1.0 * m * m * m);
+        assert_eq!(
+            format!("{:.6}", (1.0 * In * In * In).to_liters()),
+            "0.016387 L"
+        );
+    }
+
+    #[test]
+    fn liquid_ops() {
+        assert_eq!(1.5 * L + 0.5 * L, 2.0 * L);
+        assert_eq!(3.0 * tsp_us - 1.0 * tsp_us, 2.0 * tsp_us);
+        assert_eq!((2.0 * cup_metric) * 2.0, 4.0 * cup_metric);
+        assert_eq!((6.0 * mL) / 2.0, 3.0 * mL);
+    }
+}
@@ -0,0 +1,553 @@
+// lit.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+//! Compile-time parsing for the [quantity](crate::quantity) macro
+//!
+//! Every unit-declaring macro registers the unit's `Display` label, by
+//! implementing [Lookup] for a [Label] keyed on a hash of the label.  This
+//! works for custom units too, since the unit is a local type in the
+//! implementing crate.
+//!
+//! A literal is split by `const fn`s into a value and a label.  If the whole
+//! label is registered, the value is multiplied by that unit.  Otherwise, it
+//! is split into parts by its shape, such as `mi/h` or `m/s²`.
+use crate::quan::{Mass, Unit};
+use crate::{
+    length, time, Acceleration, Area, Density, FlowRate, Force, Volume,
+};
+use core::ops::{Div, Mul};
+
+/// Label shape: single unit, such as `km`
+pub const UNIT: u8 = 0;
+
+/// Label shape: `X²`
+pub const SQUARE: u8 = 1;
+
+/// Label shape: `X³`
+pub const CUBE: u8 = 2;
+
+/// Label shape: `/X`
+pub const INVERSE: u8 = 3;
+
+/// Label shape: `X/Y`
+pub const PER: u8 = 4;
+
+/// Label shape: `X/Y²`
+pub const PER_SQUARE: u8 = 5;
+
+/// Label shape: `X/Y³`
+pub const PER_CUBE: u8 = 6;
+
+/// Label shape: `X³/Y`
+pub const CUBE_PER: u8 = 7;
+
+/// Label shape: `X·Y/Z²`
+pub const PRODUCT_PER_SQUARE: u8 = 8;
+
+/// UTF-8 encoding of `²`
+const SUP_2: [u8; 2] = [0xC2, 0xB2];
+
+/// UTF-8 encoding of `³`
+const SUP_3: [u8; 2] = [0xC2, 0xB3];
+
+/// UTF-8 encoding of `·`
+const DOT: [u8; 2] = [0xC2, 0xB7];
+
+/// Unit label, keyed on its [hash]
+pub struct Label<const H: u64>;
+
+/// Lookup of a unit by label
+///
+/// `U` is the unit struct, so that other crates can implement this for their
+/// own units.
+pub trait Lookup<U> {
+    /// Part to multiply a value by
+    type Part;
+
+    /// Part value
+    const PART: Self::Part;
+}
+
+/// Hash a label (FNV-1a), never returning zero
+pub const fn hash(label: &str) -> u64 {
+    let b = label.as_bytes();
+    hash_range(b, 0, b.len())
+}
+
+/// Hash a range of bytes
+const fn hash_range(b: &[u8], start: usize, end: usize) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = start;
+    while i < end {
+        hash ^= b[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    if hash == 0 {
+        1
+    } else {
+        hash
+    }
+}
+
+/// Get the byte range of the value in a literal
+const fn number(b: &[u8]) -> (usize, usize) {
+    let mut start = 0;
+    while start < b.len() && b[start] == b' ' {
+        start += 1;
+    }
+    let mut end = start;
+    while end < b.len() {
+        match b[end] {
+            b'0'..=b'9' | b'.' | b'e' | b'E' => end += 1,
+            b'-' | b'+'
+                if end == start || matches!(b[end - 1], b'e' | b'E') =>
+            {
+                end += 1
+            }
+            _ => break,
+        }
+    }
+    (start, end)
+}
+
+/// Get the byte range of the label in a literal
+const fn label(b: &[u8]) -> (usize, usize) {
+    let (_, mut start) = number(b);
+    while start < b.len() && b[start] == b' ' {
+        start += 1;
+    }
+    let mut end = b.len();
+    while end > start && b[end - 1] == b' ' {
+        end -= 1;
+    }
+    if start == end {
+        panic!("quantity literal has no unit");
+    }
+    (start, end)
+}
+
+/// Parse the value of a literal, rounding to the nearest `f64`
+pub const fn value(lit: &str) -> f64 {
+    let b = lit.as_bytes();
+    let (start, end) = number(b);
+    let mut i = start;
+    let neg = i < end && b[i] == b'-';
+    if i < end && (b[i] == b'-' || b[i] == b'+') {
+        i += 1;
+    }
+    let mut mant: u128 = 0;
+    let mut digits = 0;
+    let mut exp: i32 = 0;
+    let mut frac = false;
+    while i < end && b[i] != b'e' && b[i] != b'E' {
+        if b[i] == b'.' {
+            if frac {
+                panic!("invalid quantity literal");
+            }
+            frac = true;
+        } else {
+            let digit = (b[i] - b'0') as u128;
+            mant = match mant.checked_mul(10) {
+                Some(m) if m <= u128::MAX - digit => m + digit,
+                _ => panic!("quantity literal has too many digits"),
+            };
+            digits += 1;
+            if frac {
+                exp -= 1;
+            }
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        panic!("quantity literal has no value");
+    }
+    if i < end {
+        i += 1;
+        let eneg = i < end && b[i] == b'-';
+        if i < end && (b[i] == b'-' || b[i] == b'+') {
+            i += 1;
+        }
+        if i == end {
+            panic!("invalid quantity literal");
+        }
+        let mut e = 0;
+        while i < end {
+            if !b[i].is_ascii_digit() {
+                panic!("invalid quantity literal");
+            }
+            if e > 1_000 {
+                panic!("quantity literal out of range");
+            }
+            e = e * 10 + (b[i] - b'0') as i32;
+            i += 1;
+        }
+        exp += if eneg { -e } else { e };
+    }
+    let val = if mant == 0 {
+        0.0
+    } else {
+        let mut scale: u128 = 1;
+        let mut e = exp.unsigned_abs();
+        while e > 0 {
+            scale = match scale.checked_mul(10) {
+                Some(s) => s,
+                None => panic!("quantity literal out of range"),
+            };
+            e -= 1;
+        }
+        if exp < 0 {
+            crate::div_rounded(mant, scale)
+        } else {
+            match mant.checked_mul(scale) {
+                Some(num) => crate::div_rounded(num, 1),
+                None => panic!("quantity literal out of range"),
+            }
+        }
+    };
+    if neg {
+        -val
+    } else {
+        val
+    }
+}
+
+/// Check whether a byte range ends with a suffix
+const fn ends_with(
+    b: &[u8],
+    start: usize,
+    end: usize,
+    suffix: [u8; 2],
+) -> bool {
+    end >= start + 2 && b[end - 2] == suffix[0] && b[end - 1] == suffix[1]
+}
+
+/// Find a byte sequence within a range
+const fn find(b: &[u8], start: usize, end: usize, pat: &[u8]) -> Option<usize> {
+    let mut i = start;
+    while i + pat.len() <= end {
+        let mut j = 0;
+        while j < pat.len() && b[i + j] == pat[j] {
+            j += 1;
+        }
+        if j == pat.len() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split the label of a literal into its shape and part ranges
+const fn split(b: &[u8]) -> (u8, [(usize, usize); 3]) {
+    let (s, e) = label(b);
+    let no = (0, 0);
+    if b[s] == b'/' {
+        return (INVERSE, [(s + 1, e), no, no]);
+    }
+    let Some(p) = find(b, s, e, b"/") else {
+        if ends_with(b, s, e, SUP_2) {
+            return (SQUARE, [(s, e - 2), no, no]);
+        }
+        if ends_with(b, s, e, SUP_3) {
+            return (CUBE, [(s, e - 2), no, no]);
+        }
+        return (UNIT, [(s, e), no, no]);
+    };
+    if let Some(d) = find(b, s, p, &DOT) {
+        if ends_with(b, p + 1, e, SUP_2) {
+            return (PRODUCT_PER_SQUARE, [(s, d), (d + 2, p), (p + 1, e - 2)]);
+        }
+        return (UNIT, [(s, e), no, no]);
+    }
+    if ends_with(b, s, p, SUP_3) {
+        return (CUBE_PER, [(s, p - 2), (p + 1, e), no]);
+    }
+    if ends_with(b, p + 1, e, SUP_2) {
+        return (PER_SQUARE, [(s, p), (p + 1, e - 2), no]);
+    }
+    if ends_with(b, p + 1, e, SUP_3) {
+        return (PER_CUBE, [(s, p), (p + 1, e - 2), no]);
+    }
+    (PER, [(s, p), (p + 1, e), no])
+}
+
+/// Hash the whole label of a literal
+pub const fn whole(lit: &str) -> u64 {
+    let b = lit.as_bytes();
+    let (start, end) = label(b);
+    hash_range(b, start, end)
+}
+
+/// Get the shape of the label of a literal
+pub const fn shape(lit: &str) -> u8 {
+    split(lit.as_bytes()).0
+}
+
+/// Hash one part of the label of a literal (zero if there is none)
+pub const fn part(lit: &str, i: usize) -> u64 {
+    let b = lit.as_bytes();
+    let (start, end) = split(b).1[i];
+    if start < end {
+        hash_range(b, start, end)
+    } else {
+        0
+    }
+}
+
+/// Probe to build a quantity from a literal
+///
+/// * `W` Hash of whole label
+/// * `S` Label shape
+/// * `A`, `B`, `C` Hashes of label parts
+pub struct Probe<
+    const W: u64,
+    const S: u8,
+    const A: u64,
+    const B: u64,
+    const C: u64,
+>(pub f64);
+
+/// Build a quantity from a registered whole label
+///
+/// This is implemented on `Probe`, so method resolution picks it before
+/// [Split], which is implemented on `&Probe`.
+pub trait Whole<U> {
+    /// Quantity type
+    type Output;
+
+    /// Build the quantity
+    fn build(&self) -> Self::Output;
+}
+
+impl<
+        U,
+        const W: u64,
+        const S: u8,
+        const A: u64,
+        const B: u64,
+        const C: u64,
+    > Whole<U> for Probe<W, S, A, B, C>
+where
+    Label<W>: Lookup<U>,
+    f64: Mul<<Label<W> as Lookup<U>>::Part>,
+{
+    type Output = <f64 as Mul<<Label<W> as Lookup<U>>::Part>>::Output;
+
+    fn build(&self) -> Self::Output {
+        self.0 * <Label<W> as Lookup<U>>::PART
+    }
+}
+
+/// Build a quantity from the parts of a label
+pub trait Split<X, Y, Z> {
+    /// Quantity type
+    type Output;
+
+    /// Build the quantity
+    fn build(&self) -> Self::Output;
+}
+
+// X² => Area
+impl<X, const W: u64, const A: u64, const B: u64, const C: u64> Split<X, (), ()>
+    for &Probe<W, SQUARE, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    X: length::Unit,
+{
+    type Output = Area<X>;
+
+    fn build(&self) -> Self::Output {
+        Area::new(self.0)
+    }
+}
+
+// X³ => Volume
+impl<X, const W: u64, const A: u64, const B: u64, const C: u64> Split<X, (), ()>
+    for &Probe<W, CUBE, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    X: length::Unit,
+{
+    type Output = Volume<X>;
+
+    fn build(&self) -> Self::Output {
+        Volume::new(self.0)
+    }
+}
+
+// /X => InverseLength, Frequency
+impl<X, const W: u64, const A: u64, const B: u64, const C: u64> Split<X, (), ()>
+    for &Probe<W, INVERSE, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    f64: Div<X>,
+{
+    type Output = <f64 as Div<X>>::Output;
+
+    fn build(&self) -> Self::Output {
+        self.0 / <Label<A> as Lookup<X>>::PART
+    }
+}
+
+// X/Y => Speed, Bandwidth, etc.
+impl<X, Y, const W: u64, const A: u64, const B: u64, const C: u64>
+    Split<X, Y, ()> for &Probe<W, PER, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    Label<B>: Lookup<Y, Part = Y>,
+    f64: Mul<X> + Mul<Y>,
+    <f64 as Mul<X>>::Output: Div<<f64 as Mul<Y>>::Output>,
+{
+    type Output =
+        <<f64 as Mul<X>>::Output as Div<<f64 as Mul<Y>>::Output>>::Output;
+
+    fn build(&self) -> Self::Output {
+        let num = self.0 * <Label<A> as Lookup<X>>::PART;
+        num / (1.0 * <Label<B> as Lookup<Y>>::PART)
+    }
+}
+
+// X/Y² => Acceleration
+impl<X, Y, const W: u64, const A: u64, const B: u64, const C: u64>
+    Split<X, Y, ()> for &Probe<W, PER_SQUARE, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    Label<B>: Lookup<Y, Part = Y>,
+    X: length::Unit,
+    Y: time::Unit,
+{
+    type Output = Acceleration<X, Y>;
+
+    fn build(&self) -> Self::Output {
+        Acceleration::new(self.0)
+    }
+}
+
+// X/Y³ => Density
+impl<X, Y, const W: u64, const A: u64, const B: u64, const C: u64>
+    Split<X, Y, ()> for &Probe<W, PER_CUBE, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    Label<B>: Lookup<Y, Part = Y>,
+    X: Unit<Measure = Mass>,
+    Y: length::Unit,
+{
+    type Output = Density<X, Y>;
+
+    fn build(&self) -> Self::Output {
+        Density::new(self.0)
+    }
+}
+
+// X³/Y => FlowRate
+impl<X, Y, const W: u64, const A: u64, const B: u64, const C: u64>
+    Split<X, Y, ()> for &Probe<W, CUBE_PER, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    Label<B>: Lookup<Y, Part = Y>,
+    X: length::Unit,
+    Y: time::Unit,
+{
+    type Output = FlowRate<X, Y>;
+
+    fn build(&self) -> Self::Output {
+        FlowRate::new(self.0)
+    }
+}
+
+// X·Y/Z² => Force
+impl<X, Y, Z, const W: u64, const A: u64, const B: u64, const C: u64>
+    Split<X, Y, Z> for &Probe<W, PRODUCT_PER_SQUARE, A, B, C>
+where
+    Label<A>: Lookup<X, Part = X>,
+    Label<B>: Lookup<Y, Part = Y>,
+    Label<C>: Lookup<Z, Part = Z>,
+    X: Unit<Measure = Mass>,
+    Y: length::Unit,
+    Z: time::Unit,
+{
+    type Output = Force<X, Y, Z>;
+
+    fn build(&self) -> Self::Output {
+        Force::new(self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::information::{Bandwidth, MB};
+    use crate::land::m2;
+    use crate::length::{ft, km, m, mi, In};
+    use crate::liquid::floz_us;
+    use crate::mass::kg;
+    use crate::molar::g_mol;
+    use crate::power::W;
+    use crate::speed::mph;
+    use crate::temp::DegF;
+    use crate::time::{h, min, s};
+    use crate::Frequency;
+    use alloc::string::ToString;
+
+    #[test]
+    fn lit_value() {
+        assert_eq!(value("55 mi/h"), 55.0);
+        assert_eq!(value("  -2.5 m"), -2.5);
+        assert_eq!(value("+0.1 m"), 0.1);
+        assert_eq!(value("1.5e3 m"), 1_500.0);
+        assert_eq!(value("1E-3 m"), 0.001);
+        assert_eq!(value("0.000 m"), 0.0);
+        assert_eq!(value("9007199254740993 m"), 9_007_199_254_740_992.0);
+        assert_eq!(value("0.30000000000000004 m"), 0.300_000_000_000_000_04);
+    }
+
+    #[test]
+    fn lit_shape() {
+        assert_eq!(shape("1 km"), UNIT);
+        assert_eq!(shape("1 US fl oz"), UNIT);
+        assert_eq!(shape("1 km²"), SQUARE);
+        assert_eq!(shape("1 ft³"), CUBE);
+        assert_eq!(shape("1 /km"), INVERSE);
+        assert_eq!(shape("1 mi/h"), PER);
+        assert_eq!(shape("1 m/s²"), PER_SQUARE);
+        assert_eq!(shape("1 kg/m³"), PER_CUBE);
+        assert_eq!(shape("1 m³/s"), CUBE_PER);
+        assert_eq!(shape("1 kg·m/s²"), PRODUCT_PER_SQUARE);
+        assert_eq!(shape("1 N·m"), UNIT);
+        assert_eq!(part("1 kg·m/s²", 1), hash("m"));
+        assert_eq!(part("1 kg·m/s²", 2), hash("s"));
+        assert_eq!(part("1 km", 1), 0);
+        assert_eq!(whole(" 1 US fl oz "), hash("US fl oz"));
+    }
+
+    #[test]
+    fn lit_quantity() {
+        assert_eq!(quantity!("55 mi/h"), 55.0 * mi / h);
+        assert_eq!(quantity!("25 mph"), 25 * mph);
+        assert_eq!(quantity!("3.5 kg"), 3.5 * kg);
+        assert_eq!(quantity!("12 in"), 12 * In);
+        assert_eq!(quantity!("-40 °F"), -40 * DegF);
+        assert_eq!(quantity!("8 US fl oz"), 8 * floz_us);
+        assert_eq!(quantity!("2.5 km²"), 2.5 * km * km);
+        assert_eq!(quantity!("2 ft³"), 2 * ft * ft * ft);
+        assert_eq!(quantity!("4 m²"), 4 * m2);
+        assert_eq!(quantity!("5 /km"), 5.0 / km);
+        assert_eq!(quantity!("60 ㎐"), Frequency::<s>::new(60.0));
+        assert_eq!(quantity!("30 /min"), 30.0 / min);
+        assert_eq!(quantity!("18 g/mol"), 18 * g_mol);
+        assert_eq!(quantity!("100 MB/s"), Bandwidth::<MB, s>::new(100.0));
+        assert_eq!(quantity!("9.8 m/s²"), 9.8 * m / s / s);
+        assert_eq!(quantity!("4 J/s"), 4 * W);
+        let d = quantity!("1000 kg/m³");
+        assert_eq!(d.to_string(), "1000 kg/m³");
+        let f = quantity!("2 m³/s");
+        assert_eq!(f.to_string(), "2 m³/s");
+        let n = quantity!("5 g·m/s²");
+        assert_eq!(n.to_string(), "5 g·m/s²");
+        assert_eq!(n.to::<kg, m, s>().quantity(), 0.005);
+    }
+}
@@ -0,0 +1,561 @@
+// long.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Long-form display of quantities
+//!
+use crate::quan::{self, Magnitude, Quantity};
+use crate::{length, time};
+use crate::{Area, Frequency, Length, Period, Speed, Volume};
+use core::fmt;
+
+/// English names of a unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Name {
+    /// Singular name
+    pub singular: &'static str,
+
+    /// Plural name
+    pub plural: &'static str,
+}
+
+/// Unit names of a quantity
+///
+/// English names are used as keys for translation by a [Language].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitName {
+    /// Simple unit (meter, second, gram, etc.)
+    Simple(Name),
+
+    /// Squared unit (square meter)
+    Square(Name),
+
+    /// Cubed unit (cubic meter)
+    Cube(Name),
+
+    /// Inverse unit (per second)
+    Inverse(Name),
+
+    /// Ratio of units (meter per second)
+    Per(Name, Name),
+}
+
+/// Language for long-form unit names
+///
+/// Applications can implement this trait to provide translations and
+/// pluralization rules for unit names.
+///
+/// ## Example
+///
+/// ```rust
+/// use core::fmt;
+/// use mag::long::{English, Language, Name, UnitName};
+/// use mag::length::{km, m};
+///
+/// struct French;
+///
+/// impl French {
+///     fn name(&self, name: Name, value: f64) -> &'static str {
+///         let plural = value.abs() >= 2.0;
+///         match (name.singular, plural) {
+///             ("meter", false) => "mètre",
+///             ("meter", true) => "mètres",
+///             ("kilometer", false) => "kilomètre",
+///             ("kilometer", true) => "kilomètres",
+///             (_, false) => name.singular,
+///             (_, true) => name.plural,
+///         }
+///     }
+/// }
+///
+/// impl Language for French {
+///     fn write_unit(
+///         &self,
+///         f: &mut fmt::Formatter,
+///         value: f64,
+///         unit: UnitName,
+///     ) -> fmt::Result {
+///         match unit {
+///             UnitName::Simple(n) => write!(f, "{}", self.name(n, value)),
+///             UnitName::Square(n) => {
+///                 let c = if value.abs() >= 2.0 { "carrés" } else { "carré" };
+///                 write!(f, "{} {c}", self.name(n, value))
+///             }
+///             _ => English.write_unit(f, value, unit),
+///         }
+///     }
+/// }
+///
+/// assert_eq!((1.5 * km).long_form_in(French).to_string(), "1.5 kilomètre");
+/// assert_eq!((3 * m * m).long_form_in(French).to_string(), "3 mètres carrés");
+/// ```
+pub trait Language {
+    /// Write unit names for a value
+    fn write_unit(
+        &self,
+        f: &mut fmt::Formatter,
+        value: f64,
+        unit: UnitName,
+    ) -> fmt::Result;
+}
+
+/// English unit names
+///
+/// Names are plural unless the value is exactly 1 or -1.  The American
+/// spellings "meter" and "liter" are used; see [International] for "metre"
+/// and "litre".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct English;
+
+/// English unit names, with international spelling
+///
+/// This is the same as [English], except that names ending in "meter" or
+/// "liter" are spelled "metre" or "litre", as in UK and International
+/// English.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::km, liquid::mL, long::International, time::h};
+///
+/// let a = (5.5 * km).long_form_in(International);
+/// let b = (100 * km / h).long_form_in(International);
+/// let c = (250 * mL).long_form_in(International);
+///
+/// assert_eq!(a.to_string(), "5.5 kilometres");
+/// assert_eq!(b.to_string(), "100 kilometres per hour");
+/// assert_eq!(c.to_string(), "250 millilitres");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct International;
+
+impl<G> Language for &G
+where
+    G: Language,
+{
+    fn write_unit(
+        &self,
+        f: &mut fmt::Formatter,
+        value: f64,
+        unit: UnitName,
+    ) -> fmt::Result {
+        (*self).write_unit(f, value, unit)
+    }
+}
+
+impl Language for English {
+    fn write_unit(
+        &self,
+        f: &mut fmt::Formatter,
+        value: f64,
+        unit: UnitName,
+    ) -> fmt::Result {
+        write_english(f, value, unit, false)
+    }
+}
+
+impl Language for International {
+    fn write_unit(
+        &self,
+        f: &mut fmt::Formatter,
+        value: f64,
+        unit: UnitName,
+    ) -> fmt::Result {
+        write_english(f, value, unit, true)
+    }
+}
+
+/// Write English unit names
+///
+/// * `re` Use "-re" spelling (metre, litre)
+fn write_english(
+    f: &mut fmt::Formatter,
+    value: f64,
+    unit: UnitName,
+    re: bool,
+) -> fmt::Result {
+    let name = |n: Name| {
+        if value == 1.0 || value == -1.0 {
+            n.singular
+        } else {
+            n.plural
+        }
+    };
+    let spell = |f: &mut fmt::Formatter, n: &str| {
+        if re {
+            write_re(f, n)
+        } else {
+            f.write_str(n)
+        }
+    };
+    match unit {
+        UnitName::Simple(n) => spell(f, name(n)),
+        UnitName::Square(n) => {
+            f.write_str("square ")?;
+            spell(f, name(n))
+        }
+        UnitName::Cube(n) => {
+            f.write_str("cubic ")?;
+            spell(f, name(n))
+        }
+        UnitName::Inverse(n) => {
+            f.write_str("per ")?;
+            spell(f, n.singular)
+        }
+        UnitName::Per(n, p) => {
+            spell(f, name(n))?;
+            f.write_str(" per ")?;
+            spell(f, p.singular)
+        }
+    }
+}
+
+/// Write a unit name with "-re" spelling
+fn write_re(f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+    for (er, re) in [("meter", "metre"), ("liter", "litre")] {
+        if let Some(i) = name.find(er) {
+            f.write_str(&name[..i])?;
+            f.write_str(re)?;
+            return f.write_str(&name[i + er.len()..]);
+        }
+    }
+    f.write_str(name)
+}
+
+/// Quantity which has long-form unit names
+pub trait Named: Magnitude {
+    /// Get the unit names
+    fn unit_name(&self) -> UnitName;
+}
+
+/// Quantity displayed with unit names instead of labels.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::{cm, ft, mi}, temp::DegC, time::h};
+///
+/// assert_eq!((25.5 * cm).long_form().to_string(), "25.5 centimeters");
+/// assert_eq!((1 * ft).long_form().to_string(), "1 foot");
+/// assert_eq!((2 * ft * ft).long_form().to_string(), "2 square feet");
+/// assert_eq!((55 * mi / h).long_form().to_string(), "55 miles per hour");
+/// assert_eq!((21.5 * DegC).long_form().to_string(), "21.5 degrees Celsius");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct LongForm<Q, G = English>(pub Q, pub G);
+
+impl<Q, G> fmt::Display for LongForm<Q, G>
+where
+    Q: Named,
+    G: Language,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.0.magnitude();
+        value.fmt(f)?;
+        write!(f, " ")?;
+        self.1.write_unit(f, value, self.0.unit_name())
+    }
+}
+
+impl<U> Length<U>
+where
+    U: length::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+impl<U> Area<U>
+where
+    U: length::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+impl<U> Volume<U>
+where
+    U: length::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+impl<U> Period<U>
+where
+    U: time::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+impl<U> Frequency<U>
+where
+    U: time::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+impl<L, P> Speed<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+impl<U> Quantity<U>
+where
+    U: quan::Unit,
+{
+    /// Get long-form display, using English unit names
+    pub fn long_form(self) -> LongForm<Self> {
+        LongForm(self, English)
+    }
+
+    /// Get long-form display, using unit names from a language
+    pub fn long_form_in<G: Language>(self, lang: G) -> LongForm<Self, G> {
+        LongForm(self, lang)
+    }
+}
+
+/// Get names of a length unit
+fn length_name<U: length::Unit>() -> Name {
+    Name {
+        singular: U::NAME,
+        plural: U::NAME_PLURAL,
+    }
+}
+
+/// Get names of a time unit
+fn time_name<U: time::Unit>() -> Name {
+    Name {
+        singular: U::NAME,
+        plural: U::NAME_PLURAL,
+    }
+}
+
+impl<U> Named for Length<U>
+where
+    U: length::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Simple(length_name::<U>())
+    }
+}
+
+impl<U> Named for Area<U>
+where
+    U: length::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Square(length_name::<U>())
+    }
+}
+
+impl<U> Named for Volume<U>
+where
+    U: length::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Cube(length_name::<U>())
+    }
+}
+
+impl<U> Named for Period<U>
+where
+    U: time::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Simple(time_name::<U>())
+    }
+}
+
+impl<U> Named for Frequency<U>
+where
+    U: time::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Inverse(time_name::<U>())
+    }
+}
+
+impl<L, P> Named for Speed<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Per(length_name::<L>(), time_name::<P>())
+    }
+}
+
+impl<U> Named for Quantity<U>
+where
+    U: quan::Unit,
+{
+    fn unit_name(&self) -> UnitName {
+        UnitName::Simple(Name {
+            singular: U::NAME,
+            plural: U::NAME_PLURAL,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{cm, ft, m, mi, In};
+    use crate::liquid::{cup_us, L};
+    use crate::mass::{kg, lb};
+    use crate::temp::DegF;
+    use crate::time::{h, min, s};
+    use crate::{declare_unit, length_unit, quan::Mass};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn long_length() {
+        assert_eq!((25.5 * cm).long_form().to_string(), "25.5 centimeters");
+        assert_eq!((1.0 * m).long_form().to_string(), "1 meter");
+        assert_eq!((-1.0 * mi).long_form().to_string(), "-1 mile");
+        assert_eq!((0.5 * In).long_form().to_string(), "0.5 inches");
+        assert_eq!((3 * m * m).long_form().to_string(), "3 square meters");
+        assert_eq!((1 * In * In * In).long_form().to_string(), "1 cubic inch");
+        assert_eq!(format!("{:.1}", (2.0 * ft).long_form()), "2.0 feet");
+    }
+
+    #[test]
+    fn long_time() {
+        assert_eq!((1 * h).long_form().to_string(), "1 hour");
+        assert_eq!((90 * min).long_form().to_string(), "90 minutes");
+        assert_eq!((60 / s).long_form().to_string(), "60 per second");
+        assert_eq!((1 * m / s).long_form().to_string(), "1 meter per second");
+    }
+
+    #[test]
+    fn long_quan() {
+        assert_eq!((2.5 * kg).long_form().to_string(), "2.5 kilograms");
+        assert_eq!((1 * lb).long_form().to_string(), "1 pound");
+        assert_eq!(
+            (98.6 * DegF).long_form().to_string(),
+            "98.6 degrees Fahrenheit"
+        );
+        assert_eq!((2 * cup_us).long_form().to_string(), "2 US cups");
+    }
+
+    #[test]
+    fn long_custom() {
+        length_unit!(smoot, "smoot", 1.7018);
+        declare_unit!(stone, "st", Mass, 6_350.293_18, "stone", "stone",);
+        assert_eq!((3 * smoot).long_form().to_string(), "3 smoot");
+        assert_eq!((12 * stone).long_form().to_string(), "12 stone");
+    }
+
+    struct German;
+
+    impl Language for German {
+        fn write_unit(
+            &self,
+            f: &mut fmt::Formatter,
+            value: f64,
+            unit: UnitName,
+        ) -> fmt::Result {
+            match unit {
+                UnitName::Simple(n) => match n.singular {
+                    "hour" if value == 1.0 => write!(f, "Stunde"),
+                    "hour" => write!(f, "Stunden"),
+                    "meter" => write!(f, "Meter"),
+                    _ => English.write_unit(f, value, unit),
+                },
+                UnitName::Square(n) if n.singular == "meter" => {
+                    write!(f, "Quadratmeter")
+                }
+                _ => English.write_unit(f, value, unit),
+            }
+        }
+    }
+
+    #[test]
+    fn long_international() {
+        let intl = International;
+        assert_eq!((1.0 * m).long_form_in(intl).to_string(), "1 metre");
+        assert_eq!(
+            (2.5 * cm).long_form_in(intl).to_string(),
+            "2.5 centimetres"
+        );
+        assert_eq!(
+            (3 * m * m).long_form_in(intl).to_string(),
+            "3 square metres"
+        );
+        assert_eq!(
+            (1 * m * m * m).long_form_in(intl).to_string(),
+            "1 cubic metre"
+        );
+        assert_eq!((2 * ft).long_form_in(intl).to_string(), "2 feet");
+        assert_eq!(
+            (5 * m / s).long_form_in(intl).to_string(),
+            "5 metres per second"
+        );
+        assert_eq!((60 / s).long_form_in(intl).to_string(), "60 per second");
+        assert_eq!((1 * L).long_form_in(intl).to_string(), "1 litre");
+        assert_eq!((2 * cup_us).long_form_in(intl).to_string(), "2 US cups");
+    }
+
+    #[test]
+    fn long_language() {
+        assert_eq!((1 * h).long_form_in(German).to_string(), "1 Stunde");
+        assert_eq!((2.5 * h).long_form_in(&German).to_string(), "2.5 Stunden");
+        assert_eq!(
+            (4 * m * m).long_form_in(German).to_string(),
+            "4 Quadratmeter"
+        );
+        assert_eq!((4 * s).long_form_in(German).to_string(), "4 seconds");
+        assert_eq!((4 * s).long_form_in(English), (4 * s).long_form());
+    }
+}
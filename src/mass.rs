@@ -20,7 +20,9 @@
 //! assert_eq!(b.to_string(), "5 g");
 //! ```
 use crate::declare_unit;
-use crate::quan::Mass;
+use crate::quan::{Mass, Quantity, Unit};
+use crate::sum::KahanSum;
+use core::fmt;
 
 declare_unit!(
     /** Metric Ton / Tonne */
@@ -28,6 +30,8 @@ declare_unit!(
     "t",
     Mass,
     1_000_000.0,
+    "tonne",
+    "tonnes",
 );
 
 declare_unit!(
@@ -36,6 +40,8 @@ declare_unit!(
     "kg",
     Mass,
     1_000.0,
+    "kilogram",
+    "kilograms",
 );
 
 declare_unit!(
@@ -44,6 +50,8 @@ declare_unit!(
     "g",
     Mass,
     1.0,
+    "gram",
+    "grams",
 );
 
 declare_unit!(
@@ -52,6 +60,8 @@ declare_unit!(
     "dg",
     Mass,
     0.1,
+    "decigram",
+    "decigrams",
 );
 
 declare_unit!(
@@ -60,6 +70,8 @@ declare_unit!(
     "cg",
     Mass,
     0.01,
+    "centigram",
+    "centigrams",
 );
 
 declare_unit!(
@@ -68,6 +80,8 @@ declare_unit!(
     "mg",
     Mass,
     0.001,
+    "milligram",
+    "milligrams",
 );
 
 declare_unit!(
@@ -76,6 +90,8 @@ declare_unit!(
     "μg",
     Mass,
     0.000_001,
+    "microgram",
+    "micrograms",
 );
 
 declare_unit!(
@@ -84,6 +100,8 @@ declare_unit!(
     "ng",
     Mass,
     0.000_000_001,
+    "nanogram",
+    "nanograms",
 );
 
 declare_unit!(
@@ -92,6 +110,8 @@ declare_unit!(
     "lb",
     Mass,
     453.592_37,
+    "pound",
+    "pounds",
 );
 
 declare_unit!(
@@ -100,6 +120,8 @@ declare_unit!(
     "sl",
     Mass,
     14_593.903,
+    "slug",
+    "slugs",
 );
 
 declare_unit!(
@@ -108,8 +130,74 @@ declare_unit!(
     "Da",
     Mass,
     1.660_539_066_60e-24,
+    "dalton",
+    "daltons",
 );
 
+unit_fns!(
+    tonnes => Quantity<t>::new_const,
+    kilograms => Quantity<kg>::new_const,
+    grams => Quantity<g>::new_const,
+    milligrams => Quantity<mg>::new_const,
+    micrograms => Quantity<ug>::new_const,
+    pounds => Quantity<lb>::new_const,
+);
+
+/// Mass balance error, with the discrepancy (parts minus total)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Imbalance<U>(pub Quantity<U>)
+where
+    U: Unit<Measure = Mass>;
+
+impl<U> fmt::Display for Imbalance<U>
+where
+    U: Unit<Measure = Mass>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "mass imbalance: ")?;
+        // show explicit sign for excess
+        if self.0.value() > 0.0 {
+            write!(f, "+")?;
+        }
+        self.0.fmt(f)
+    }
+}
+
+impl<U> core::error::Error for Imbalance<U> where
+    U: Unit<Measure = Mass> + fmt::Debug
+{
+}
+
+/// Check that the sum of parts balances with a total, within a tolerance
+///
+/// ```rust
+/// use mag::mass::{g, kg, reconcile, Imbalance};
+///
+/// let parts = [1.2 * kg, 0.8 * kg, 0.5 * kg];
+/// assert_eq!(reconcile(&parts, 2.5 * kg, (10 * g).to()), Ok(()));
+///
+/// let err = reconcile(&parts, 2.0 * kg, (10 * g).to()).unwrap_err();
+/// assert_eq!(err, Imbalance(0.5 * kg));
+/// assert_eq!(err.to_string(), "mass imbalance: +0.5 kg");
+/// ```
+pub fn reconcile<U>(
+    parts: &[Quantity<U>],
+    total: Quantity<U>,
+    tol: Quantity<U>,
+) -> Result<(), Imbalance<U>>
+where
+    U: Unit<Measure = Mass> + Copy,
+{
+    let mut sum = parts.iter().copied().collect::<KahanSum<_>>();
+    sum.add(Quantity::new(-total.value()));
+    let diff = sum.total();
+    if diff.value() <= tol.value() && diff.value() >= -tol.value() {
+        Ok(())
+    } else {
+        Err(Imbalance(diff))
+    }
+}
+
 #[cfg(test)]
 mod test {
     extern crate alloc;
@@ -117,6 +205,24 @@ mod test {
     use super::*;
     use alloc::string::ToString;
 
+    #[test]
+    fn mass_scalars() {
+        let reading: u32 = 750;
+        assert_eq!(g * reading, 750.0 * g);
+        assert_eq!(kg * 1.5_f32, 1.5 * kg);
+        assert_eq!(lb * 40_i64, 40.0 * lb);
+        assert_eq!(reading * (1.0 * g), 750.0 * g);
+        assert_eq!(-2_i32 * (1.5 * kg), -3.0 * kg);
+    }
+
+    #[test]
+    fn mass_fns() {
+        const LOAD: Quantity<kg> = kilograms(3.0);
+        assert_eq!(LOAD, 3.0 * kg);
+        assert_eq!(grams(2.5), 2.5 * g);
+        assert_eq!(pounds(1.0).to(), 453.59237 * g);
+    }
+
     #[test]
     fn mass_display() {
         assert_eq!((2.5 * kg).to_string(), "2.5 kg");
@@ -127,6 +233,18 @@ mod test {
         assert_eq!((3.9 * ug).to_string(), "3.9 μg");
     }
 
+    #[test]
+    fn mass_unit_id() {
+        extern crate std;
+        use crate::quan::Unit;
+        use std::collections::HashSet;
+
+        let ids: HashSet<_> = [kg::id(), g::id(), lb::id(), kg::id()].into();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.contains(&lb::id()));
+        assert!(!ids.contains(&mg::id()));
+    }
+
     #[test]
     fn mass_to() {
         assert_eq!((1.0 * g).to(), (0.001 * kg));
@@ -149,10 +267,54 @@ mod test {
     fn mass_mul() {
         assert_eq!((3.0 * ng) * 3.0, 9.0 * ng);
         assert_eq!(3.0 * (3.0 * g), 9.0 * g);
+        assert_eq!((0.5 * kg) * 12u32, 6.0 * kg);
+        assert_eq!(12u32 * (0.25 * kg), 3.0 * kg);
+        assert_eq!((0.5 * g).times(3_000_000_000), 1_500_000_000.0 * g);
     }
 
     #[test]
     fn mass_div() {
         assert_eq!((5.0 * dg) / 5.0, 1.0 * dg);
     }
+
+    #[test]
+    fn mass_assign() {
+        let mut a = 2.5 * kg;
+        a += 1.5 * kg;
+        a -= 1.0 * kg;
+        a *= 4.0;
+        a /= 2.0;
+        assert_eq!(a, 6.0 * kg);
+    }
+
+    #[test]
+    fn mass_parse() {
+        use crate::quan::ParseError;
+
+        assert_eq!("2.5 kg".parse(), Ok(2.5 * kg));
+        assert_eq!("3.9 μg".parse(), Ok(3.9 * ug));
+        assert_eq!("lb".parse::<Quantity<lb>>(), Err(ParseError::Value));
+    }
+
+    #[test]
+    fn mass_reinterpret() {
+        assert_eq!((5.0 * lb).reinterpret_unit::<kg>(), 5.0 * kg);
+    }
+
+    #[test]
+    fn mass_reconcile() {
+        let parts = [0.1 * g; 10];
+        assert_eq!(reconcile(&parts, 1.0 * g, 0.000_001 * g), Ok(()));
+        assert_eq!(
+            reconcile(&parts, 1.5 * g, 0.1 * g),
+            Err(Imbalance(-0.49999999999999994 * g))
+        );
+        assert_eq!(reconcile(&[], 0.0 * lb, 0.0 * lb), Ok(()));
+        assert_eq!(reconcile(&[1.0 * kg], 1.5 * kg, 0.5 * kg), Ok(()));
+        assert_eq!(
+            reconcile(&[1.0 * kg], 1.75 * kg, 0.5 * kg),
+            Err(Imbalance(-0.75 * kg))
+        );
+        assert_eq!(Imbalance(-2.0 * lb).to_string(), "mass imbalance: -2 lb");
+    }
 }
@@ -0,0 +1,138 @@
+// maxspeed.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! OpenStreetMap `maxspeed` tags
+//!
+//! The [maxspeed] tag holds a number in km/h, a number followed by `mph`, or
+//! one of a few special values.  [MaxSpeed] parses these into typed speeds.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{km, mi}, maxspeed::MaxSpeed, time::h};
+//!
+//! assert_eq!("50".parse(), Ok(MaxSpeed::Kph(50.0 * km / h)));
+//! assert_eq!("30 mph".parse(), Ok(MaxSpeed::Mph(30.0 * mi / h)));
+//! assert_eq!("none".parse(), Ok(MaxSpeed::Unlimited));
+//!
+//! let limit: MaxSpeed = "walk".parse()?;
+//! assert_eq!(limit.speed(), None);
+//! # Ok::<(), mag::quan::ParseError>(())
+//! ```
+//!
+//! [maxspeed]: https://wiki.openstreetmap.org/wiki/Key:maxspeed
+use crate::length::{km, mi};
+use crate::quan::ParseError;
+use crate::time::h;
+use crate::Speed;
+use core::fmt;
+use core::str::FromStr;
+
+/// Value of a `maxspeed` tag
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MaxSpeed {
+    /// Speed limit in km/h (no unit in tag)
+    Kph(Speed<km, h>),
+    /// Speed limit in mph
+    Mph(Speed<mi, h>),
+    /// Walking pace (`walk`)
+    Walk,
+    /// No speed limit (`none`)
+    Unlimited,
+    /// Limit shown by traffic signals (`signals`)
+    Signals,
+    /// Variable limit, such as on electronic signs (`variable`)
+    Variable,
+}
+
+impl MaxSpeed {
+    /// Get the speed limit in km/h, if numeric
+    pub fn speed(self) -> Option<Speed<km, h>> {
+        match self {
+            MaxSpeed::Kph(speed) => Some(speed),
+            MaxSpeed::Mph(speed) => Some(speed.to()),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for MaxSpeed {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s {
+            "walk" => return Ok(MaxSpeed::Walk),
+            "none" => return Ok(MaxSpeed::Unlimited),
+            "signals" => return Ok(MaxSpeed::Signals),
+            "variable" => return Ok(MaxSpeed::Variable),
+            _ => (),
+        }
+        let (value, unit) = match s.split_once(' ') {
+            Some((value, unit)) => (value, Some(unit.trim_start())),
+            None => (s, None),
+        };
+        let value: f64 = value.parse().map_err(|_| ParseError::Value)?;
+        if !value.is_finite() || value < 0.0 {
+            return Err(ParseError::Value);
+        }
+        match unit {
+            None | Some("km/h") => Ok(MaxSpeed::Kph(Speed::new(value))),
+            Some("mph") => Ok(MaxSpeed::Mph(Speed::new(value))),
+            Some(_) => Err(ParseError::Unit),
+        }
+    }
+}
+
+impl fmt::Display for MaxSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MaxSpeed::Kph(speed) => write!(f, "{}", speed.quantity()),
+            MaxSpeed::Mph(speed) => write!(f, "{} mph", speed.quantity()),
+            MaxSpeed::Walk => write!(f, "walk"),
+            MaxSpeed::Unlimited => write!(f, "none"),
+            MaxSpeed::Signals => write!(f, "signals"),
+            MaxSpeed::Variable => write!(f, "variable"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse() {
+        assert_eq!("100".parse(), Ok(MaxSpeed::Kph(100.0 * km / h)));
+        assert_eq!(" 60 km/h".parse(), Ok(MaxSpeed::Kph(60.0 * km / h)));
+        assert_eq!("25 mph".parse(), Ok(MaxSpeed::Mph(25.0 * mi / h)));
+        assert_eq!("signals".parse(), Ok(MaxSpeed::Signals));
+        assert_eq!("variable".parse(), Ok(MaxSpeed::Variable));
+        assert_eq!("fast".parse::<MaxSpeed>(), Err(ParseError::Value));
+        assert_eq!("-5".parse::<MaxSpeed>(), Err(ParseError::Value));
+        assert_eq!("NaN".parse::<MaxSpeed>(), Err(ParseError::Value));
+        assert_eq!("10 knots".parse::<MaxSpeed>(), Err(ParseError::Unit));
+        assert_eq!("30mph".parse::<MaxSpeed>(), Err(ParseError::Value));
+    }
+
+    #[test]
+    fn speed() {
+        assert_eq!(MaxSpeed::Kph(80.0 * km / h).speed(), Some(80.0 * km / h));
+        assert_eq!(
+            MaxSpeed::Mph(50.0 * mi / h).speed(),
+            Some(80.4672 * km / h)
+        );
+        assert_eq!(MaxSpeed::Unlimited.speed(), None);
+    }
+
+    #[test]
+    fn display() {
+        for tag in ["50", "30 mph", "7.5", "walk", "none", "signals"] {
+            assert_eq!(tag.parse::<MaxSpeed>().unwrap().to_string(), tag);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// molar.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of molar mass.
+//!
+//! Each unit is defined relative to grams per mole with a conversion factor.
+//! They can be used to conveniently create MolarMass quantities.
+//!
+//! ## Operations
+//!
+//! * [Mass] `/` Amount `=>` MolarMass (g/mol)
+//! * [Mass] `/` MolarMass `=>` Amount (mol)
+//! * MolarMass `*` Amount `=>` [Mass] (g)
+//! * Amount `*` MolarMass `=>` [Mass] (g)
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{amount::{mmol, mol}, mass::{g, mg}, molar::g_mol};
+//!
+//! let nacl = 58.44 * g_mol;
+//!
+//! assert_eq!((116.88 * g) / nacl, 2.0 * mol);
+//! assert_eq!((nacl * (10.0 * mmol)).to(), 584.4 * mg);
+//! assert_eq!((180.0 * g) / (2.0 * mol), 90.0 * g_mol);
+//! ```
+//!
+//! [Mass]: ../quan/struct.Mass.html
+use crate::amount::{kmol, mmol, mol, umol};
+use crate::declare_unit;
+use crate::mass::{cg, dg, g, kg, lb, mg, ng, sl, t, ug, Da};
+use crate::quan::{Amount, MolarMass, Quantity, Unit};
+use core::ops::{Div, Mul};
+
+declare_unit!(
+    /** Gram per mole */
+    g_mol,
+    "g/mol",
+    MolarMass,
+    1.0,
+    "gram per mole",
+    "grams per mole",
+);
+
+declare_unit!(
+    /** Kilogram per mole */
+    kg_mol,
+    "kg/mol",
+    MolarMass,
+    1_000.0,
+    "kilogram per mole",
+    "kilograms per mole",
+);
+
+// Mass / Amount => MolarMass
+// Mass / MolarMass => Amount
+//
+// Generic impls would overlap with Quantity / Quantity => Ratio, so these
+// are implemented for each mass unit.
+macro_rules! impl_mass_div {
+    ($($unit:ident),+) => {
+        $(
+            impl<N> Div<Quantity<N>> for Quantity<$unit>
+            where
+                N: Unit<Measure = Amount>,
+            {
+                type Output = Quantity<g_mol>;
+                fn div(self, amt: Quantity<N>) -> Self::Output {
+                    let mass = self.value() * $unit::FACTOR;
+                    Quantity::new(mass / (amt.value() * N::FACTOR))
+                }
+            }
+
+            impl Div<Quantity<g_mol>> for Quantity<$unit> {
+                type Output = Quantity<mol>;
+                fn div(self, mm: Quantity<g_mol>) -> Self::Output {
+                    Quantity::new(self.value() * $unit::FACTOR / mm.value())
+                }
+            }
+
+            impl Div<Quantity<kg_mol>> for Quantity<$unit> {
+                type Output = Quantity<mol>;
+                fn div(self, mm: Quantity<kg_mol>) -> Self::Output {
+                    self / mm.to::<g_mol>()
+                }
+            }
+        )+
+    };
+}
+
+impl_mass_div!(t, kg, g, dg, cg, mg, ug, ng, lb, sl, Da);
+
+// MolarMass * Amount => Mass
+impl<R, N> Mul<Quantity<N>> for Quantity<R>
+where
+    R: Unit<Measure = MolarMass>,
+    N: Unit<Measure = Amount>,
+{
+    type Output = Quantity<g>;
+    fn mul(self, amt: Quantity<N>) -> Self::Output {
+        let mm = self.value() * R::FACTOR;
+        Quantity::new(mm * amt.value() * N::FACTOR)
+    }
+}
+
+// Amount * MolarMass => Mass
+//
+// A generic impl would overlap with MolarMass * Amount, so this is
+// implemented for each amount unit.
+macro_rules! impl_amount_mul {
+    ($($unit:ident),+) => {
+        $(
+            impl<R> Mul<Quantity<R>> for Quantity<$unit>
+            where
+                R: Unit<Measure = MolarMass>,
+            {
+                type Output = Quantity<g>;
+                fn mul(self, mm: Quantity<R>) -> Self::Output {
+                    mm * self
+                }
+            }
+        )+
+    };
+}
+
+impl_amount_mul!(kmol, mol, mmol, umol);
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn molar_display() {
+        assert_eq!((18.015 * g_mol).to_string(), "18.015 g/mol");
+        assert_eq!((0.044 * kg_mol).to_string(), "0.044 kg/mol");
+        assert_eq!((1.0 * kg_mol).to(), 1_000.0 * g_mol);
+    }
+
+    #[test]
+    fn molar_ops() {
+        assert_eq!((88.0 * g) / (2.0 * mol), 44.0 * g_mol);
+        assert_eq!((1.0 * kg) / (25.0 * mmol), 40_000.0 * g_mol);
+        assert_eq!((88.0 * g) / (44.0 * g_mol), 2.0 * mol);
+        assert_eq!((2.0 * kg) / (0.5 * kg_mol), 4.0 * mol);
+        assert_eq!((50.0 * g_mol) * (3.0 * mol), 150.0 * g);
+        assert_eq!((2.0 * kmol) * (0.5 * kg_mol), 1_000_000.0 * g);
+    }
+}
@@ -0,0 +1,200 @@
+// motion.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Motion profiles with constant acceleration
+//!
+//! A [Profile] iterates over `(time, speed, distance)` samples, starting from
+//! an initial speed and changing at a constant rate.  When braking (negative
+//! acceleration), the profile ends with a final sample at the moment the
+//! speed reaches zero.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::m, motion::Profile, time::s};
+//!
+//! let braking = Profile::new(20.0 * m / s, -5.0 * m / s / s, 1.0 * s);
+//! let table: Vec<_> = braking.collect();
+//!
+//! assert_eq!(table.len(), 5);
+//! assert_eq!(table[1], (1.0 * s, 15.0 * m / s, 17.5 * m));
+//! assert_eq!(table[4], (4.0 * s, 0.0 * m / s, 40.0 * m));
+//! ```
+use crate::{length, time, Acceleration, Length, Period, Speed};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Motion profile iterator.
+///
+/// Yields `(time, speed, distance)` samples at each time step.  With zero or
+/// positive acceleration the iterator is infinite; use `take` or
+/// `take_while` to limit it.
+#[derive(Clone, Debug)]
+pub struct Profile<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Initial speed quantity
+    speed: f64,
+
+    /// Acceleration quantity
+    accel: f64,
+
+    /// Time step quantity
+    step: f64,
+
+    /// Index of next sample
+    index: u32,
+
+    /// Time when stopped (braking only)
+    stop: Option<f64>,
+
+    /// Flag indicating final sample was yielded
+    done: bool,
+
+    /// Length unit
+    length: PhantomData<L>,
+
+    /// Period unit
+    period: PhantomData<P>,
+}
+
+impl<L, P> Profile<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    /// Create a new motion profile
+    ///
+    /// * `speed` Initial speed
+    /// * `accel` Constant acceleration (negative for braking)
+    /// * `step` Time step between samples
+    pub fn new(
+        speed: Speed<L, P>,
+        accel: Acceleration<L, P>,
+        step: Period<P>,
+    ) -> Self {
+        let (speed, accel) = (speed.quantity(), accel.quantity());
+        let stop = if speed * accel < 0.0 {
+            Some(-speed / accel)
+        } else {
+            None
+        };
+        Profile {
+            speed,
+            accel,
+            step: step.quantity(),
+            index: 0,
+            stop,
+            done: false,
+            length: PhantomData,
+            period: PhantomData,
+        }
+    }
+
+    /// Get sample at a time
+    fn sample(&self, t: f64) -> (Period<P>, Speed<L, P>, Length<L>) {
+        let speed = self.speed + self.accel * t;
+        let dist = self.speed * t + self.accel * t * t / 2.0;
+        (Period::new(t), Speed::new(speed), Length::new(dist))
+    }
+}
+
+impl<L, P> Iterator for Profile<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Item = (Period<P>, Speed<L, P>, Length<L>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let t = self.step * f64::from(self.index);
+        match self.stop {
+            Some(stop) if t >= stop => {
+                // final sample at stopping time
+                self.done = true;
+                let (t, _, dist) = self.sample(stop);
+                Some((t, Speed::new(0.0), dist))
+            }
+            _ => {
+                self.index += 1;
+                Some(self.sample(t))
+            }
+        }
+    }
+}
+
+impl<L, P> FusedIterator for Profile<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+}
+
+/// Calculate the distance to stop from a speed with constant deceleration
+///
+/// The deceleration is a magnitude; its sign is ignored.
+///
+/// ```rust
+/// use mag::{length::m, motion::stopping_distance, time::s};
+///
+/// let d = stopping_distance(30.0 * m / s, 7.5 * m / s / s);
+/// assert_eq!(d, 60.0 * m);
+/// ```
+pub fn stopping_distance<L, P>(
+    speed: Speed<L, P>,
+    decel: Acceleration<L, P>,
+) -> Length<L>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    let v = speed.quantity();
+    let a = decel.quantity();
+    let a = if a < 0.0 { -a } else { a };
+    Length::new(v * v / (2.0 * a))
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::time::s;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn accelerating() {
+        let p = Profile::new(0.0 * m / s, 2.0 * m / s / s, 0.5 * s);
+        let table: Vec<_> = p.take(3).collect();
+        assert_eq!(table[0], (0.0 * s, 0.0 * m / s, 0.0 * m));
+        assert_eq!(table[1], (0.5 * s, 1.0 * m / s, 0.25 * m));
+        assert_eq!(table[2], (1.0 * s, 2.0 * m / s, 1.0 * m));
+    }
+
+    #[test]
+    fn braking() {
+        // stop falls between steps
+        let p = Profile::new(44.0 * ft / s, -16.0 * ft / s / s, 1.0 * s);
+        let table: Vec<_> = p.collect();
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[2], (2.0 * s, 12.0 * ft / s, 56.0 * ft));
+        assert_eq!(table[3], (2.75 * s, 0.0 * ft / s, 60.5 * ft));
+        assert_eq!(
+            stopping_distance(44.0 * ft / s, -16.0 * ft / s / s),
+            60.5 * ft
+        );
+    }
+
+    #[test]
+    fn constant() {
+        let mut p = Profile::new(10.0 * m / s, 0.0 * m / s / s, 2.0 * s);
+        assert_eq!(p.nth(5), Some((10.0 * s, 10.0 * m / s, 100.0 * m)));
+    }
+}
@@ -0,0 +1,172 @@
+// power.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of power.
+//!
+//! Each unit is defined relative to watts with a conversion factor.  They can
+//! be used to conveniently create Power quantities.
+//!
+//! ## Operations
+//!
+//! * Energy `/` [Period] `=>` Power (W)
+//! * Power `*` [Period] `=>` Energy (J)
+//! * [Period] `*` Power `=>` Energy (J)
+//! * Energy `/` Power `=>` [Period] (s)
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{energy::kWh, power::{kW, W}, time::{h, min}};
+//!
+//! let a = 1_500.0 * W;
+//! let b = 4.5 * kWh;
+//!
+//! assert_eq!(a.to_string(), "1500 W");
+//! assert_eq!((a * (2.0 * h)).to(), 3 * kWh);
+//! assert_eq!((b / (90.0 * min)).to(), 3 * kW);
+//! assert_eq!((b / a).to::<h>(), 3.0 * h);
+//! ```
+//!
+//! [Period]: ../struct.Period.html
+use crate::declare_unit;
+use crate::energy::J;
+use crate::quan::{Energy, Power, Quantity, Unit};
+use crate::{time, Period};
+use core::ops::{Div, Mul};
+
+declare_unit!(
+    /** Watt */
+    W,
+    "W",
+    Power,
+    1.0,
+    "watt",
+    "watts",
+);
+
+declare_unit!(
+    /** Kilowatt */
+    kW,
+    "kW",
+    Power,
+    1_000.0,
+    "kilowatt",
+    "kilowatts",
+);
+
+declare_unit!(
+    /** Megawatt */
+    MW,
+    "MW",
+    Power,
+    1_000_000.0,
+    "megawatt",
+    "megawatts",
+);
+
+declare_unit!(
+    /** Horsepower (mechanical) */
+    hp,
+    "hp",
+    Power,
+    745.699_871_582_270_2,
+    "horsepower",
+    "horsepower",
+);
+
+// Energy / Period => Power
+impl<E, T> Div<Period<T>> for Quantity<E>
+where
+    E: Unit<Measure = Energy>,
+    T: time::Unit,
+{
+    type Output = Quantity<W>;
+    fn div(self, per: Period<T>) -> Self::Output {
+        Quantity::new(self.value() * E::FACTOR / (per.quantity() * T::S_FACTOR))
+    }
+}
+
+// Power * Period => Energy
+impl<P, T> Mul<Period<T>> for Quantity<P>
+where
+    P: Unit<Measure = Power>,
+    T: time::Unit,
+{
+    type Output = Quantity<J>;
+    fn mul(self, per: Period<T>) -> Self::Output {
+        Quantity::new(self.value() * P::FACTOR * per.quantity() * T::S_FACTOR)
+    }
+}
+
+// Period * Power => Energy
+impl<P, T> Mul<Quantity<P>> for Period<T>
+where
+    P: Unit<Measure = Power>,
+    T: time::Unit,
+{
+    type Output = Quantity<J>;
+    fn mul(self, pow: Quantity<P>) -> Self::Output {
+        pow * self
+    }
+}
+
+// Energy / Power => Period
+//
+// A generic impl would overlap with Quantity / Quantity => Ratio, so this is
+// implemented for each power unit.
+macro_rules! impl_energy_div {
+    ($($unit:ident),+) => {
+        $(
+            impl<E> Div<Quantity<$unit>> for Quantity<E>
+            where
+                E: Unit<Measure = Energy>,
+            {
+                type Output = Period<time::s>;
+                fn div(self, pow: Quantity<$unit>) -> Self::Output {
+                    let energy = self.value() * E::FACTOR;
+                    Period::new(energy / (pow.value() * $unit::FACTOR))
+                }
+            }
+        )+
+    };
+}
+
+impl_energy_div!(W, kW, MW, hp);
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::energy::{kJ, kWh, BTU};
+    use crate::time::{h, ms, s};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn power_display() {
+        assert_eq!((60.0 * W).to_string(), "60 W");
+        assert_eq!((2 * kW).to_string(), "2 kW");
+        assert_eq!((1.5 * MW).to_string(), "1.5 MW");
+        assert_eq!((300 * hp).to_string(), "300 hp");
+    }
+
+    #[test]
+    fn power_to() {
+        assert_eq!((1.0 * MW).to(), 1_000.0 * kW);
+        assert_eq!(format!("{:.2}", (1.0 * hp).to::<W>()), "745.70 W");
+        assert_eq!(format!("{:.4}", (1.0 * kW).to::<hp>()), "1.3410 hp");
+    }
+
+    #[test]
+    fn power_ops() {
+        assert_eq!(5.0 * kJ / (2.0 * s), 2_500.0 * W);
+        assert_eq!(10.0 * J / (5.0 * ms), 2_000.0 * W);
+        assert_eq!(2.0 * kW * (3.0 * s), 6_000.0 * J);
+        assert_eq!((30.0 * s) * (100.0 * W), 3_000.0 * J);
+        assert_eq!((1.0 * kWh) / (500.0 * W), 7_200.0 * s);
+        // 12,000 BTU/h is one ton of refrigeration
+        let ton = (12_000.0 * BTU / (1.0 * h)).to::<kW>();
+        assert_eq!(format!("{:.3}", ton), "3.517 kW");
+    }
+}
@@ -0,0 +1,477 @@
+// precise.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! High-precision conversions with double-double arithmetic.
+//!
+//! This module requires the `double-double` feature.  A [DoubleDouble]
+//! represents a value as the unevaluated sum of two `f64`s, giving about 106
+//! bits of precision.  [Precise] quantities use the same unit definitions as
+//! the rest of the crate, but combine conversion factors without rounding
+//! error at each step.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{ft, m}, precise::Precise};
+//!
+//! // f64 conversion accumulates rounding error
+//! assert_eq!((1.0 * m * m).to::<ft>(), 10.763910416709724 * ft * ft);
+//!
+//! let a = Precise::new(1.0 * m * m).to::<mag::Area<ft>>();
+//! assert_eq!(a.quantity(), 10.763910416709722 * ft * ft);
+//! ```
+use crate::quan::{self, Magnitude, Quantity};
+use crate::{length, time};
+use crate::{Area, Frequency, Length, Period, Speed, Volume};
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Floating point value with about 106 bits of precision.
+///
+/// The value is `hi + lo`, where `lo` is less than half an ulp of `hi`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DoubleDouble {
+    /// High-order part
+    hi: f64,
+
+    /// Low-order part
+    lo: f64,
+}
+
+/// Sum of two values, with exact error term
+fn two_sum(a: f64, b: f64) -> DoubleDouble {
+    let s = a + b;
+    let bb = s - a;
+    let err = (a - (s - bb)) + (b - bb);
+    DoubleDouble { hi: s, lo: err }
+}
+
+/// Sum of two values where `|a| >= |b|`, with exact error term
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let s = a + b;
+    DoubleDouble {
+        hi: s,
+        lo: b - (s - a),
+    }
+}
+
+/// Split a value into two 26-bit halves (Dekker)
+fn split(a: f64) -> (f64, f64) {
+    const SPLITTER: f64 = 134_217_729.0; // 2^27 + 1
+    let t = SPLITTER * a;
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+/// Product of two values, with exact error term
+fn two_prod(a: f64, b: f64) -> DoubleDouble {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let err = ((ah * bh - p) + ah * bl + al * bh) + al * bl;
+    DoubleDouble { hi: p, lo: err }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(hi: f64) -> Self {
+        DoubleDouble { hi, lo: 0.0 }
+    }
+}
+
+impl From<DoubleDouble> for f64 {
+    fn from(dd: DoubleDouble) -> Self {
+        dd.hi + dd.lo
+    }
+}
+
+impl DoubleDouble {
+    /// Create a new value from high and low parts
+    pub fn new(hi: f64, lo: f64) -> Self {
+        two_sum(hi, lo)
+    }
+
+    /// Get the high-order part
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Get the low-order part
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Round to nearest `f64`
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_f64().fmt(f)
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi) {
+            Some(Ordering::Equal) => self.lo.partial_cmp(&other.lo),
+            ord => ord,
+        }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        let s = two_sum(self.hi, other.hi);
+        let t = two_sum(self.lo, other.lo);
+        let s = quick_two_sum(s.hi, s.lo + t.hi);
+        quick_two_sum(s.hi, s.lo + t.lo)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        let p = two_prod(self.hi, other.hi);
+        let lo = p.lo + (self.hi * other.lo + self.lo * other.hi);
+        quick_two_sum(p.hi, lo)
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        let q1 = self.hi / other.hi;
+        let r = self - other * DoubleDouble::from(q1);
+        let q2 = r.hi / other.hi;
+        let r = r - other * DoubleDouble::from(q2);
+        let q3 = r.hi / other.hi;
+        quick_two_sum(q1, q2) + DoubleDouble::from(q3)
+    }
+}
+
+/// Recover the intended value of a unit factor.
+///
+/// Factors are usually simple fractions, such as 381/1250 (0.3048) or 5/9,
+/// which cannot be represented exactly as `f64`.  If a fraction with a small
+/// denominator rounds to the factor, it is used instead.
+fn factor(x: f64) -> DoubleDouble {
+    const LIMIT: u128 = 1 << 53;
+    if x == 0.0 || !x.is_finite() {
+        return x.into();
+    }
+    let bits = x.abs().to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    if exp == 0 {
+        return x.into();
+    }
+    let shift = 1075 - exp;
+    if shift <= 0 || shift > 120 {
+        return x.into();
+    }
+    // x = num / den exactly
+    let (mut num, mut den) =
+        (u128::from(bits & ((1 << 52) - 1) | 1 << 52), 1u128 << shift);
+    // continued fraction convergents h / k
+    let (mut h0, mut h1) = (0u128, 1u128);
+    let (mut k0, mut k1) = (1u128, 0u128);
+    while den != 0 {
+        let a = num / den;
+        (num, den) = (den, num - a * den);
+        let h = a * h1 + h0;
+        let k = a * k1 + k0;
+        if h >= LIMIT || k >= LIMIT {
+            break;
+        }
+        if (h as f64) / (k as f64) == x.abs() {
+            let v = DoubleDouble::from(h as f64) / DoubleDouble::from(k as f64);
+            return if x < 0.0 { -v } else { v };
+        }
+        (h0, h1) = (h1, h);
+        (k0, k1) = (k1, k);
+    }
+    x.into()
+}
+
+/// Quantity which can be converted with double-double precision.
+///
+/// This is implemented for all quantity types.  Unit factors are combined as
+/// the simplest fraction which rounds to each `f64` factor, when one exists
+/// with numerator and denominator below 2⁵³.
+pub trait Convertible: Magnitude {
+    /// Quantity in base units, which all convertible units share
+    type Base;
+
+    /// Factor to convert to base units
+    fn base_factor() -> DoubleDouble;
+
+    /// Value of (absolute) zero
+    fn zero() -> DoubleDouble {
+        DoubleDouble::default()
+    }
+}
+
+/// Get length factor to meters
+fn m_factor<U: length::Unit>() -> DoubleDouble {
+    factor(U::M_FACTOR)
+}
+
+/// Get time factor to seconds
+fn s_factor<U: time::Unit>() -> DoubleDouble {
+    factor(U::S_FACTOR)
+}
+
+impl<U> Convertible for Length<U>
+where
+    U: length::Unit,
+{
+    type Base = Length<length::m>;
+    fn base_factor() -> DoubleDouble {
+        m_factor::<U>()
+    }
+}
+
+impl<U> Convertible for Area<U>
+where
+    U: length::Unit,
+{
+    type Base = Area<length::m>;
+    fn base_factor() -> DoubleDouble {
+        m_factor::<U>() * m_factor::<U>()
+    }
+}
+
+impl<U> Convertible for Volume<U>
+where
+    U: length::Unit,
+{
+    type Base = Volume<length::m>;
+    fn base_factor() -> DoubleDouble {
+        m_factor::<U>() * m_factor::<U>() * m_factor::<U>()
+    }
+}
+
+impl<U> Convertible for Period<U>
+where
+    U: time::Unit,
+{
+    type Base = Period<time::s>;
+    fn base_factor() -> DoubleDouble {
+        s_factor::<U>()
+    }
+}
+
+impl<U> Convertible for Frequency<U>
+where
+    U: time::Unit,
+{
+    type Base = Frequency<time::s>;
+    fn base_factor() -> DoubleDouble {
+        DoubleDouble::from(1.0) / s_factor::<U>()
+    }
+}
+
+impl<L, P> Convertible for Speed<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    type Base = Speed<length::m, time::s>;
+    fn base_factor() -> DoubleDouble {
+        m_factor::<L>() / s_factor::<P>()
+    }
+}
+
+impl<U> Convertible for Quantity<U>
+where
+    U: quan::Unit,
+{
+    type Base = U::Measure;
+    fn base_factor() -> DoubleDouble {
+        factor(U::FACTOR)
+    }
+    fn zero() -> DoubleDouble {
+        factor(U::ZERO)
+    }
+}
+
+/// Quantity with a double-double value.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Precise<Q>
+where
+    Q: Convertible,
+{
+    /// Value in units of the quantity
+    value: DoubleDouble,
+
+    /// Quantity type
+    quan: PhantomData<Q>,
+}
+
+impl<Q> Precise<Q>
+where
+    Q: Convertible,
+{
+    /// Create a precise quantity
+    pub fn new(quan: Q) -> Self {
+        Self::from_value(quan.magnitude().into())
+    }
+
+    /// Create a precise quantity from a double-double value
+    pub fn from_value(value: DoubleDouble) -> Self {
+        Precise {
+            value,
+            quan: PhantomData,
+        }
+    }
+
+    /// Get the double-double value
+    pub fn value(self) -> DoubleDouble {
+        self.value
+    }
+
+    /// Round to an `f64` quantity
+    pub fn quantity(self) -> Q {
+        Q::from_magnitude(self.value.to_f64())
+    }
+
+    /// Convert to another unit of the same quantity
+    pub fn to<R>(self) -> Precise<R>
+    where
+        R: Convertible<Base = Q::Base>,
+    {
+        let base = (self.value - Q::zero()) * Q::base_factor();
+        Precise::from_value(base / R::base_factor() + R::zero())
+    }
+}
+
+impl<Q> Add for Precise<Q>
+where
+    Q: Convertible,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::from_value(self.value + other.value)
+    }
+}
+
+impl<Q> Sub for Precise<Q>
+where
+    Q: Convertible,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::from_value(self.value - other.value)
+    }
+}
+
+impl<Q> Mul<f64> for Precise<Q>
+where
+    Q: Convertible,
+{
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self::Output {
+        Self::from_value(self.value * scalar.into())
+    }
+}
+
+impl<Q> Div<f64> for Precise<Q>
+where
+    Q: Convertible,
+{
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self::Output {
+        Self::from_value(self.value / scalar.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{ft, km, mi, yd, In};
+    use crate::mass::{g, lb};
+    use crate::temp::{DegC, DegF};
+    use crate::time::{h, min, s};
+
+    #[test]
+    fn dd_ops() {
+        let a = DoubleDouble::from(0.1) + DoubleDouble::from(0.2);
+        assert_eq!(a.hi(), 0.30000000000000004);
+        assert!(a.lo() != 0.0);
+        let third = DoubleDouble::from(1.0) / DoubleDouble::from(3.0);
+        let one = third * DoubleDouble::from(3.0);
+        assert_eq!(one.to_f64(), 1.0);
+        assert!((one - DoubleDouble::from(1.0)).to_f64().abs() < 1e-30);
+        assert!(third < DoubleDouble::new(0.5, 0.0));
+    }
+
+    #[test]
+    fn factors() {
+        assert_eq!(factor(0.3048), DoubleDouble::from(381.0) / 1250.0.into());
+        assert_eq!(factor(5.0 / 9.0), DoubleDouble::from(5.0) / 9.0.into());
+        assert_eq!(
+            factor(-459.67),
+            DoubleDouble::from(-45967.0) / 100.0.into()
+        );
+        assert_eq!(factor(1_000.0), DoubleDouble::from(1_000.0));
+        assert_eq!(factor(0.001).to_f64(), 0.001);
+        assert_eq!(factor(1.660_539_066_60e-24).lo(), 0.0);
+    }
+
+    #[test]
+    fn precise_to() {
+        let a = Precise::new(1.0 * ft).to::<Length<In>>();
+        assert_eq!(a.quantity(), 12.0 * In);
+        let b = Precise::new(1.0 * ft * ft).to::<Area<In>>();
+        assert_eq!(b.quantity(), 144.0 * In * In);
+        let c = Precise::new(88.0 * ft / s).to::<Speed<mi, h>>();
+        assert_eq!(c.quantity(), 60.0 * mi / h);
+        let d = Precise::new(1.0 * mi).to::<Length<km>>();
+        assert_eq!(d.quantity(), 1.609344 * km);
+        let e = Precise::new(2.0 * yd * yd * yd).to::<Volume<ft>>();
+        assert_eq!(e.quantity(), 54.0 * ft * ft * ft);
+        let f = Precise::new(4.75 * h).to::<Period<min>>();
+        assert_eq!(f.quantity(), 285.0 * min);
+        let freq = Precise::new(60.0 / min).to::<Frequency<s>>();
+        assert_eq!(freq.quantity(), 1.0 / s);
+    }
+
+    #[test]
+    fn precise_quan() {
+        let a = Precise::new(1.0 * lb).to::<Quantity<g>>();
+        assert_eq!(a.quantity(), 453.59237 * g);
+        let b = Precise::new(212.0 * DegF).to::<Quantity<DegC>>();
+        assert_eq!(b.quantity(), 100.0 * DegC);
+        let c = Precise::new(100.0 * DegC).to::<Quantity<DegF>>();
+        assert_eq!(c.quantity(), 212.0 * DegF);
+    }
+
+    #[test]
+    fn precise_ops() {
+        let a = Precise::new(0.1 * km) + Precise::new(0.2 * km);
+        assert_eq!((a - Precise::new(0.2 * km)).quantity(), 0.1 * km);
+        assert_eq!((a * 10.0 / 3.0).quantity(), 1.0 * km);
+    }
+}
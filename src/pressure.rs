@@ -0,0 +1,190 @@
+// pressure.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of pressure.
+//!
+//! Each unit is defined relative to pascals with a conversion factor.  They
+//! can be used to conveniently create Pressure quantities.
+//!
+//! ## Operations
+//!
+//! * [Force] `/` [Area] `=>` Pressure (Pa)
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{force::N, length::cm, pressure::{bar, kPa, Pa}};
+//!
+//! let a = 2.5 * bar;
+//! let b = (50.0 * N) / (10.0 * cm * cm);
+//!
+//! assert_eq!(a.to_string(), "2.5 bar");
+//! assert_eq!(a.to(), 250 * kPa);
+//! assert_eq!(b, 50_000.0 * Pa);
+//! ```
+//!
+//! [Area]: ../struct.Area.html
+//! [Force]: ../struct.Force.html
+use crate::declare_unit;
+use crate::mass::kg;
+use crate::quan::{Mass, Pressure, Quantity, Unit};
+use crate::{length, time, Area, Force};
+use core::ops::Div;
+
+declare_unit!(
+    /** Pascal */
+    Pa,
+    "Pa",
+    Pressure,
+    1.0,
+    "pascal",
+    "pascals",
+);
+
+declare_unit!(
+    /** Hectopascal */
+    hPa,
+    "hPa",
+    Pressure,
+    100.0,
+    "hectopascal",
+    "hectopascals",
+);
+
+declare_unit!(
+    /** Kilopascal */
+    kPa,
+    "kPa",
+    Pressure,
+    1_000.0,
+    "kilopascal",
+    "kilopascals",
+);
+
+declare_unit!(
+    /** Megapascal */
+    MPa,
+    "MPa",
+    Pressure,
+    1_000_000.0,
+    "megapascal",
+    "megapascals",
+);
+
+declare_unit!(
+    /** Bar */
+    bar,
+    "bar",
+    Pressure,
+    100_000.0,
+    "bar",
+    "bars",
+);
+
+declare_unit!(
+    /** Millibar */
+    mbar,
+    "mbar",
+    Pressure,
+    100.0,
+    "millibar",
+    "millibars",
+);
+
+declare_unit!(
+    /** Standard atmosphere */
+    atm,
+    "atm",
+    Pressure,
+    101_325.0,
+    "atmosphere",
+    "atmospheres",
+);
+
+declare_unit!(
+    /** Pound-force per square inch */
+    psi,
+    "psi",
+    Pressure,
+    6_894.757_293_168_361,
+    "pound per square inch",
+    "pounds per square inch",
+);
+
+declare_unit!(
+    /** Millimeter of mercury */
+    mmHg,
+    "mmHg",
+    Pressure,
+    133.322_387_415,
+    "millimeter of mercury",
+    "millimeters of mercury",
+);
+
+declare_unit!(
+    /** Inch of mercury */
+    inHg,
+    "inHg",
+    Pressure,
+    3_386.388_640_341,
+    "inch of mercury",
+    "inches of mercury",
+);
+
+// Force / Area => Pressure
+impl<M, L, T, R> Div<Area<R>> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+    R: length::Unit,
+{
+    type Output = Quantity<Pa>;
+    fn div(self, area: Area<R>) -> Self::Output {
+        let force = self.to::<kg, length::m, time::s>();
+        let area = area.to::<length::m>();
+        Quantity::new(force.quantity() / area.quantity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::force::N;
+    use crate::length::{ft, m, In};
+    use crate::mass::lb;
+    use crate::time::s;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn pressure_display() {
+        assert_eq!((101.3 * kPa).to_string(), "101.3 kPa");
+        assert_eq!((1013 * mbar).to_string(), "1013 mbar");
+        assert_eq!((32 * psi).to_string(), "32 psi");
+        assert_eq!((29.92 * inHg).to_string(), "29.92 inHg");
+    }
+
+    #[test]
+    fn pressure_to() {
+        assert_eq!((1.0 * bar).to(), 1_000.0 * mbar);
+        assert_eq!((1.0 * mbar).to(), 1.0 * hPa);
+        assert_eq!((1.0 * atm).to(), 101.325 * kPa);
+        assert_eq!(format!("{:.3}", (1.0 * atm).to::<mmHg>()), "760.000 mmHg");
+        assert_eq!(format!("{:.3}", (1.0 * atm).to::<inHg>()), "29.921 inHg");
+        assert_eq!(format!("{:.4}", (1.0 * MPa).to::<psi>()), "145.0377 psi");
+    }
+
+    #[test]
+    fn pressure_ops() {
+        assert_eq!((6.0 * N) / (2.0 * m * m), 3.0 * Pa);
+        assert_eq!(2.0 * kPa + 0.5 * kPa, 2.5 * kPa);
+        assert_eq!((4.0 * bar) / 2.0, 2.0 * bar);
+        // 1 lbf = 1 lb · 32.174049 ft/s²
+        let lbf = Force::<lb, ft, s>::new(32.174_048_556_430_45);
+        let p = (lbf / (1.0 * In * In)).to::<psi>();
+        assert_eq!(format!("{:.6}", p), "1.000000 psi");
+    }
+}
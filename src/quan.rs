@@ -2,9 +2,14 @@
 //
 // Copyright (C) 2021-2022  Douglas P Lau
 //
+use core::any::TypeId;
 use core::fmt;
+use core::iter::Sum;
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Sub};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
 
 /// Measure of mass.
 ///
@@ -23,22 +28,114 @@ use core::ops::{Add, Div, Mul, Sub};
 ///
 /// # Example: Solar Mass Units
 /// ```rust
-/// use mag::{declare_unit, mass::kg, quan::Mass};
+/// use mag::{declare_unit, mass::kg, quan::Mass, ratio::Ratio};
 ///
 /// declare_unit!(M, "M☉", Mass, 1.988_47e33,);
 ///
 /// let sun = 1 * M;
 /// assert_eq!(sun.to_string(), "1 M☉");
 /// assert_eq!(sun.to(), 1.988_47e30 * kg);
+/// assert_eq!(sun / (4 * M), Ratio::new(0.25));
 /// ```
 ///
 /// [mass]: struct.Mass.html
 /// [unit]: ../mass/index.html
 /// [to]: struct.Quantity.html#method.to
 ///
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Mass;
 
+/// Measure of _distance_ (length in one dimension).
+///
+/// All [length unit]s are also units of this measure, so they can be used
+/// with [Quantity] as well as the [Length] struct.  Conversions between the
+/// two are provided with `From`.  Areas and volumes are still only available
+/// as the [Area] and [Volume] structs.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::{ft, m}, quan::Quantity, Length};
+///
+/// let a = Quantity::<ft>::new(3.0);
+/// assert_eq!(a.to_string(), "3 ft");
+/// assert_eq!(a.to::<m>(), Quantity::new(0.9144000000000001));
+/// assert_eq!(Length::from(a), 3.0 * ft);
+/// assert_eq!(Quantity::from(2.5 * m), Quantity::<m>::new(2.5));
+/// ```
+///
+/// [Area]: ../struct.Area.html
+/// [Length]: ../struct.Length.html
+/// [Volume]: ../struct.Volume.html
+/// [length unit]: ../length/index.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Distance;
+
+/// Measure of _time_.
+///
+/// All [time unit]s are also units of this measure, so they can be used with
+/// [Quantity] as well as the [Period] struct.  Conversions between the two
+/// are provided with `From`.  Frequencies and speeds are still only available
+/// as the [Frequency] and [Speed] structs.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{quan::Quantity, time::{min, s}, Period};
+///
+/// let a = Quantity::<min>::new(1.5);
+/// assert_eq!(a.to_string(), "1.5 min");
+/// assert_eq!(a.to::<s>(), Quantity::new(90.0));
+/// assert_eq!(Period::from(a), 1.5 * min);
+/// ```
+///
+/// [Frequency]: ../struct.Frequency.html
+/// [Period]: ../struct.Period.html
+/// [Speed]: ../struct.Speed.html
+/// [time unit]: ../time/index.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Time;
+
+/// Volume of a _liquid_ or other substance.
+///
+/// LiquidVolume is a measure with units such as `L` and `cup_us`, which are
+/// not the cube of any length unit.  Quantities can be converted to a
+/// [Volume] of cubed length units with `to_volume`.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::liquid::{cup_us, mL};
+///
+/// let a = 2 * cup_us;
+/// assert_eq!(a.to_string(), "2 US cup");
+/// assert_eq!(format!("{:.1}", a.to::<mL>()), "473.2 mL");
+/// ```
+///
+/// [Volume]: ../struct.Volume.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LiquidVolume;
+
+/// Area of _land_ or other surface.
+///
+/// LandArea is a measure with units such as `ha` and `ac`, which are not the
+/// square of any length unit.  Quantities can be converted to an [Area] of
+/// squared length units with `to_area`.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::land::{ac, ha};
+///
+/// let a = 40 * ac;
+/// assert_eq!(a.to_string(), "40 ac");
+/// assert_eq!(format!("{:.2}", a.to::<ha>()), "16.19 ha");
+/// ```
+///
+/// [Area]: ../struct.Area.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LandArea;
+
 /// Thermodynamic _temperature_.
 ///
 /// Temperature is a "base quantity" with units such as DegC and DegF.
@@ -53,14 +150,200 @@ pub struct Mass;
 /// assert_eq!(a.to(), 37 * DegC);
 /// assert_eq!((22.8 * DegC).to_string(), "22.8 °C");
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Temperature;
 
+/// Measure of _energy_ (or work, or heat).
+///
+/// Energy is a measure with units such as `J` and `kWh`.  Dividing by a
+/// [Period] gives [Power].
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{energy::{kJ, kWh}, power::kW, time::h};
+///
+/// let a = 1.5 * kWh;
+/// assert_eq!(a.to_string(), "1.5 kWh");
+/// assert_eq!(a.to(), 5_400 * kJ);
+/// assert_eq!((a / (3.0 * h)).to(), 0.5 * kW);
+/// ```
+///
+/// [Period]: ../struct.Period.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Energy;
+
+/// Measure of _power_ (energy per time).
+///
+/// Power is a measure with units such as `W` and `hp`.  Multiplying by a
+/// [Period] gives [Energy].
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{energy::kWh, power::{hp, kW}, time::h};
+///
+/// let a = 2.0 * kW;
+/// assert_eq!(a.to_string(), "2 kW");
+/// assert_eq!(format!("{:.3}", a.to::<hp>()), "2.682 hp");
+/// assert_eq!(format!("{:.1}", (a * (8.0 * h)).to::<kWh>()), "16.0 kWh");
+/// ```
+///
+/// [Period]: ../struct.Period.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Power;
+
+/// Measure of _pressure_ (force per area).
+///
+/// Pressure is a measure with units such as `Pa`, `bar` and `psi`.  Dividing
+/// a [Force] by an [Area] gives a pressure.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::pressure::{atm, hPa, psi};
+///
+/// let a = 1.0 * atm;
+/// assert_eq!(a.to_string(), "1 atm");
+/// assert_eq!(a.to(), 1_013.25 * hPa);
+/// assert_eq!(format!("{:.2}", a.to::<psi>()), "14.70 psi");
+/// ```
+///
+/// [Area]: ../struct.Area.html
+/// [Force]: ../struct.Force.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pressure;
+
+/// Measure of _torque_ (or moment of force).
+///
+/// Torque is a measure with units such as `N·m` and `lbf·ft`.  Multiplying a
+/// [Force] by a lever arm [Length] gives a torque.  It has the same dimension
+/// as [Energy], but is a distinct measure.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::torque::{lbf_ft, N_m};
+///
+/// let a = 100.0 * lbf_ft;
+/// assert_eq!(a.to_string(), "100 lbf·ft");
+/// assert_eq!(format!("{:.1}", a.to::<N_m>()), "135.6 N·m");
+/// ```
+///
+/// [Force]: ../struct.Force.html
+/// [Length]: ../struct.Length.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Torque;
+
+/// Amount of digital _information_ (data size).
+///
+/// Information is a measure with units such as `B` and `MiB`.  Decimal (SI)
+/// prefixes are powers of 1000, while binary prefixes (`KiB`, `MiB`) are
+/// powers of 1024.  Dividing by a [Period] gives a [Bandwidth].
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::information::{kB, KiB, MB};
+///
+/// let a = 4 * KiB;
+/// assert_eq!(a.to_string(), "4 KiB");
+/// assert_eq!(a.to(), 4.096 * kB);
+/// assert_eq!(format!("{:.3}", a.to::<MB>()), "0.004 MB");
+/// ```
+///
+/// [Bandwidth]: ../information/struct.Bandwidth.html
+/// [Period]: ../struct.Period.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Information;
+
+/// Measure of _amount of substance_.
+///
+/// Amount is a "base quantity", with units such as `mol` and `mmol`.
+/// Dividing a [Mass] by a [MolarMass] gives an amount.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::amount::{mmol, mol};
+///
+/// let a = 250.0 * mmol;
+/// assert_eq!(a.to_string(), "250 mmol");
+/// assert_eq!(a.to(), 0.25 * mol);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Amount;
+
+/// Measure of _molar mass_ (mass per amount of substance).
+///
+/// Molar mass is a measure with units such as `g/mol` and `kg/mol`.
+/// Dividing a [Mass] by an [Amount] gives a molar mass.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{amount::mol, mass::g, molar::g_mol};
+///
+/// let water = 18.015 * g_mol;
+/// assert_eq!(water.to_string(), "18.015 g/mol");
+/// assert_eq!((36.03 * g) / water, 2.0 * mol);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MolarMass;
+
+/// Runtime identifier of a unit.
+///
+/// Every unit struct has a distinct id, which can be used as a key in maps or
+/// registries.  Ids are only meaningful within one build of a program.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{length::{ft, m}, mass::kg, quan::Unit};
+///
+/// assert_eq!(m::id(), m::id());
+/// assert_ne!(m::id(), ft::id());
+/// assert_ne!(m::id(), kg::id());
+/// assert_eq!(kg::id().label(), "kg");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnitId {
+    /// Type of unit struct
+    type_id: TypeId,
+
+    /// Unit label
+    label: &'static str,
+}
+
+impl UnitId {
+    /// Create a unit id for a unit struct
+    pub fn of<T>(label: &'static str) -> Self
+    where
+        T: ?Sized + 'static,
+    {
+        UnitId {
+            type_id: TypeId::of::<T>(),
+            label,
+        }
+    }
+
+    /// Get the unit label
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+}
+
 /// Unit of measure
 pub trait Unit {
     /// Unit label
     const LABEL: &'static str;
 
+    /// Unit name (singular)
+    const NAME: &'static str = Self::LABEL;
+
+    /// Unit name (plural)
+    const NAME_PLURAL: &'static str = Self::NAME;
+
     /// Factor to convert to base unit
     const FACTOR: f64;
 
@@ -70,6 +353,14 @@ pub trait Unit {
     /// Measure (length, mass, etc.)
     type Measure;
 
+    /// Get runtime identifier
+    fn id() -> UnitId
+    where
+        Self: 'static,
+    {
+        UnitId::of::<Self>(Self::LABEL)
+    }
+
     /// Convert a value to another unit of the same measure
     fn convert<T>(val: f64) -> f64
     where
@@ -79,15 +370,244 @@ pub trait Unit {
     }
 }
 
+/// Quantity with an `f64` magnitude in a specific unit
+///
+/// This is implemented for all quantity types, allowing generic code to
+/// operate on magnitudes without discarding units.
+pub trait Magnitude {
+    /// Get the magnitude, in the quantity's unit
+    fn magnitude(&self) -> f64;
+
+    /// Create a quantity from a magnitude
+    fn from_magnitude(magnitude: f64) -> Self;
+}
+
+/// Quantity with a unit label
+///
+/// This is implemented for all quantity types.  The label is the unit part of
+/// `Display` output, such as `m²` or `km/h`.
+pub trait Label: Magnitude {
+    /// Write the unit label
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result;
+
+    /// Write the unit label, with ASCII replacements for other characters
+    ///
+    /// For example, `μm²` is written as `um^2` and `㎑` as `kHz`.
+    fn write_ascii_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        Self::write_label(&mut AsciiLabel(w))
+    }
+
+    /// Check whether a string matches the unit label (or its ASCII form)
+    fn is_label(label: &str) -> bool {
+        let mut rest = LabelMatch(label);
+        if Self::write_label(&mut rest).is_ok() && rest.0.is_empty() {
+            return true;
+        }
+        let mut rest = LabelMatch(label);
+        Self::write_ascii_label(&mut rest).is_ok() && rest.0.is_empty()
+    }
+}
+
+/// Format a quantity as its magnitude followed by the unit label
+///
+/// Formatter flags (precision, width, sign) apply to the magnitude.  Without
+/// a precision, magnitudes use core's shortest round-trip float formatting;
+/// see [display](../display/index.html) for hiding conversion artifacts.
+/// The alternate flag (`{:#}`) writes an ASCII label.  Labels are written
+/// piece by piece, so no intermediate strings are allocated.
+pub(crate) fn fmt_quantity<Q: Label>(
+    q: &Q,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    fmt::Display::fmt(&q.magnitude(), f)?;
+    f.write_str(" ")?;
+    write_label::<Q>(f)
+}
+
+/// Write a unit label, in ASCII form with the alternate flag (`{:#}`)
+pub(crate) fn write_label<Q: Label>(f: &mut fmt::Formatter) -> fmt::Result {
+    if f.alternate() {
+        Q::write_ascii_label(f)
+    } else {
+        Q::write_label(f)
+    }
+}
+
+/// Error parsing a quantity from a string
+///
+/// All quantity types implement `FromStr`, accepting the same format as
+/// `Display` output.
+///
+/// ```rust
+/// use mag::{length::cm, quan::ParseError, Length};
+///
+/// assert_eq!("25.5 cm".parse::<Length<cm>>(), Ok(25.5 * cm));
+/// assert_eq!("25.5 in".parse::<Length<cm>>(), Err(ParseError::Unit));
+/// assert_eq!("25,5 cm".parse::<Length<cm>>(), Err(ParseError::Value));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// Malformed numeric value
+    Value,
+    /// Wrong or missing unit label
+    Unit,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Value => write!(f, "invalid quantity value"),
+            ParseError::Unit => write!(f, "invalid quantity unit"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Parse a quantity from a value and unit label, such as `25.5 cm`
+///
+/// Whitespace between the value and label is optional.
+pub(crate) fn parse_quantity<Q: Label>(s: &str) -> Result<Q, ParseError> {
+    let s = s.trim();
+    let (value, label) = match s.split_once(char::is_whitespace) {
+        Some((value, label)) => (value, label.trim_start()),
+        None => s
+            .char_indices()
+            .map(|(i, _)| s.split_at(i))
+            .find(|(_, label)| Q::is_label(label))
+            .ok_or(ParseError::Unit)?,
+    };
+    if !Q::is_label(label) {
+        return Err(ParseError::Unit);
+    }
+    let value: f64 = value.parse().map_err(|_| ParseError::Value)?;
+    if cfg!(feature = "assert-finite") && !value.is_finite() {
+        return Err(ParseError::Value);
+    }
+    Ok(Q::from_magnitude(value))
+}
+
+/// Writer which matches a label against a string
+struct LabelMatch<'a>(&'a str);
+
+impl fmt::Write for LabelMatch<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.0.strip_prefix(s) {
+            Some(rest) => {
+                self.0 = rest;
+                Ok(())
+            }
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+/// Writer which replaces non-ASCII label characters
+pub(crate) struct AsciiLabel<'a, W: fmt::Write>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write> fmt::Write for AsciiLabel<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            'μ' | 'µ' => self.0.write_char('u'),
+            '°' => self.0.write_str("deg"),
+            '²' => self.0.write_str("^2"),
+            '³' => self.0.write_str("^3"),
+            '·' => self.0.write_char('*'),
+            '☉' => self.0.write_str("sun"),
+            '㎐' => self.0.write_str("Hz"),
+            '㎑' => self.0.write_str("kHz"),
+            '㎒' => self.0.write_str("MHz"),
+            '㎓' => self.0.write_str("GHz"),
+            _ => self.0.write_char(c),
+        }
+    }
+}
+
+/// Implement scalar multiplication with a unit
+///
+/// Both `f64` and `i32` can be multiplied by a unit, as `2.5 * km`.  Other
+/// left-hand scalar types are not implemented, since each one breaks
+/// inference for literals:
+///
+/// * With `u32`, `i64` or `u64`, an integer literal no longer defaults to
+///   `i32` before a method call, so `(3 * kmol).to_string()` fails with
+///   E0282 (type annotations needed).
+/// * With `f32`, a float literal is ambiguous too, so `let d = 42.195 * km;`
+///   followed by `d.to()` fails with E0282.
+///
+/// Instead, a unit can be multiplied by any scalar on the right, as
+/// `km * reading`.  Quantities can be multiplied by `f64`, `i32` or `u32` on
+/// either side, as `count * (1.0 * km)`.
+/// `f32`, `i32` and `u32` convert to `f64` exactly, but `i64` and `u64` values
+/// with a magnitude above 2⁵³ are rounded to the nearest `f64`.
+///
+/// ```compile_fail
+/// use mag::length::km;
+///
+/// let reading: u32 = 5;
+/// let _ = reading * km; // use `km * reading`
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_scalar_mul {
+    ($unit:ty => $out:ty) => {
+        impl core::ops::Mul<$unit> for f64 {
+            type Output = $out;
+            fn mul(self, _unit: $unit) -> Self::Output {
+                <$out>::new(self)
+            }
+        }
+
+        impl core::ops::Mul<$unit> for i32 {
+            type Output = $out;
+            fn mul(self, _unit: $unit) -> Self::Output {
+                <$out>::new(f64::from(self))
+            }
+        }
+
+        $crate::impl_scalar_mul!(
+            @rhs $unit => $out, v,
+            f64 => v, f32 => f64::from(v), i32 => f64::from(v),
+            u32 => f64::from(v), i64 => v as f64, u64 => v as f64
+        );
+    };
+    (@rhs $unit:ty => $out:ty, $v:ident, $($scalar:ty => $conv:expr),+) => {
+        $(
+            impl core::ops::Mul<$scalar> for $unit {
+                type Output = $out;
+                fn mul(self, $v: $scalar) -> Self::Output {
+                    <$out>::new($conv)
+                }
+            }
+        )+
+    };
+}
+
 /// Define a custom [unit] of measure.
 ///
 /// * `unit` Unit struct name
 /// * `label` Standard unit label
 /// * `measure` A base or derived measure
 /// * `factor` Factor to convert
-/// * `zero` (Absolute) zero point
+/// * `zero` (Absolute) zero point (optional)
+/// * `name` Unit name, singular (optional)
+/// * `plural` Unit name, plural (optional)
+///
+/// Derived measures of [Quantity], such as [Pressure], work the same way as
+/// base measures.  For a derived struct such as `Speed`, see
+/// [declare_derived_unit].
 ///
 /// [Unit]: quan/trait.Unit.html
+/// [Quantity]: quan/struct.Quantity.html
+/// [Pressure]: quan/struct.Pressure.html
+/// [declare_derived_unit]: macro.declare_derived_unit.html
 #[macro_export]
 macro_rules! declare_unit {
     ($(#[$doc:meta])*
@@ -95,10 +615,11 @@ macro_rules! declare_unit {
         $label:expr,
         $measure:ident,
         $factor:expr,
+        $($name:literal, $plural:literal,)?
     ) => {
         $(#[$doc])*
         #[allow(non_camel_case_types)]
-        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
         pub struct $unit;
 
         impl $crate::quan::Unit for $unit {
@@ -106,20 +627,21 @@ macro_rules! declare_unit {
             const LABEL: &'static str = $label;
             const FACTOR: f64 = $factor;
             const ZERO: f64 = 0.0;
+            $(
+                const NAME: &'static str = $name;
+                const NAME_PLURAL: &'static str = $plural;
+            )?
         }
 
-        impl core::ops::Mul<$unit> for f64 {
-            type Output = $crate::quan::Quantity<$unit>;
-            fn mul(self, _unit: $unit) -> Self::Output {
-                Self::Output::new(self)
-            }
-        }
+        // scalar * <unit> => Quantity
+        $crate::impl_scalar_mul!($unit => $crate::quan::Quantity<$unit>);
 
-        impl core::ops::Mul<$unit> for i32 {
-            type Output = $crate::quan::Quantity<$unit>;
-            fn mul(self, _unit: $unit) -> Self::Output {
-                Self::Output::new(self)
-            }
+        // "<label>" => <unit>, for quantity!
+        impl $crate::lit::Lookup<$unit>
+            for $crate::lit::Label<{ $crate::lit::hash($label) }>
+        {
+            type Part = $unit;
+            const PART: $unit = $unit;
         }
     };
     ($(#[$doc:meta])*
@@ -128,10 +650,11 @@ macro_rules! declare_unit {
         $measure:ident,
         $factor:expr,
         $zero:expr,
+        $($name:literal, $plural:literal,)?
     ) => {
         $(#[$doc])*
         #[allow(non_camel_case_types)]
-        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
         pub struct $unit;
 
         impl $crate::quan::Unit for $unit {
@@ -139,6 +662,10 @@ macro_rules! declare_unit {
             const LABEL: &'static str = $label;
             const FACTOR: f64 = $factor;
             const ZERO: f64 = $zero;
+            $(
+                const NAME: &'static str = $name;
+                const NAME_PLURAL: &'static str = $plural;
+            )?
 
             /// Convert a value to another unit of the same measure
             fn convert<T>(val: f64) -> f64
@@ -150,18 +677,15 @@ macro_rules! declare_unit {
             }
         }
 
-        impl core::ops::Mul<$unit> for f64 {
-            type Output = $crate::quan::Quantity<$unit>;
-            fn mul(self, _unit: $unit) -> Self::Output {
-                Self::Output::new(self)
-            }
-        }
+        // scalar * <unit> => Quantity
+        $crate::impl_scalar_mul!($unit => $crate::quan::Quantity<$unit>);
 
-        impl core::ops::Mul<$unit> for i32 {
-            type Output = $crate::quan::Quantity<$unit>;
-            fn mul(self, _unit: $unit) -> Self::Output {
-                Self::Output::new(self)
-            }
+        // "<label>" => <unit>, for quantity!
+        impl $crate::lit::Lookup<$unit>
+            for $crate::lit::Label<{ $crate::lit::hash($label) }>
+        {
+            type Part = $unit;
+            const PART: $unit = $unit;
         }
     };
 }
@@ -175,8 +699,11 @@ macro_rules! declare_unit {
 ///
 /// * f64 `*` [Unit] `=> Quantity<Unit>`
 /// * i32 `*` [Unit] `=> Quantity<Unit>`
+/// * [Unit] `*` f64, f32, i32, u32, i64 or u64 `=> Quantity<Unit>`
 /// * `Quantity<Unit> + Quantity<Unit> => Quantity<Unit>`
 /// * `Quantity<Unit> - Quantity<Unit> => Quantity<Unit>`
+/// * `Quantity<Unit> += Quantity<Unit>`
+/// * `Quantity<Unit> -= Quantity<Unit>`
 ///
 /// [to]: #method.to
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -185,7 +712,7 @@ where
     U: Unit,
 {
     /// Quantity of units
-    pub value: f64,
+    value: f64,
 
     /// Unit of measure
     unit: PhantomData<U>,
@@ -201,11 +728,52 @@ where
         V: Into<f64>,
     {
         Self {
-            value: value.into(),
+            value: crate::check_finite(value.into()),
+            unit: PhantomData,
+        }
+    }
+
+    /// Create a new quantity in a `const` context
+    ///
+    /// ```rust
+    /// use mag::{mass::kg, quan::Quantity};
+    ///
+    /// const PAYLOAD: Quantity<kg> = Quantity::new_const(250.0);
+    /// assert_eq!(PAYLOAD, 250 * kg);
+    /// ```
+    pub const fn new_const(value: f64) -> Self {
+        Self {
+            value: crate::check_finite(value),
             unit: PhantomData,
         }
     }
 
+    /// Get the value, in the quantity's unit
+    ///
+    /// ```rust
+    /// use mag::mass::kg;
+    ///
+    /// assert_eq!((2.5 * kg).value(), 2.5);
+    /// ```
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Apply a function to the value, keeping the unit
+    ///
+    /// ```rust
+    /// use mag::mass::kg;
+    ///
+    /// let reading = -0.3 * kg;
+    /// assert_eq!(reading.map(|v| v.max(0.0)), 0.0 * kg);
+    /// ```
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: FnOnce(f64) -> f64,
+    {
+        Self::new(f(self.value))
+    }
+
     /// Convert quantity to the specified units
     pub fn to<T>(self) -> Quantity<T>
     where
@@ -213,6 +781,69 @@ where
     {
         Quantity::new(U::convert::<T>(self.value))
     }
+
+    /// Convert quantity to the specified units in a `const` context
+    ///
+    /// This uses the unit factors and zero points directly, so it matches
+    /// [to] for all units defined with [declare_unit], including
+    /// temperatures.  A custom `convert` method is not used.
+    ///
+    /// ```rust
+    /// use mag::{quan::Quantity, temp::{fahrenheit, DegC}};
+    ///
+    /// const BODY_TEMP: Quantity<DegC> = fahrenheit(98.6).to_const();
+    /// assert_eq!(format!("{:.1}", BODY_TEMP), "37.0 °C");
+    /// ```
+    ///
+    /// [declare_unit]: ../macro.declare_unit.html
+    /// [to]: #method.to
+    pub const fn to_const<T>(self) -> Quantity<T>
+    where
+        T: Unit<Measure = <U>::Measure>,
+    {
+        let value = if U::ZERO == 0.0 && T::ZERO == 0.0 {
+            self.value * (U::FACTOR / T::FACTOR)
+        } else {
+            (self.value - U::ZERO) * U::FACTOR / T::FACTOR + T::ZERO
+        };
+        Quantity::new_const(value)
+    }
+
+    /// Change the unit **without converting** the value.
+    ///
+    /// This is an escape hatch for cases where the value is already known to
+    /// be in the target unit, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<T>(self) -> Quantity<T>
+    where
+        T: Unit<Measure = <U>::Measure>,
+    {
+        Quantity::new(self.value)
+    }
+}
+
+impl<U> Magnitude for Quantity<U>
+where
+    U: Unit,
+{
+    fn magnitude(&self) -> f64 {
+        self.value
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<U> Label for Quantity<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::LABEL)
+    }
 }
 
 impl<U> fmt::Display for Quantity<U>
@@ -220,8 +851,18 @@ where
     U: Unit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.value.fmt(f)?;
-        write!(f, " {}", U::LABEL)
+        fmt_quantity(self, f)
+    }
+}
+
+impl<U> FromStr for Quantity<U>
+where
+    U: Unit,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
     }
 }
 
@@ -245,14 +886,79 @@ where
     }
 }
 
+impl<U> AddAssign for Quantity<U>
+where
+    U: Unit,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.value + other.value);
+    }
+}
+
+impl<U> SubAssign for Quantity<U>
+where
+    U: Unit,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.value - other.value);
+    }
+}
+
+impl<U> Sum for Quantity<U>
+where
+    U: Unit,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
+    }
+}
+
 /// Marker trait for units which can be scaled by multiplication (or division)
 ///
 /// * `Quantity<Unit> * f64 => Quantity<Unit>`
 /// * `f64 * Quantity<Unit> => Quantity<Unit>`
+/// * `i32 * Quantity<Unit> => Quantity<Unit>`
+/// * `u32 * Quantity<Unit> => Quantity<Unit>`
 /// * `Quantity<Unit> / f64 => Quantity<Unit>`
+/// * `Quantity<Unit> *= f64`
+/// * `Quantity<Unit> /= f64`
 pub trait MulUnit {}
 
 impl MulUnit for Mass {}
+impl MulUnit for Distance {}
+impl MulUnit for Time {}
+impl MulUnit for LiquidVolume {}
+impl MulUnit for LandArea {}
+impl MulUnit for Energy {}
+impl MulUnit for Power {}
+impl MulUnit for Pressure {}
+impl MulUnit for Torque {}
+impl MulUnit for Information {}
+impl MulUnit for Amount {}
+impl MulUnit for MolarMass {}
+
+/// Quantity type which can be scaled by a number
+///
+/// This is implemented for every quantity type except temperatures, which
+/// have no [MulUnit] measure.  Generic code can use it to bound scaling,
+/// such as [checked_mul](crate::checked::Checked::checked_mul).
+///
+/// ```compile_fail
+/// use mag::{checked::Checked, temp::DegC};
+///
+/// let _ = (20.0 * DegC).checked_mul(2.0);
+/// ```
+pub trait Scalable: Magnitude {}
+
+impl<U, M> Scalable for Quantity<U>
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+}
 
 impl<U, M, V> Mul<V> for Quantity<U>
 where
@@ -277,6 +983,41 @@ where
     }
 }
 
+impl<U, M> Mul<Quantity<U>> for i32
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    type Output = Quantity<U>;
+    fn mul(self, quan: Self::Output) -> Self::Output {
+        Self::Output::new(f64::from(self) * quan.value)
+    }
+}
+
+impl<U, M> Mul<Quantity<U>> for u32
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    type Output = Quantity<U>;
+    fn mul(self, quan: Self::Output) -> Self::Output {
+        Self::Output::new(f64::from(self) * quan.value)
+    }
+}
+
+impl<U, M> Quantity<U>
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.value * count as f64)
+    }
+}
+
 impl<U, M> Div<f64> for Quantity<U>
 where
     U: Unit<Measure = M>,
@@ -287,3 +1028,23 @@ where
         Self::new(self.value / scalar)
     }
 }
+
+impl<U, M> MulAssign<f64> for Quantity<U>
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.value * scalar);
+    }
+}
+
+impl<U, M> DivAssign<f64> for Quantity<U>
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.value / scalar);
+    }
+}
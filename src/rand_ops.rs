@@ -0,0 +1,160 @@
+// rand_ops.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Random sampling with the `rand` crate
+//!
+//! This module requires the `rand` feature.  `SampleUniform` is implemented
+//! for all quantity types, so they can be sampled uniformly from a range of
+//! quantities with the same units.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::m, time::s};
+//! use rand::{distributions::Uniform, rngs::mock::StepRng, Rng};
+//!
+//! let mut rng = StepRng::new(0, 1 << 60);
+//!
+//! let a = rng.gen_range(1.0 * m..5.0 * m);
+//! assert!(a >= 1.0 * m && a < 5.0 * m);
+//!
+//! let speeds = Uniform::new_inclusive(10.0 * m / s, 20.0 * m / s);
+//! for v in (&mut rng).sample_iter(speeds).take(10) {
+//!     assert!(v >= 10.0 * m / s && v <= 20.0 * m / s);
+//! }
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{Information, Magnitude, Mass, Quantity, Unit};
+use crate::{
+    length, speed, time, Acceleration, Area, Density, FlowRate, Force,
+    Frequency, InverseLength, Length, Period, Speed, Volume,
+};
+use core::marker::PhantomData;
+use rand::distributions::uniform::{
+    SampleBorrow, SampleUniform, UniformFloat, UniformSampler,
+};
+use rand::Rng;
+
+/// Uniform sampler for quantities
+///
+/// Quantities are sampled by their magnitudes, using `UniformFloat<f64>`.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformQuantity<Q> {
+    /// Magnitude sampler
+    inner: UniformFloat<f64>,
+
+    /// Quantity type
+    quan: PhantomData<Q>,
+}
+
+impl<Q> UniformSampler for UniformQuantity<Q>
+where
+    Q: Magnitude,
+{
+    type X = Q;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Q> + Sized,
+        B2: SampleBorrow<Q> + Sized,
+    {
+        UniformQuantity {
+            inner: UniformFloat::new(
+                low.borrow().magnitude(),
+                high.borrow().magnitude(),
+            ),
+            quan: PhantomData,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Q> + Sized,
+        B2: SampleBorrow<Q> + Sized,
+    {
+        UniformQuantity {
+            inner: UniformFloat::new_inclusive(
+                low.borrow().magnitude(),
+                high.borrow().magnitude(),
+            ),
+            quan: PhantomData,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Q {
+        Q::from_magnitude(self.inner.sample(rng))
+    }
+}
+
+/// Implement `SampleUniform` for a quantity type
+macro_rules! impl_sample_uniform {
+    ($quan:ident<$($param:ident),+> where $($bounds:tt)+) => {
+        impl<$($param),+> SampleUniform for $quan<$($param),+>
+        where
+            $($bounds)+
+        {
+            type Sampler = UniformQuantity<Self>;
+        }
+    };
+}
+
+impl_sample_uniform!(Length<U> where U: length::Unit);
+impl_sample_uniform!(Area<U> where U: length::Unit);
+impl_sample_uniform!(InverseLength<U> where U: length::Unit);
+impl_sample_uniform!(Volume<U> where U: length::Unit);
+impl_sample_uniform!(Period<U> where U: time::Unit);
+impl_sample_uniform!(Frequency<U> where U: time::Unit);
+impl_sample_uniform!(Speed<L, P> where (L, P): speed::Units);
+impl_sample_uniform!(
+    Acceleration<L, P> where L: length::Unit, P: time::Unit
+);
+impl_sample_uniform!(FlowRate<L, P> where L: length::Unit, P: time::Unit);
+impl_sample_uniform!(
+    Density<M, L> where M: Unit<Measure = Mass>, L: length::Unit
+);
+impl_sample_uniform!(
+    Force<M, L, T>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        T: time::Unit
+);
+impl_sample_uniform!(
+    Bandwidth<D, P> where D: Unit<Measure = Information>, P: time::Unit
+);
+impl_sample_uniform!(Quantity<U> where U: Unit);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::mass::kg;
+    use crate::temp::DegC;
+    use crate::time::s;
+    use rand::distributions::{Distribution, Uniform};
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn sample_range() {
+        let mut rng = StepRng::new(0, 0x1234_5678_9abc_def1);
+        for _ in 0..100 {
+            let a = rng.gen_range(1.0 * m..5.0 * m);
+            assert!(a >= 1.0 * m && a < 5.0 * m);
+            let t = rng.gen_range(-10.0 * DegC..=40.0 * DegC);
+            assert!(t >= -10.0 * DegC && t <= 40.0 * DegC);
+        }
+    }
+
+    #[test]
+    fn sample_uniform() {
+        let mut rng = StepRng::new(0, 1 << 61);
+        let dist = Uniform::new(2.0 * kg, 3.0 * kg);
+        let a = dist.sample(&mut rng);
+        assert_eq!(a, 2.0 * kg);
+        let b = dist.sample(&mut rng);
+        assert_eq!(b, 2.125 * kg);
+        let dist = Uniform::new(0.0 * ft / s, 8.0 * ft / s);
+        assert_eq!(dist.sample(&mut rng), 2.0 * ft / s);
+    }
+}
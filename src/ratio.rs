@@ -0,0 +1,313 @@
+// ratio.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Dimensionless ratios
+//!
+//! Dividing two quantities of the same type results in a [Ratio].  Ratios
+//! can be multiplied back into any quantity, and displayed as a percentage
+//! with [display_percent](Ratio::display_percent).
+//!
+//! For [Quantity], division is implemented for every unit except
+//! temperatures (which may have an offset zero), including custom units.
+//!
+//! Before ratios were added, `Length`, `Area` and `Volume` division resulted
+//! in `f64`; use `f64::from(ratio)` where a plain number is still needed.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::m, ratio::Ratio, time::s};
+//!
+//! let r = (5.0 * m) / (2.0 * m);
+//! assert_eq!(r, Ratio::new(2.5));
+//! assert_eq!(r.display_percent().to_string(), "250%");
+//! assert_eq!(r * (4.0 * s), 10.0 * s);
+//!
+//! let grade: Ratio = "6%".parse()?;
+//! assert_eq!(format!("{:.2}", grade), "0.06");
+//! # Ok::<(), mag::quan::ParseError>(())
+//! ```
+use crate::information::Bandwidth;
+use crate::quan::{Information, Mass, MulUnit, ParseError, Quantity, Unit};
+use crate::{
+    length, speed, time, Acceleration, Area, Density, FlowRate, Force,
+    Frequency, InverseLength, Length, Period, Speed, Volume,
+};
+use core::fmt;
+use core::ops::{Div, Mul};
+use core::str::FromStr;
+
+/// Dimensionless ratio of two quantities
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Ratio {
+    /// Ratio value (1.0 is 100%)
+    value: f64,
+}
+
+/// Display wrapper for a [Ratio] as a percentage
+///
+/// Formatter flags (precision, width, sign) apply to the percentage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PercentDisplay(pub Ratio);
+
+impl Ratio {
+    /// Create a new ratio
+    pub const fn new(value: f64) -> Self {
+        Ratio { value }
+    }
+
+    /// Get the ratio value (1.0 is 100%)
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    /// Create a ratio from a percentage
+    pub const fn from_percent(percent: f64) -> Self {
+        Self::new(percent / 100.0)
+    }
+
+    /// Get the ratio as a percentage
+    pub const fn percent(self) -> f64 {
+        self.value * 100.0
+    }
+
+    /// Display as a percentage, such as `250%`
+    pub fn display_percent(self) -> PercentDisplay {
+        PercentDisplay(self)
+    }
+}
+
+impl From<f64> for Ratio {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Ratio> for f64 {
+    fn from(ratio: Ratio) -> Self {
+        ratio.value
+    }
+}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Display for PercentDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0.percent(), f)?;
+        f.write_str("%")
+    }
+}
+
+impl FromStr for Ratio {
+    type Err = ParseError;
+
+    /// Parse a ratio, such as `0.25` or `25%`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (s, percent) = match s.strip_suffix('%') {
+            Some(s) => (s.trim_end(), true),
+            None => (s, false),
+        };
+        let value: f64 = s.parse().map_err(|_| ParseError::Value)?;
+        if cfg!(feature = "assert-finite") && !value.is_finite() {
+            return Err(ParseError::Value);
+        }
+        Ok(if percent {
+            Self::from_percent(value)
+        } else {
+            Self::new(value)
+        })
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        Self::new(self.value * other.value)
+    }
+}
+
+impl Mul<f64> for Ratio {
+    type Output = f64;
+    fn mul(self, scalar: f64) -> Self::Output {
+        self.value * scalar
+    }
+}
+
+impl Mul<Ratio> for f64 {
+    type Output = f64;
+    fn mul(self, ratio: Ratio) -> Self::Output {
+        self * ratio.value
+    }
+}
+
+impl Div for Ratio {
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        Self::new(self.value / other.value)
+    }
+}
+
+/// Implement ratio operations for a quantity struct
+///
+/// * `Quantity / Quantity => Ratio`
+/// * `Quantity * Ratio => Quantity`
+/// * `Ratio * Quantity => Quantity`
+macro_rules! impl_ratio_ops {
+    ($quan:ident<$($param:ident),+> where $($bounds:tt)+) => {
+        impl<$($param),+> Div for $quan<$($param),+>
+        where
+            $($bounds)+
+        {
+            type Output = Ratio;
+            fn div(self, other: Self) -> Self::Output {
+                Ratio::new(self.quantity() / other.quantity())
+            }
+        }
+
+        impl<$($param),+> Mul<Ratio> for $quan<$($param),+>
+        where
+            $($bounds)+
+        {
+            type Output = Self;
+            fn mul(self, ratio: Ratio) -> Self::Output {
+                Self::new(self.quantity() * ratio.value)
+            }
+        }
+
+        impl<$($param),+> Mul<$quan<$($param),+>> for Ratio
+        where
+            $($bounds)+
+        {
+            type Output = $quan<$($param),+>;
+            fn mul(self, quan: Self::Output) -> Self::Output {
+                quan * self
+            }
+        }
+    };
+}
+
+impl_ratio_ops!(Length<U> where U: length::Unit);
+impl_ratio_ops!(Area<U> where U: length::Unit);
+impl_ratio_ops!(InverseLength<U> where U: length::Unit);
+impl_ratio_ops!(Volume<U> where U: length::Unit);
+impl_ratio_ops!(Period<U> where U: time::Unit);
+impl_ratio_ops!(Frequency<U> where U: time::Unit);
+impl_ratio_ops!(Speed<L, P> where (L, P): speed::Units);
+impl_ratio_ops!(Acceleration<L, P> where L: length::Unit, P: time::Unit);
+impl_ratio_ops!(FlowRate<L, P> where L: length::Unit, P: time::Unit);
+impl_ratio_ops!(
+    Density<M, L> where M: Unit<Measure = Mass>, L: length::Unit
+);
+impl_ratio_ops!(
+    Force<M, L, T>
+    where
+        M: Unit<Measure = Mass>,
+        L: length::Unit,
+        T: time::Unit
+);
+impl_ratio_ops!(
+    Bandwidth<D, P> where D: Unit<Measure = Information>, P: time::Unit
+);
+
+impl<U, M> Quantity<U>
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    /// Calculate the ratio to another quantity of the same unit
+    ///
+    /// Quantities can also be multiplied by a [Ratio], since it converts
+    /// into `f64`.
+    pub fn ratio(self, other: Self) -> Ratio {
+        Ratio::new(self.value() / other.value())
+    }
+}
+
+// Quantity / Quantity => Ratio
+impl<U, M> Div for Quantity<U>
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    type Output = Ratio;
+    fn div(self, other: Self) -> Self::Output {
+        self.ratio(other)
+    }
+}
+
+impl<U, M> Mul<Quantity<U>> for Ratio
+where
+    U: Unit<Measure = M>,
+    M: MulUnit,
+{
+    type Output = Quantity<U>;
+    fn mul(self, quan: Self::Output) -> Self::Output {
+        quan * self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::energy::J;
+    use crate::length::{ft, km, m};
+    use crate::liquid::L;
+    use crate::mass::kg;
+    use crate::power::W;
+    use crate::time::{h, s};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn ratio_div() {
+        assert_eq!((5.0 * m) / (2.0 * m), Ratio::new(2.5));
+        assert_eq!((3.0 * km) / (12.0 * km), Ratio::new(0.25));
+        assert_eq!((6.0 * s) / (3.0 * s), Ratio::new(2.0));
+        assert_eq!((60.0 * km / h) / (40.0 * km / h), Ratio::new(1.5));
+        assert_eq!((1.0 * kg).ratio(4.0 * kg), Ratio::new(0.25));
+        assert_eq!((1.0 * kg) / (4.0 * kg), Ratio::new(0.25));
+        assert_eq!((3.0 * J) / (2.0 * J), Ratio::new(1.5));
+        assert_eq!((3.0 * W) / (6.0 * W), Ratio::new(0.5));
+        assert_eq!(
+            Quantity::<m>::new(9.0) / Quantity::new(3.0),
+            Ratio::new(3.0)
+        );
+        assert_eq!(f64::from((2.0 * L) / (8.0 * L)), 0.25);
+    }
+
+    #[test]
+    fn ratio_mul() {
+        let r = Ratio::from_percent(50.0);
+        assert_eq!(r * (3.0 * ft), 1.5 * ft);
+        assert_eq!((8.0 * ft * ft) * r, 4.0 * ft * ft);
+        assert_eq!(r * (2.0 * L), 1.0 * L);
+        assert_eq!((2.0 * L) * r, 1.0 * L);
+        assert_eq!(r * Ratio::new(0.5), Ratio::new(0.25));
+        assert_eq!(r * 10.0, 5.0);
+    }
+
+    #[test]
+    fn ratio_display() {
+        let r = Ratio::new(2.5);
+        assert_eq!(r.to_string(), "2.5");
+        assert_eq!(r.percent(), 250.0);
+        assert_eq!(r.display_percent().to_string(), "250%");
+        let r = (1.0 * m) / (3.0 * m);
+        assert_eq!(format!("{:.1}", r.display_percent()), "33.3%");
+    }
+
+    #[test]
+    fn ratio_parse() {
+        assert_eq!("0.25".parse(), Ok(Ratio::new(0.25)));
+        assert_eq!(" 150 % ".parse(), Ok(Ratio::new(1.5)));
+        assert_eq!("%".parse::<Ratio>(), Err(ParseError::Value));
+        assert_eq!("half".parse::<Ratio>(), Err(ParseError::Value));
+    }
+}
@@ -0,0 +1,216 @@
+// rect.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Axis-aligned rectangles with length units
+//!
+//! Points are `(x, y)` pairs of [Length]s, as with [Area::polygon].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{ft, m}, rect::Rect};
+//!
+//! let room = Rect::new((0.0 * m, 0.0 * m), 4.0 * m, 3.0 * m);
+//!
+//! assert_eq!(room.area(), 12.0 * m * m);
+//! assert!(room.contains((2.5 * m, 1.0 * m)));
+//! assert!(!room.contains((2.5 * m, 3.5 * m)));
+//!
+//! let plan = room.translate(1.0 * m, 1.0 * m).scale(0.5);
+//! assert_eq!(plan.width, 2.0 * m);
+//! assert_eq!(plan.x, 0.5 * m);
+//! assert_eq!(format!("{:.2}", room.to::<ft>().width), "13.12 ft");
+//! ```
+//!
+//! [Area::polygon]: ../struct.Area.html#method.polygon
+use crate::length::Unit;
+use crate::{Area, Length};
+
+/// Axis-aligned rectangle
+///
+/// The rectangle is closed: points on its edges are contained.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect<U>
+where
+    U: Unit,
+{
+    /// Minimum X coordinate
+    pub x: Length<U>,
+
+    /// Minimum Y coordinate
+    pub y: Length<U>,
+
+    /// Width (non-negative)
+    pub width: Length<U>,
+
+    /// Height (non-negative)
+    pub height: Length<U>,
+}
+
+impl<U> Rect<U>
+where
+    U: Unit,
+{
+    /// Create a new rectangle from an origin point and size
+    ///
+    /// A negative width or height extends from the origin in the negative
+    /// direction.
+    pub fn new(
+        origin: (Length<U>, Length<U>),
+        width: Length<U>,
+        height: Length<U>,
+    ) -> Self {
+        let (x0, w) = span(origin.0.quantity(), width.quantity());
+        let (y0, h) = span(origin.1.quantity(), height.quantity());
+        Rect {
+            x: Length::new(x0),
+            y: Length::new(y0),
+            width: Length::new(w),
+            height: Length::new(h),
+        }
+    }
+
+    /// Create a new rectangle with two opposite corners
+    pub fn from_corners(
+        a: (Length<U>, Length<U>),
+        b: (Length<U>, Length<U>),
+    ) -> Self {
+        let width = Length::new(b.0.quantity() - a.0.quantity());
+        let height = Length::new(b.1.quantity() - a.1.quantity());
+        Rect::new(a, width, height)
+    }
+
+    /// Get the maximum X coordinate
+    pub fn right(&self) -> Length<U> {
+        Length::new(self.x.quantity() + self.width.quantity())
+    }
+
+    /// Get the maximum Y coordinate
+    pub fn bottom(&self) -> Length<U> {
+        Length::new(self.y.quantity() + self.height.quantity())
+    }
+
+    /// Get the center point
+    pub fn center(&self) -> (Length<U>, Length<U>) {
+        (
+            Length::new(self.x.quantity() + self.width.quantity() / 2.0),
+            Length::new(self.y.quantity() + self.height.quantity() / 2.0),
+        )
+    }
+
+    /// Calculate the area
+    pub fn area(&self) -> Area<U> {
+        Area::new(self.width.quantity() * self.height.quantity())
+    }
+
+    /// Scale position and size by a factor, relative to the origin
+    pub fn scale(&self, factor: f64) -> Self {
+        Rect::new(
+            (
+                Length::new(self.x.quantity() * factor),
+                Length::new(self.y.quantity() * factor),
+            ),
+            Length::new(self.width.quantity() * factor),
+            Length::new(self.height.quantity() * factor),
+        )
+    }
+
+    /// Move by an offset
+    pub fn translate(&self, dx: Length<U>, dy: Length<U>) -> Self {
+        Rect {
+            x: Length::new(self.x.quantity() + dx.quantity()),
+            y: Length::new(self.y.quantity() + dy.quantity()),
+            width: Length::new(self.width.quantity()),
+            height: Length::new(self.height.quantity()),
+        }
+    }
+
+    /// Check whether a point is inside the rectangle (or on an edge)
+    pub fn contains(&self, pt: (Length<U>, Length<U>)) -> bool {
+        let (x, y) = (pt.0.quantity(), pt.1.quantity());
+        x >= self.x.quantity()
+            && x <= self.right().quantity()
+            && y >= self.y.quantity()
+            && y <= self.bottom().quantity()
+    }
+
+    /// Check whether another rectangle is entirely inside this one
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        other.x.quantity() >= self.x.quantity()
+            && other.right().quantity() <= self.right().quantity()
+            && other.y.quantity() >= self.y.quantity()
+            && other.bottom().quantity() <= self.bottom().quantity()
+    }
+
+    /// Check whether two rectangles overlap (or touch)
+    pub fn intersects(&self, other: &Self) -> bool {
+        other.x.quantity() <= self.right().quantity()
+            && other.right().quantity() >= self.x.quantity()
+            && other.y.quantity() <= self.bottom().quantity()
+            && other.bottom().quantity() >= self.y.quantity()
+    }
+
+    /// Convert to specified units
+    pub fn to<T: Unit>(&self) -> Rect<T> {
+        let factor = U::factor::<T>();
+        Rect {
+            x: Length::new(self.x.quantity() * factor),
+            y: Length::new(self.y.quantity() * factor),
+            width: Length::new(self.width.quantity() * factor),
+            height: Length::new(self.height.quantity() * factor),
+        }
+    }
+}
+
+/// Normalize a span to start at the minimum, with non-negative length
+fn span(start: f64, len: f64) -> (f64, f64) {
+    if len < 0.0 {
+        (start + len, -len)
+    } else {
+        (start, len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{cm, m, mm};
+
+    #[test]
+    fn rect_new() {
+        let r = Rect::new((1.0 * m, 2.0 * m), -3.0 * m, 4.0 * m);
+        assert_eq!(r.x, -2.0 * m);
+        assert_eq!(r.width, 3.0 * m);
+        assert_eq!(r.right(), 1.0 * m);
+        assert_eq!(r.bottom(), 6.0 * m);
+        assert_eq!(r.center(), (-0.5 * m, 4.0 * m));
+        let c = Rect::from_corners((5.0 * cm, 5.0 * cm), (1.0 * cm, 2.0 * cm));
+        assert_eq!(c, Rect::new((1.0 * cm, 2.0 * cm), 4.0 * cm, 3.0 * cm));
+        assert_eq!(c.area(), 12.0 * cm * cm);
+    }
+
+    #[test]
+    fn rect_contains() {
+        let r = Rect::new((0.0 * mm, 0.0 * mm), 10.0 * mm, 5.0 * mm);
+        assert!(r.contains((0.0 * mm, 0.0 * mm)));
+        assert!(r.contains((10.0 * mm, 5.0 * mm)));
+        assert!(!r.contains((10.5 * mm, 5.0 * mm)));
+        let s = Rect::new((2.0 * mm, 1.0 * mm), 3.0 * mm, 3.0 * mm);
+        assert!(r.contains_rect(&s));
+        assert!(!s.contains_rect(&r));
+        let t = s.translate(7.0 * mm, 0.0 * mm);
+        assert!(!r.contains_rect(&t));
+        assert!(r.intersects(&t));
+        assert!(r.intersects(&t.translate(1.0 * mm, 0.0 * mm)));
+        assert!(!r.intersects(&t.translate(1.5 * mm, 0.0 * mm)));
+    }
+
+    #[test]
+    fn rect_scale() {
+        let r = Rect::new((1.0 * m, 1.0 * m), 2.0 * m, 1.0 * m);
+        let s = r.scale(-2.0);
+        assert_eq!(s, Rect::new((-6.0 * m, -4.0 * m), 4.0 * m, 2.0 * m));
+        assert_eq!(r.to::<cm>().height, 100.0 * cm);
+    }
+}
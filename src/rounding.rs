@@ -0,0 +1,155 @@
+// rounding.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Rounding rules for quantities
+//!
+//! Enforcement and billing systems often have codified rules, such as
+//! "speeds are rounded down to a whole km/h".  A [RoundingRule] captures a
+//! step size and [Rounding] direction, and can be applied to any number of
+//! quantities.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{cm, km, m}, rounding::RoundingRule, time::h};
+//!
+//! let speed = RoundingRule::down(1.0 * km / h);
+//! assert_eq!(speed.apply(87.9 * km / h), 87.0 * km / h);
+//!
+//! let length = RoundingRule::nearest((1.0 * cm).to::<m>());
+//! assert_eq!(length.apply(2.345_6 * m), 2.35 * m);
+//! ```
+use crate::quan::Magnitude;
+
+/// Direction for rounding to a step
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward negative infinity (floor)
+    Down,
+
+    /// Round toward positive infinity (ceiling)
+    Up,
+
+    /// Round toward zero (truncate)
+    TowardZero,
+
+    /// Round to nearest step, with halfway cases away from zero
+    Nearest,
+}
+
+/// Rule for rounding quantities to a multiple of a step
+///
+/// Step counts saturate at the `i64` limits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundingRule<Q> {
+    /// Step size
+    step: Q,
+
+    /// Rounding direction
+    rounding: Rounding,
+}
+
+impl<Q> RoundingRule<Q>
+where
+    Q: Magnitude,
+{
+    /// Create a new rounding rule
+    pub fn new(step: Q, rounding: Rounding) -> Self {
+        RoundingRule { step, rounding }
+    }
+
+    /// Create a rule to round down to a step
+    pub fn down(step: Q) -> Self {
+        Self::new(step, Rounding::Down)
+    }
+
+    /// Create a rule to round up to a step
+    pub fn up(step: Q) -> Self {
+        Self::new(step, Rounding::Up)
+    }
+
+    /// Create a rule to round toward zero to a step
+    pub fn toward_zero(step: Q) -> Self {
+        Self::new(step, Rounding::TowardZero)
+    }
+
+    /// Create a rule to round to the nearest step
+    pub fn nearest(step: Q) -> Self {
+        Self::new(step, Rounding::Nearest)
+    }
+
+    /// Get the step size
+    pub fn step(&self) -> &Q {
+        &self.step
+    }
+
+    /// Get the rounding direction
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// Apply the rule to a quantity
+    pub fn apply(&self, q: Q) -> Q {
+        let step = self.step.magnitude();
+        let steps = q.magnitude() / step;
+        Q::from_magnitude(self.rounding.round(steps) * step)
+    }
+}
+
+impl Rounding {
+    /// Round a value to an integer (saturating at the `i64` limits)
+    pub(crate) fn round(self, val: f64) -> f64 {
+        match self {
+            Rounding::Down => floor(val),
+            Rounding::Up => -floor(-val),
+            Rounding::TowardZero => val as i64 as f64,
+            Rounding::Nearest => crate::quantize(val, 1.0).0 as f64,
+        }
+    }
+}
+
+/// Round a value down to an integer
+fn floor(val: f64) -> f64 {
+    let t = val as i64 as f64;
+    if t > val {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{cm, m, mm};
+    use crate::mass::{g, kg};
+    use crate::time::{min, s};
+
+    #[test]
+    fn round_directions() {
+        let step = 0.5 * m;
+        assert_eq!(RoundingRule::down(step).apply(1.7 * m), 1.5 * m);
+        assert_eq!(RoundingRule::down(step).apply(-1.7 * m), -2.0 * m);
+        assert_eq!(RoundingRule::up(step).apply(1.2 * m), 1.5 * m);
+        assert_eq!(RoundingRule::up(step).apply(-1.2 * m), -1.0 * m);
+        assert_eq!(RoundingRule::toward_zero(step).apply(-1.7 * m), -1.5 * m);
+        assert_eq!(RoundingRule::nearest(step).apply(1.7 * m), 1.5 * m);
+        assert_eq!(RoundingRule::nearest(step).apply(-1.25 * m), -1.5 * m);
+        assert_eq!(RoundingRule::up(step).apply(2.0 * m), 2.0 * m);
+    }
+
+    #[test]
+    fn round_units() {
+        // billing: round up to whole minutes
+        let billing = RoundingRule::up(1.0 * min);
+        assert_eq!(billing.apply((125.0 * s).to()), 3.0 * min);
+        let weight = RoundingRule::nearest(10.0 * g);
+        assert_eq!(weight.apply(1_234.0 * g), 1_230.0 * g);
+        let scale = RoundingRule::new((100.0 * g).to::<kg>(), Rounding::Down);
+        assert_eq!(scale.apply(2.55 * kg), 2.5 * kg);
+        assert_eq!(scale.rounding(), Rounding::Down);
+        let mm_rule = RoundingRule::nearest((1.0 * mm).to::<cm>());
+        assert_eq!(mm_rule.step(), &(0.1 * cm));
+    }
+}
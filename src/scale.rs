@@ -0,0 +1,153 @@
+// scale.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Map and model scales
+//!
+//! A [Scale] is a dimensionless ratio between lengths on a map (or scale
+//! model) and the real lengths they represent, such as `1:25000`.
+//! Conversions can change units at the same time, since map lengths are
+//! usually measured in much smaller units than real ones.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{cm, km, m, mm}, scale::Scale};
+//!
+//! let map = Scale::one_to(25_000.0);
+//! assert_eq!(map.to_real::<_, km>(4.0 * cm), 1.0 * km);
+//! assert_eq!(map.to_model::<_, mm>(50.0 * m), 2.0 * mm);
+//!
+//! let ho: Scale = "1:87".parse()?;
+//! assert_eq!(ho.to_string(), "1:87");
+//! # Ok::<(), mag::quan::ParseError>(())
+//! ```
+use crate::length::Unit;
+use crate::quan::ParseError;
+use crate::Length;
+use core::fmt;
+use core::str::FromStr;
+
+/// Ratio of model (or map) lengths to real lengths
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Scale {
+    /// Real length per unit of model length
+    factor: f64,
+}
+
+impl Scale {
+    /// Create a new scale from a `model:real` ratio
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either side of the ratio is not positive and finite.
+    pub fn new(model: f64, real: f64) -> Self {
+        assert!(is_positive(model) && is_positive(real), "invalid scale");
+        Scale {
+            factor: real / model,
+        }
+    }
+
+    /// Create a reduction scale of `1:real`, such as a map scale
+    pub fn one_to(real: f64) -> Self {
+        Self::new(1.0, real)
+    }
+
+    /// Get the real length per unit of model length
+    pub fn factor(self) -> f64 {
+        self.factor
+    }
+
+    /// Convert a model length to real length, in units `R`
+    pub fn to_real<U, R>(self, model: Length<U>) -> Length<R>
+    where
+        U: Unit,
+        R: Unit,
+    {
+        Length::<U>::new(model.quantity() * self.factor).to()
+    }
+
+    /// Convert a real length to model length, in units `R`
+    pub fn to_model<U, R>(self, real: Length<U>) -> Length<R>
+    where
+        U: Unit,
+        R: Unit,
+    {
+        Length::<U>::new(real.quantity() / self.factor).to()
+    }
+}
+
+/// Check if a value is positive and finite
+fn is_positive(value: f64) -> bool {
+    value > 0.0 && value.is_finite()
+}
+
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.factor >= 1.0 {
+            write!(f, "1:{}", self.factor)
+        } else {
+            write!(f, "{}:1", 1.0 / self.factor)
+        }
+    }
+}
+
+impl FromStr for Scale {
+    type Err = ParseError;
+
+    /// Parse a scale ratio, such as `1:25000` or `1/87`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (model, real) =
+            s.trim().split_once([':', '/']).ok_or(ParseError::Value)?;
+        let model: f64 = model.trim().parse().map_err(|_| ParseError::Value)?;
+        let real: f64 = real.trim().parse().map_err(|_| ParseError::Value)?;
+        if is_positive(model) && is_positive(real) {
+            Ok(Self::new(model, real))
+        } else {
+            Err(ParseError::Value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{m, mi, mm, In};
+    use alloc::string::ToString;
+
+    #[test]
+    fn scale_convert() {
+        let s = Scale::one_to(63_360.0);
+        assert_eq!(s.to_real::<In, mi>(1.0 * In), 1.0 * mi);
+        assert_eq!(s.to_model::<mi, In>(2.5 * mi), 2.5 * In);
+        let s = Scale::new(10.0, 1.0);
+        assert_eq!(s.to_real::<mm, mm>(50.0 * mm), 5.0 * mm);
+        assert_eq!(s.to_model::<m, mm>(1.0 * m), 10_000.0 * mm);
+        assert_eq!(s.factor(), 0.1);
+    }
+
+    #[test]
+    fn scale_display() {
+        assert_eq!(Scale::one_to(25_000.0).to_string(), "1:25000");
+        assert_eq!(Scale::new(2.0, 1.0).to_string(), "2:1");
+        assert_eq!(Scale::new(1.0, 1.0).to_string(), "1:1");
+    }
+
+    #[test]
+    fn scale_parse() {
+        assert_eq!("1:24000".parse(), Ok(Scale::one_to(24_000.0)));
+        assert_eq!(" 1 / 48 ".parse(), Ok(Scale::new(1.0, 48.0)));
+        assert_eq!("4:1".parse::<Scale>().unwrap().to_string(), "4:1");
+        assert_eq!("1:0".parse::<Scale>(), Err(ParseError::Value));
+        assert_eq!("1-87".parse::<Scale>(), Err(ParseError::Value));
+        assert_eq!("one:ten".parse::<Scale>(), Err(ParseError::Value));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid scale")]
+    fn scale_invalid() {
+        Scale::one_to(-1.0);
+    }
+}
@@ -0,0 +1,309 @@
+// slice.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Helpers for slices of quantities
+//!
+//! Bulk operations such as [convert_slice] and [summarize] work in a single
+//! pass, with conversion factors computed once rather than per element.
+use crate::dynamic::Dynamic;
+use crate::quan::Magnitude;
+use core::cmp::Ordering;
+
+/// Summary of a slice of quantities, from [summarize]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary<Q>
+where
+    Q: Magnitude,
+{
+    /// Number of quantities
+    pub count: usize,
+
+    /// Sum of all quantities
+    pub sum: Q,
+
+    /// Minimum quantity
+    pub min: Q,
+
+    /// Maximum quantity
+    pub max: Q,
+}
+
+impl<Q> Summary<Q>
+where
+    Q: Magnitude,
+{
+    /// Get the arithmetic mean
+    pub fn mean(&self) -> Q {
+        Q::from_magnitude(self.sum.magnitude() / self.count as f64)
+    }
+}
+
+/// Convert raw magnitudes in place from one unit to another.
+///
+/// The magnitudes are in the units of `Q`, and are converted to the units of
+/// `R`.  Offsets (such as temperature zero points) are handled.  This is a
+/// simple multiply-add loop, which the compiler can auto-vectorize.
+///
+/// ```rust
+/// use mag::{length::{ft, m}, slice::convert_slice, Length};
+///
+/// let mut values = [1.0, 2.5, 10.0];
+/// convert_slice::<Length<m>, Length<ft>>(&mut values);
+///
+/// assert_eq!(format!("{:.3}", values[1]), "8.202");
+/// ```
+pub fn convert_slice<Q, R>(values: &mut [f64])
+where
+    Q: Dynamic,
+    R: Dynamic<Base = Q::Base>,
+{
+    let scale = Q::base_factor() / R::base_factor();
+    let offset = R::zero() - Q::zero() * scale;
+    for v in values.iter_mut() {
+        *v = *v * scale + offset;
+    }
+}
+
+/// Convert a slice of quantities into another slice with different units.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+///
+/// ```rust
+/// use mag::{length::{km, m}, slice::convert_quantities};
+///
+/// let legs = [1.2 * km, 0.35 * km];
+/// let mut out = [0.0 * m; 2];
+/// convert_quantities(&legs, &mut out);
+///
+/// assert_eq!(out, [1_200.0 * m, 350.0 * m]);
+/// ```
+pub fn convert_quantities<Q, R>(src: &[Q], dst: &mut [R])
+where
+    Q: Dynamic,
+    R: Dynamic<Base = Q::Base>,
+{
+    assert_eq!(src.len(), dst.len(), "slice lengths differ");
+    let scale = Q::base_factor() / R::base_factor();
+    let offset = R::zero() - Q::zero() * scale;
+    for (q, r) in src.iter().zip(dst.iter_mut()) {
+        *r = R::from_magnitude(q.magnitude() * scale + offset);
+    }
+}
+
+/// Summarize a slice of quantities in a single pass.
+///
+/// Returns `None` if the slice is empty.  NaN magnitudes are ignored for
+/// `min` and `max`, but propagate to `sum`.
+///
+/// ```rust
+/// use mag::{mass::kg, slice::summarize};
+///
+/// let loads = [12.0 * kg, 7.5 * kg, 20.5 * kg];
+/// let s = summarize(&loads).unwrap();
+///
+/// assert_eq!(s.count, 3);
+/// assert_eq!(s.sum, 40.0 * kg);
+/// assert_eq!(s.min, 7.5 * kg);
+/// assert_eq!(s.max, 20.5 * kg);
+/// assert_eq!(format!("{:.2}", s.mean()), "13.33 kg");
+/// ```
+pub fn summarize<Q>(items: &[Q]) -> Option<Summary<Q>>
+where
+    Q: Magnitude,
+{
+    let first = items.first()?.magnitude();
+    let mut sum = 0.0;
+    let mut min = first;
+    let mut max = first;
+    for q in items {
+        let v = q.magnitude();
+        sum += v;
+        min = min.min(v);
+        max = max.max(v);
+    }
+    Some(Summary {
+        count: items.len(),
+        sum: Q::from_magnitude(sum),
+        min: Q::from_magnitude(min),
+        max: Q::from_magnitude(max),
+    })
+}
+
+/// Collapse consecutive quantities within a tolerance of each other.
+///
+/// Each quantity is compared with the last one kept; if they differ by no
+/// more than `tol`, it is removed.  Kept quantities are moved to the front of
+/// the slice, in order, and that prefix is returned.  The order of the
+/// remaining (removed) quantities is unspecified.
+///
+/// ```rust
+/// use mag::{length::m, slice::dedup_within};
+///
+/// let mut readings = [1.0 * m, 1.02 * m, 0.99 * m, 1.5 * m, 1.51 * m, 1.0 * m];
+/// let kept = dedup_within(&mut readings, 0.05 * m);
+///
+/// assert_eq!(kept, [1.0 * m, 1.5 * m, 1.0 * m]);
+/// ```
+pub fn dedup_within<Q>(items: &mut [Q], tol: Q) -> &mut [Q]
+where
+    Q: Magnitude,
+{
+    let tol = tol.magnitude();
+    let mut len = 0;
+    for i in 0..items.len() {
+        let keep = match len {
+            0 => true,
+            _ => {
+                let diff = items[i].magnitude() - items[len - 1].magnitude();
+                !(-tol..=tol).contains(&diff)
+            }
+        };
+        if keep {
+            items.swap(len, i);
+            len += 1;
+        }
+    }
+    &mut items[..len]
+}
+
+/// Binary search a sorted slice of quantities.
+///
+/// Quantities are compared with [f64::total_cmp] on their magnitudes, so no
+/// `partial_cmp` unwrapping is needed.  Returns `Ok` with the index of a
+/// matching quantity, or `Err` with the index where it could be inserted to
+/// keep the slice sorted.
+///
+/// ```rust
+/// use mag::{mass::kg, slice::binary_search_quantity};
+///
+/// // lower bounds of weight bands
+/// let bands = [0.0 * kg, 2.0 * kg, 10.0 * kg, 30.0 * kg];
+///
+/// assert_eq!(binary_search_quantity(&bands, 10.0 * kg), Ok(2));
+/// assert_eq!(binary_search_quantity(&bands, 12.5 * kg), Err(3));
+/// ```
+pub fn binary_search_quantity<Q>(sorted: &[Q], key: Q) -> Result<usize, usize>
+where
+    Q: Magnitude,
+{
+    let key = key.magnitude();
+    sorted.binary_search_by(|q| q.magnitude().total_cmp(&key))
+}
+
+/// Check whether a slice of quantities is sorted in ascending order.
+///
+/// Quantities are compared with [f64::total_cmp] on their magnitudes.
+///
+/// ```rust
+/// use mag::{length::mi, slice::is_sorted_quantity, time::h};
+///
+/// let tiers = [25.0 * mi / h, 45.0 * mi / h, 65.0 * mi / h];
+///
+/// assert!(is_sorted_quantity(&tiers));
+/// assert!(!is_sorted_quantity(&[tiers[1], tiers[0]]));
+/// ```
+pub fn is_sorted_quantity<Q>(items: &[Q]) -> bool
+where
+    Q: Magnitude,
+{
+    items.windows(2).all(|w| {
+        w[0].magnitude().total_cmp(&w[1].magnitude()) != Ordering::Greater
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{km, m};
+    use crate::mass::g;
+    use crate::quan::Quantity;
+    use crate::temp::{DegC, DegK};
+    use crate::time::{h, ms, s};
+    use crate::{Length, Period, Speed};
+
+    #[test]
+    fn dedup() {
+        let mut t = [0.0 * s, 0.1 * s, 0.2 * s, 0.3 * s, 0.4 * s];
+        assert_eq!(dedup_within(&mut t, 0.25 * s), [0.0 * s, 0.3 * s]);
+        let mut t = [5.0 * g, 5.0 * g, 5.0 * g];
+        assert_eq!(dedup_within(&mut t, 0.0 * g), [5.0 * g]);
+        let mut t = [1.0 * g, 2.0 * g, 1.0 * g];
+        assert_eq!(dedup_within(&mut t, 0.5 * g), [1.0 * g, 2.0 * g, 1.0 * g]);
+        let mut t: [crate::Period<s>; 0] = [];
+        assert!(dedup_within(&mut t, 1.0 * s).is_empty());
+    }
+
+    #[test]
+    fn search() {
+        let t = [1.0 * s, 2.0 * s, 2.0 * s, 5.0 * s];
+        assert_eq!(binary_search_quantity(&t, 5.0 * s), Ok(3));
+        assert_eq!(binary_search_quantity(&t, 0.5 * s), Err(0));
+        assert_eq!(binary_search_quantity(&t, 9.0 * s), Err(4));
+        assert!(binary_search_quantity(&t, 2.0 * s).is_ok());
+        let t: [crate::Period<s>; 0] = [];
+        assert_eq!(binary_search_quantity(&t, 1.0 * s), Err(0));
+    }
+
+    #[test]
+    fn sorted() {
+        assert!(is_sorted_quantity(&[1.0 * g, 1.0 * g, 3.0 * g]));
+        assert!(!is_sorted_quantity(&[1.0 * g, 3.0 * g, 2.0 * g]));
+        assert!(is_sorted_quantity::<crate::Period<s>>(&[]));
+        #[cfg(not(feature = "assert-finite"))]
+        assert!(!is_sorted_quantity(&[f64::NAN * g, 1.0 * g]));
+    }
+
+    #[test]
+    fn convert() {
+        let mut v = [1.0, 2.0, 0.5];
+        convert_slice::<Period<s>, Period<ms>>(&mut v);
+        assert_eq!(v, [1_000.0, 2_000.0, 500.0]);
+        let mut v = [36.0, 72.0];
+        convert_slice::<Speed<km, h>, Speed<m, s>>(&mut v);
+        assert_eq!(v, [10.0, 20.0]);
+        let mut v = [0.0, -273.15];
+        convert_slice::<Quantity<DegC>, Quantity<DegK>>(&mut v);
+        assert_eq!(v, [273.15, 0.0]);
+        let mut v = [0.0, 100.0];
+        convert_slice::<Length<m>, Length<m>>(&mut v);
+        assert_eq!(v, [0.0, 100.0]);
+    }
+
+    #[test]
+    fn convert_typed() {
+        let src = [0.0 * DegC, -273.15 * DegC];
+        let mut dst = [0.0 * DegK; 2];
+        convert_quantities(&src, &mut dst);
+        assert_eq!(dst, [273.15 * DegK, 0.0 * DegK]);
+        let src = [1.5 * km, 0.25 * km];
+        let mut dst = [0.0 * m; 2];
+        convert_quantities(&src, &mut dst);
+        assert_eq!(dst, [1_500.0 * m, 250.0 * m]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_len() {
+        let mut dst = [0.0 * m; 1];
+        convert_quantities(&[1.0 * km, 2.0 * km], &mut dst);
+    }
+
+    #[test]
+    fn summary() {
+        let t = [3.0 * s, 1.0 * s, 2.0 * s];
+        let sum = summarize(&t).unwrap();
+        assert_eq!(sum.count, 3);
+        assert_eq!(sum.sum, 6.0 * s);
+        assert_eq!((sum.min, sum.max), (1.0 * s, 3.0 * s));
+        assert_eq!(sum.mean(), 2.0 * s);
+        assert_eq!(summarize::<Period<s>>(&[]), None);
+        let one = summarize(&[-4.0 * g]).unwrap();
+        assert_eq!(
+            (one.min, one.max, one.mean()),
+            (-4.0 * g, -4.0 * g, -4.0 * g)
+        );
+    }
+}
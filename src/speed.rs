@@ -3,12 +3,40 @@
 // Copyright (C) 2019-2021  Minnesota Department of Transportation
 // Copyright (C) 2019-2022  Douglas P Lau
 //
-//! Private module for speed structs
+//! Speed structs and composite units
 //!
-use crate::{length, time};
+//! Speeds are usually created by dividing a length by a time unit, such as
+//! `55.0 * mi / h`, which makes a `Speed<mi, h>`.  Common combinations also
+//! have composite [Unit]s, which can be used directly: `55.0 * mph` makes a
+//! `Speed<mph>`, displayed with the composite label.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::length::{km, mi};
+//! use mag::speed::{kph, mph};
+//! use mag::time::h;
+//! use mag::Speed;
+//!
+//! let a: Speed<mph> = 60.0 * mph;
+//! let b = 100 * kph;
+//!
+//! assert_eq!(a.to::<mi, h>(), 60.0 * mi / h);
+//! assert_eq!(b.to::<km, h>(), 100.0 * km / h);
+//! assert_eq!(a.to_string(), "60 mph");
+//! assert_eq!(format!("{:.1}", b.to::<mph, ()>()), "62.1 mph");
+//! ```
+use crate::quan::{
+    fmt_quantity, parse_quantity, Label, Magnitude, ParseError, Scalable,
+};
+use crate::{length, time, Frequency, Length};
 use core::fmt;
+use core::iter::Sum;
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Sub};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
 
 /// Quantity of _speed_.
 ///
@@ -24,7 +52,18 @@ use core::ops::{Add, Div, Mul, Sub};
 /// * Speed `*` f64 `=>` Speed
 /// * f64 `*` Speed `=>` Speed
 /// * i32 `*` Speed `=>` Speed
+/// * u32 `*` Speed `=>` Speed
+/// * Speed `*` u32 `=>` Speed
 /// * Speed `/` f64 `=>` Speed
+/// * Speed `/` Speed `=>` [Ratio]
+/// * Speed `+=` Speed
+/// * Speed `-=` Speed
+/// * Speed `*=` f64
+/// * Speed `/=` f64
+/// * Speed `/` [time unit] `=>` [Acceleration]
+/// * Speed `/` [Period] `=>` [Acceleration]
+/// * Speed `/` [Frequency] `=>` [Length]
+/// * Speed `/` [Length] `=>` [Frequency]
 ///
 /// Units must be the same for operations with two Speed operands.  The [to]
 /// method can be used for conversion.
@@ -32,28 +71,32 @@ use core::ops::{Add, Div, Mul, Sub};
 /// ## Example
 ///
 /// ```rust
-/// use mag::{Speed, length::{m, mi}, time::{h, s}};
+/// use mag::{Speed, length::{m, mi}, speed::mph, time::{h, s}};
 ///
 /// let a = 7.4 * m / s;
 /// let b = 55 * mi / h;
+/// let c = 60.0 * mph;
 ///
 /// assert_eq!(a.to_string(), "7.4 m/s");
 /// assert_eq!(b.to_string(), "55 mi/h");
+/// assert_eq!(format!("{}", c), "60 mph");
 /// ```
+/// [Acceleration]: struct.Acceleration.html
 /// [Frequency]: struct.Frequency.html
 /// [Length]: struct.Length.html
 /// [Period]: struct.Period.html
+/// [Ratio]: ratio/struct.Ratio.html
 /// [length unit]: length/index.html
 /// [time unit]: time/index.html
 /// [to]: struct.Speed.html#method.to
 ///
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-pub struct Speed<L, P>
+pub struct Speed<L, P = ()>
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     /// Speed quantity
+    #[deprecated(note = "use the `quantity()` method")]
     pub quantity: f64,
 
     /// Length unit
@@ -63,111 +106,518 @@ where
     period: PhantomData<P>,
 }
 
-// Speed + Speed => Speed
-impl<L, P> Add for Speed<L, P>
+/// Speed in _knots_ (nautical miles per hour).
+///
+/// Knots are displayed as `NM/h`.
+///
+/// ```rust
+/// use mag::{length::{km, NM}, time::h, Knots};
+///
+/// let a: Knots = 12.0 * NM / h;
+///
+/// assert_eq!(a.to_string(), "12 NM/h");
+/// assert_eq!(a.to(), 22.224 * km / h);
+/// ```
+pub type Knots = Speed<length::NM, time::h>;
+
+/// Composite speed unit, such as [mph]
+///
+/// A composite unit combines a length unit and a time unit, with its own
+/// label.  Multiplying a number by one creates a `Speed<unit>`, which is
+/// displayed with the composite label.  Generic code can name a speed with
+/// one type parameter, as `Speed<U>`.
+pub trait Unit {
+    /// Length unit
+    type Length: length::Unit;
+
+    /// Time unit
+    type Time: time::Unit;
+
+    /// Unit label
+    const LABEL: &'static str;
+}
+
+/// Units of a [Speed]
+///
+/// This is implemented for a composite [Unit] with `()`, as in `Speed<mph>`,
+/// and for a length unit with a time unit, as in `Speed<mi, h>`.
+pub trait Units {
+    /// Length unit
+    type Length: length::Unit;
+
+    /// Time unit
+    type Time: time::Unit;
+
+    /// Write the unit label
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result;
+}
+
+impl<L, P> Units for (L, P)
 where
     L: length::Unit,
     P: time::Unit,
+{
+    type Length = L;
+    type Time = P;
+
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(L::LABEL)?;
+        w.write_char('/')?;
+        w.write_str(P::LABEL)
+    }
+}
+
+impl<U> Units for (U, ())
+where
+    U: Unit,
+{
+    type Length = U::Length;
+    type Time = U::Time;
+
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::LABEL)
+    }
+}
+
+/// Define a custom unit for a derived measure.
+///
+/// * `unit` Unit struct name
+/// * `label` Standard unit label
+/// * `measure` A derived measure (currently `Speed`)
+/// * `composition` Length and time units, such as `NM / h`
+///
+/// A `Speed` unit is a composite [speed::Unit](speed/trait.Unit.html).
+/// Multiplying a number by it creates a `Speed<unit>`, which is displayed
+/// with the unit label.  Derived measures of [Quantity], such as `Pressure`,
+/// use [declare_unit] with a conversion factor instead.
+///
+/// ```rust
+/// use mag::{declare_derived_unit, length::{ft, m}, time::min};
+///
+/// declare_derived_unit!(
+///     /** Feet per minute */
+///     fpm,
+///     "ft/min",
+///     Speed,
+///     ft / min,
+/// );
+///
+/// let a = 500.0 * fpm;
+///
+/// assert_eq!(a.to_string(), "500 ft/min");
+/// assert_eq!(a.to(), 152.4 * m / min);
+/// ```
+///
+/// [Quantity]: quan/struct.Quantity.html
+/// [declare_unit]: macro.declare_unit.html
+#[macro_export]
+macro_rules! declare_derived_unit {
+    ($(#[$doc:meta])*
+        $unit:ident,
+        $label:expr,
+        Speed,
+        $($len:ident)::+ / $($per:ident)::+ $(,)?
+    ) => {
+        $(#[$doc])*
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $unit;
+
+        impl $crate::speed::Unit for $unit {
+            type Length = $($len)::+;
+            type Time = $($per)::+;
+            const LABEL: &'static str = $label;
+        }
+
+        // scalar * <unit> => Speed
+        $crate::impl_scalar_mul!($unit => $crate::Speed<$unit>);
+
+        // "<label>" => <unit>, for quantity!
+        impl $crate::lit::Lookup<$unit>
+            for $crate::lit::Label<{ $crate::lit::hash($label) }>
+        {
+            type Part = $unit;
+            const PART: $unit = $unit;
+        }
+    };
+}
+
+declare_derived_unit!(
+    /** Miles per hour (`mi/h`) */
+    mph,
+    "mph",
+    Speed,
+    length::mi / time::h,
+);
+
+declare_derived_unit!(
+    /** Kilometers per hour (`km/h`) */
+    kph,
+    "km/h",
+    Speed,
+    length::km / time::h,
+);
+
+declare_derived_unit!(
+    /** Meters per second (`m/s`) */
+    mps,
+    "m/s",
+    Speed,
+    length::m / time::s,
+);
+
+declare_derived_unit!(
+    /** Feet per second (`ft/s`) */
+    fps,
+    "ft/s",
+    Speed,
+    length::ft / time::s,
+);
+
+declare_derived_unit!(
+    /** Knot (nautical miles per hour) */
+    knot,
+    "kn",
+    Speed,
+    length::NM / time::h,
+);
+
+// Speed + Speed => Speed
+impl<L, P> Add for Speed<L, P>
+where
+    (L, P): Units,
 {
     type Output = Self;
     fn add(self, other: Self) -> Self::Output {
-        Self::new(self.quantity + other.quantity)
+        Self::new(self.quantity() + other.quantity())
     }
 }
 
 // Speed - Speed => Speed
 impl<L, P> Sub for Speed<L, P>
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     type Output = Self;
     fn sub(self, other: Self) -> Self::Output {
-        Self::new(self.quantity - other.quantity)
+        Self::new(self.quantity() - other.quantity())
+    }
+}
+
+// Sum of Speed => Speed
+impl<L, P> Sum for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::new(0.0), |a, b| a + b)
     }
 }
 
 // Speed * f64 => Speed
 impl<L, P> Mul<f64> for Speed<L, P>
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     type Output = Self;
     fn mul(self, scalar: f64) -> Self::Output {
-        Self::new(self.quantity * scalar)
+        Self::new(self.quantity() * scalar)
     }
 }
 
 // f64 * Speed => Speed
 impl<L, P> Mul<Speed<L, P>> for f64
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     type Output = Speed<L, P>;
     fn mul(self, other: Speed<L, P>) -> Self::Output {
-        Speed::new(self * other.quantity)
+        Speed::new(self * other.quantity())
     }
 }
 
 // i32 * Speed => Speed
 impl<L, P> Mul<Speed<L, P>> for i32
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
+{
+    type Output = Speed<L, P>;
+    fn mul(self, other: Speed<L, P>) -> Self::Output {
+        Speed::new(f64::from(self) * other.quantity())
+    }
+}
+
+// Speed * u32 => Speed
+impl<L, P> Mul<u32> for Speed<L, P>
+where
+    (L, P): Units,
+{
+    type Output = Self;
+    fn mul(self, count: u32) -> Self::Output {
+        Self::new(self.quantity() * f64::from(count))
+    }
+}
+
+// u32 * Speed => Speed
+impl<L, P> Mul<Speed<L, P>> for u32
+where
+    (L, P): Units,
 {
     type Output = Speed<L, P>;
     fn mul(self, other: Speed<L, P>) -> Self::Output {
-        Speed::new(f64::from(self) * other.quantity)
+        Speed::new(f64::from(self) * other.quantity())
     }
 }
 
 // Speed / f64 => Speed
 impl<L, P> Div<f64> for Speed<L, P>
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     type Output = Self;
     fn div(self, scalar: f64) -> Self::Output {
-        Self::new(self.quantity / scalar)
+        Self::new(self.quantity() / scalar)
+    }
+}
+
+// Speed += Speed
+impl<L, P> AddAssign for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() + other.quantity());
     }
 }
 
+// Speed -= Speed
+impl<L, P> SubAssign for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = Self::new(self.quantity() - other.quantity());
+    }
+}
+
+// Speed *= f64
+impl<L, P> MulAssign<f64> for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() * scalar);
+    }
+}
+
+// Speed /= f64
+impl<L, P> DivAssign<f64> for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn div_assign(&mut self, scalar: f64) {
+        *self = Self::new(self.quantity() / scalar);
+    }
+}
+
+// Speed / Frequency => Length
+impl<L, P> Div<Frequency<<(L, P) as Units>::Time>> for Speed<L, P>
+where
+    (L, P): Units,
+{
+    type Output = Length<<(L, P) as Units>::Length>;
+    fn div(self, freq: Frequency<<(L, P) as Units>::Time>) -> Self::Output {
+        Length::new(self.quantity() / freq.quantity())
+    }
+}
+
+// Speed / Length => Frequency
+impl<L, P> Div<Length<<(L, P) as Units>::Length>> for Speed<L, P>
+where
+    (L, P): Units,
+{
+    type Output = Frequency<<(L, P) as Units>::Time>;
+    fn div(self, len: Length<<(L, P) as Units>::Length>) -> Self::Output {
+        Frequency::new(self.quantity() / len.quantity())
+    }
+}
+
+impl_base_ops!(@access Speed<L, P> where (L, P): Units);
+
 impl<L, P> Speed<L, P>
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     /// Create a new speed quantity
-    pub fn new(quantity: f64) -> Self {
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
         Speed::<L, P> {
-            quantity,
+            quantity: crate::check_finite(quantity),
             length: PhantomData,
             period: PhantomData,
         }
     }
 
+    /// Multiply by an item count.
+    ///
+    /// Counts above 2⁵³ are rounded to the nearest `f64`.
+    pub fn times(self, count: u64) -> Self {
+        Self::new(self.quantity() * count as f64)
+    }
+
+    /// Quantize to an integer multiple of a scale step.
+    ///
+    /// Returns the nearest step count and the residual error (the quantity
+    /// minus the quantized value).
+    ///
+    /// ```rust
+    /// use mag::{length::km, time::h, Speed};
+    ///
+    /// let step = Speed::<km, h>::new(0.5);
+    /// let (count, err) = Speed::<km, h>::new(88.3).quantize(step);
+    /// assert_eq!(count, 177);
+    /// assert_eq!(err, Speed::new(-0.20000000000000284));
+    /// ```
+    pub fn quantize(self, scale: Self) -> (i64, Self) {
+        let (count, err) = crate::quantize(self.quantity(), scale.quantity());
+        (count, Self::new(err))
+    }
+
+    /// Change the units **without converting** the quantity.
+    ///
+    /// This is an escape hatch for cases where the quantity is already known
+    /// to be in the target units, such as at an FFI boundary.  Use [to] for
+    /// conversion.
+    ///
+    /// [to]: #method.to
+    pub fn reinterpret_unit<N, R>(self) -> Speed<N, R>
+    where
+        (N, R): Units,
+    {
+        Speed::new(self.quantity())
+    }
+
     /// Convert to specified units
-    pub fn to<N, R>(self) -> Speed<N, R>
+    ///
+    /// ```rust
+    /// use mag::{length::{ft, mi}, speed::mph, time::{h, s}, Speed};
+    ///
+    /// let a = 88.0 * ft / s;
+    ///
+    /// assert_eq!(format!("{:.0}", a.to::<mi, h>()), "60 mi/h");
+    /// let b: Speed<mph> = a.to();
+    /// assert_eq!(format!("{:.0}", b), "60 mph");
+    /// ```
+    pub const fn to<N, R>(self) -> Speed<N, R>
+    where
+        (N, R): Units,
+    {
+        let factor = <<(L, P) as Units>::Length as length::Factor<
+            <(N, R) as Units>::Length,
+        >>::FACTOR
+            / <<(L, P) as Units>::Time as time::Factor<
+                <(N, R) as Units>::Time,
+            >>::FACTOR;
+        Speed::new(self.quantity() * factor)
+    }
+
+    /// Convert to SI base units (`m/s`)
+    pub const fn to_base(self) -> Speed<length::m, time::s> {
+        self.to::<length::m, time::s>()
+    }
+
+    /// Convert to a composite [Unit], which is displayed with its label
+    ///
+    /// ```rust
+    /// use mag::{length::m, speed::{kph, mph}, time::s};
+    ///
+    /// let a = 10.0 * m / s;
+    ///
+    /// assert_eq!(a.display_as::<kph>().to_string(), "36 km/h");
+    /// assert_eq!(format!("{:.2}", a.display_as::<mph>()), "22.37 mph");
+    /// ```
+    pub const fn display_as<A>(self) -> Speed<A>
+    where
+        A: Unit,
+    {
+        self.to::<A, ()>()
+    }
+
+    /// Convert to a different length unit, keeping the time unit
+    ///
+    /// ```rust
+    /// use mag::{length::{km, mi}, time::h};
+    ///
+    /// let a = 100.0 * km / h;
+    /// assert_eq!(format!("{:.2}", a.to_length::<mi>()), "62.14 mi/h");
+    /// ```
+    pub const fn to_length<N>(self) -> Speed<N, <(L, P) as Units>::Time>
     where
         N: length::Unit,
+    {
+        self.to::<N, <(L, P) as Units>::Time>()
+    }
+
+    /// Convert to a different time unit, keeping the length unit
+    ///
+    /// ```rust
+    /// use mag::{length::km, time::{h, s}};
+    ///
+    /// let a = 36.0 * km / h;
+    /// assert_eq!(a.to_period::<s>(), 0.01 * km / s);
+    /// ```
+    pub const fn to_period<R>(self) -> Speed<<(L, P) as Units>::Length, R>
+    where
         R: time::Unit,
     {
-        let factor = L::factor::<N>() / P::factor::<R>();
-        Speed::new(self.quantity * factor)
+        self.to::<<(L, P) as Units>::Length, R>()
+    }
+}
+
+impl<L, P> Magnitude for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn magnitude(&self) -> f64 {
+        self.quantity()
+    }
+
+    fn from_magnitude(magnitude: f64) -> Self {
+        Self::new(magnitude)
+    }
+}
+
+impl<L, P> Scalable for Speed<L, P> where (L, P): Units {}
+
+impl<L, P> Label for Speed<L, P>
+where
+    (L, P): Units,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        <(L, P) as Units>::write_label(w)
     }
 }
 
 impl<L, P> fmt::Display for Speed<L, P>
 where
-    L: length::Unit,
-    P: time::Unit,
+    (L, P): Units,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.quantity.fmt(f)?;
-        write!(f, " {}/{}", L::LABEL, P::LABEL)
+        fmt_quantity(self, f)
+    }
+}
+
+impl<L, P> FromStr for Speed<L, P>
+where
+    (L, P): Units,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_quantity(s)
     }
 }
 
@@ -181,6 +631,59 @@ mod test {
     use alloc::format;
     use alloc::string::ToString;
 
+    #[test]
+    fn speed_composite() {
+        assert_eq!(60.0 * mph, Speed::<mph>::new(60.0));
+        assert_eq!((60.0 * mph).to::<mi, h>(), 60.0 * mi / h);
+        assert_eq!((50 * kph).to::<km, h>(), 50.0 * km / h);
+        assert_eq!((9.8 * mps).to::<m, s>(), 9.8 * m / s);
+        assert_eq!((30 * mph).to_string(), "30 mph");
+        assert_eq!(format!("{}", 60.0 * mph), "60 mph");
+        assert_eq!((36.0 * kph).display_as::<mps>().to_string(), "10 m/s");
+        assert_eq!(
+            format!("{:.1}", (100.0 * kph).display_as::<mph>()),
+            "62.1 mph"
+        );
+        assert_eq!((100.0 * km / h).to_string(), "100 km/h");
+        assert_eq!(crate::quantity!("25 mph"), 25.0 * mph);
+        assert_eq!(crate::quantity!("25 mi/h"), 25.0 * mi / h);
+    }
+
+    #[test]
+    fn speed_composite_display() {
+        assert_eq!(format!("{}", 60.0 * mph), "60 mph");
+        assert_eq!(format!("{:.1}", 88.0 * kph), "88.0 km/h");
+        assert_eq!(format!("{}", 9.8 * mps), "9.8 m/s");
+        assert_eq!(format!("{}", 30 * fps), "30 ft/s");
+        assert_eq!(format!("{}", 12.0 * knot), "12 kn");
+        assert_eq!(format!("{}", (60.0 * mph).to::<mi, h>()), "60 mi/h");
+        assert_eq!("60 mph".parse::<Speed<mph>>(), Ok(60.0 * mph));
+        assert_eq!("12 kn".parse::<Speed<knot>>(), Ok(12.0 * knot));
+    }
+
+    #[test]
+    fn speed_single_param() {
+        fn to_base<U: super::Unit>(v: Speed<U>) -> Speed<mps> {
+            v.to()
+        }
+        assert_eq!(to_base::<kph>(36.0 * kph), 10.0 * mps);
+        assert_eq!(to_base::<knot>(1.0 * knot), (1_852.0 / 3_600.0) * mps);
+        assert_eq!((88.0 * fps).to::<ft, s>(), 88.0 * ft / s);
+        assert_eq!((12.0 * knot).to_string(), "12 kn");
+        assert_eq!((12.0 * NM / h).display_as::<knot>().to_string(), "12 kn");
+    }
+
+    #[test]
+    fn speed_cycles() {
+        // wheel circumference from speed and rotation rate
+        let rpm = 300.0 / min;
+        let circ = (36.0 * km / min) / rpm;
+        assert_eq!(circ, 0.12 * km);
+        assert_eq!((36.0 * km / min) / circ, rpm);
+        assert_eq!((10.0 * m / s) / (2.0 / s), 5.0 * m);
+        assert_eq!((10.0 * m / s) / (4.0 * m), 2.5 / s);
+    }
+
     #[test]
     fn speed_display() {
         assert_eq!((23.4 * m / s).to_string(), "23.4 m/s");
@@ -193,6 +696,8 @@ mod test {
     fn speed_to() {
         assert_eq!((88.0 * ft / s).to(), 59.99999999999999 * mi / h);
         assert_eq!((55.0 * mi / h).to(), 88.51392000000001 * km / h);
+        assert_eq!((3.0 * km / h).to_length(), 3_000.0 * m / h);
+        assert_eq!((120.0 * m / min).to_period(), 2.0 * m / s);
     }
 
     #[test]
@@ -211,6 +716,9 @@ mod test {
     fn speed_mul() {
         assert_eq!((5.1 * In / s) * 2.0, 10.2 * In / s);
         assert_eq!(3.0 * (10.5 * mi / us), 31.5 * mi / us);
+        assert_eq!(4u32 * (2.5 * m / s), 10.0 * m / s);
+        assert_eq!((2.5 * m / s) * 4u32, 10.0 * m / s);
+        assert_eq!((2.5 * m / s).times(4), 10.0 * m / s);
         // Length * Frequency => Speed
         assert_eq!((15.0 * m) * (3.0 / ds), 45.0 * m / ds);
         // Frequency * Length => Speed
@@ -224,4 +732,32 @@ mod test {
         // Length / Period => Speed
         assert_eq!((45.5 * km) / (1.0 * h), Speed::<km, h>::new(45.5));
     }
+
+    #[test]
+    fn speed_assign() {
+        let mut a = 10.0 * m / s;
+        a += 5.0 * m / s;
+        a -= 3.0 * m / s;
+        a *= 2.0;
+        a /= 8.0;
+        assert_eq!(a, 3.0 * m / s);
+    }
+
+    #[test]
+    fn speed_parse() {
+        assert_eq!("55 mi/h".parse(), Ok(55.0 * mi / h));
+        assert_eq!("7.4 m/s".parse::<Speed<m, s>>(), Ok(7.4 * m / s));
+        assert!("7.4 m/h".parse::<Speed<m, s>>().is_err());
+    }
+
+    #[test]
+    fn speed_reinterpret() {
+        assert_eq!((30.0 * mi / h).reinterpret_unit(), 30.0 * km / h);
+    }
+
+    #[test]
+    fn speed_base() {
+        assert_eq!((36.0 * km / h).to_base(), 10.0 * m / s);
+        assert_eq!((3.0 * m / ms).to_base(), 3_000.0 * m / s);
+    }
 }
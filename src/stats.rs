@@ -0,0 +1,186 @@
+// stats.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Statistics on quantities
+//!
+//! The [Stats] extension trait adds statistical methods to any iterator of
+//! quantities, returning typed results.  All methods return `None` when there
+//! are not enough quantities.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::m, stats::Stats};
+//!
+//! let heights = [1.62 * m, 1.75 * m, 1.81 * m, 1.70 * m];
+//! let mean = heights.into_iter().mean().unwrap();
+//! let sd = heights.into_iter().stddev().unwrap();
+//!
+//! assert_eq!(format!("{:.3}", mean), "1.720 m");
+//! assert_eq!(format!("{:.3}", sd), "0.080 m");
+//! assert_eq!(heights.into_iter().minmax(), Some((1.62 * m, 1.81 * m)));
+//! ```
+use crate::quan::Magnitude;
+use crate::sqrt;
+
+/// Statistical methods for iterators of quantities
+///
+/// This is implemented for all iterators over quantities.  For an iterator
+/// over references, use `copied()` first.
+pub trait Stats<Q>: Iterator<Item = Q> + Sized
+where
+    Q: Magnitude,
+{
+    /// Calculate the arithmetic mean
+    fn mean(self) -> Option<Q> {
+        let mut count = 0;
+        let mut sum = 0.0;
+        for q in self {
+            count += 1;
+            sum += q.magnitude();
+        }
+        (count > 0).then(|| Q::from_magnitude(sum / count as f64))
+    }
+
+    /// Calculate the sample standard deviation
+    ///
+    /// This uses Welford's algorithm in a single pass, with Bessel's
+    /// correction (dividing by `n - 1`).  At least two quantities are needed.
+    fn stddev(self) -> Option<Q> {
+        let mut count = 0;
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        for q in self {
+            let v = q.magnitude();
+            count += 1;
+            let delta = v - mean;
+            mean += delta / count as f64;
+            m2 += delta * (v - mean);
+        }
+        (count > 1).then(|| Q::from_magnitude(sqrt(m2 / (count - 1) as f64)))
+    }
+
+    /// Find the minimum and maximum quantities
+    ///
+    /// NaN magnitudes are ignored, unless all quantities are NaN.
+    fn minmax(self) -> Option<(Q, Q)> {
+        let mut it = self.map(|q| q.magnitude());
+        let first = it.next()?;
+        let (min, max) =
+            it.fold((first, first), |(min, max), v| (min.min(v), max.max(v)));
+        Some((Q::from_magnitude(min), Q::from_magnitude(max)))
+    }
+
+    /// Calculate the median
+    ///
+    /// This requires the `std` feature, since the quantities are collected
+    /// into a buffer.  See [median] for a version which works on a slice.
+    #[cfg(feature = "std")]
+    fn median(self) -> Option<Q> {
+        let mut buf: std::vec::Vec<Q> = self.collect();
+        median(&mut buf)
+    }
+}
+
+impl<I, Q> Stats<Q> for I
+where
+    I: Iterator<Item = Q>,
+    Q: Magnitude,
+{
+}
+
+/// Calculate the median of a slice of quantities.
+///
+/// The slice is partially reordered.  For an even number of quantities, the
+/// mean of the two middle values is returned.  Quantities are compared with
+/// [f64::total_cmp] on their magnitudes.
+///
+/// ```rust
+/// use mag::{stats::median, time::s};
+///
+/// let mut laps = [62.0 * s, 58.5 * s, 60.0 * s, 75.0 * s];
+///
+/// assert_eq!(median(&mut laps), Some(61.0 * s));
+/// ```
+pub fn median<Q>(items: &mut [Q]) -> Option<Q>
+where
+    Q: Magnitude,
+{
+    let len = items.len();
+    if len == 0 {
+        return None;
+    }
+    let mid = len / 2;
+    let (lower, upper, _) = items.select_nth_unstable_by(mid, |a, b| {
+        a.magnitude().total_cmp(&b.magnitude())
+    });
+    let upper = upper.magnitude();
+    if len % 2 == 1 {
+        return Some(Q::from_magnitude(upper));
+    }
+    let lower = lower
+        .iter()
+        .map(Magnitude::magnitude)
+        .max_by(f64::total_cmp)?;
+    Some(Q::from_magnitude((lower + upper) / 2.0))
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::mass::kg;
+    use crate::temp::DegC;
+    use crate::time::s;
+    use crate::Period;
+    use alloc::format;
+
+    #[test]
+    fn mean() {
+        let t = [1.0 * s, 2.0 * s, 6.0 * s];
+        assert_eq!(t.iter().copied().mean(), Some(3.0 * s));
+        assert_eq!(t.iter().copied().mean(), Some(3.0 * s));
+        assert_eq!(
+            [20.0 * DegC, 22.0 * DegC].into_iter().mean(),
+            Some(21.0 * DegC)
+        );
+        assert_eq!(core::iter::empty::<Period<s>>().mean(), None);
+    }
+
+    #[test]
+    fn stddev() {
+        let m = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].map(|v| v * kg);
+        let sd = m.into_iter().stddev().unwrap();
+        assert_eq!(format!("{sd:.6}"), "2.138090 kg");
+        assert_eq!([3.0 * kg].into_iter().stddev(), None);
+        assert_eq!([3.0 * kg, 3.0 * kg].into_iter().stddev(), Some(0.0 * kg));
+    }
+
+    #[test]
+    fn minmax() {
+        let t = [4.0 * s, -1.0 * s, 9.0 * s, 2.0 * s];
+        assert_eq!(t.into_iter().minmax(), Some((-1.0 * s, 9.0 * s)));
+        assert_eq!([5.0 * s].into_iter().minmax(), Some((5.0 * s, 5.0 * s)));
+        assert_eq!(core::iter::empty::<Period<s>>().minmax(), None);
+    }
+
+    #[test]
+    fn median_slice() {
+        let mut t = [3.0 * s, 1.0 * s, 2.0 * s];
+        assert_eq!(median(&mut t), Some(2.0 * s));
+        let mut t = [4.0 * kg, 1.0 * kg, 3.0 * kg, 2.0 * kg];
+        assert_eq!(median(&mut t), Some(2.5 * kg));
+        let mut t: [Period<s>; 0] = [];
+        assert_eq!(median(&mut t), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn median_iter() {
+        let t = [9.0 * s, 1.0 * s, 5.0 * s, 7.0 * s];
+        assert_eq!(t.into_iter().median(), Some(6.0 * s));
+        assert_eq!(core::iter::empty::<Period<s>>().median(), None);
+    }
+}
@@ -0,0 +1,187 @@
+// step.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Stepping through ranges of quantities
+//!
+//! The [StepByQuantity] trait is implemented for ranges (`a..b` and `a..=b`)
+//! of any quantity type, including temperatures.  Each value is calculated
+//! as `start + i * step`, so rounding errors do not accumulate.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::m, step::StepByQuantity, temp::DegC};
+//!
+//! let sweep: Vec<_> = (0.0 * m..2.0 * m).step_by_quantity(0.5 * m).collect();
+//! assert_eq!(sweep, [0.0 * m, 0.5 * m, 1.0 * m, 1.5 * m]);
+//!
+//! let temps = (20.0 * DegC..=30.0 * DegC).step_by_quantity(5.0 * DegC);
+//! assert_eq!(temps.count(), 3);
+//! ```
+use crate::quan::Magnitude;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Range, RangeInclusive};
+
+/// Iterator of quantities stepping through a range
+///
+/// Created by [step_by_quantity](StepByQuantity::step_by_quantity).
+#[derive(Clone, Debug)]
+pub struct Steps<Q>
+where
+    Q: Magnitude,
+{
+    /// Starting magnitude
+    start: f64,
+
+    /// Ending magnitude
+    end: f64,
+
+    /// Step magnitude
+    step: f64,
+
+    /// Include end point
+    inclusive: bool,
+
+    /// Index of next step
+    index: u64,
+
+    /// Done flag
+    done: bool,
+
+    /// Quantity type
+    quan: PhantomData<Q>,
+}
+
+/// Ranges which can be stepped through by a quantity
+pub trait StepByQuantity<Q>
+where
+    Q: Magnitude,
+{
+    /// Step through the range by a quantity
+    ///
+    /// A negative step counts down, from a start above the end.  If the step
+    /// does not move from the start toward the end, the iterator is empty.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the step is zero or NaN.
+    fn step_by_quantity(self, step: Q) -> Steps<Q>;
+}
+
+impl<Q> Steps<Q>
+where
+    Q: Magnitude,
+{
+    /// Create a new stepping iterator
+    fn new(start: Q, end: Q, step: Q, inclusive: bool) -> Self {
+        let step = step.magnitude();
+        assert!(step != 0.0 && !step.is_nan(), "step must be non-zero");
+        Steps {
+            start: start.magnitude(),
+            end: end.magnitude(),
+            step,
+            inclusive,
+            index: 0,
+            done: false,
+            quan: PhantomData,
+        }
+    }
+}
+
+impl<Q> StepByQuantity<Q> for Range<Q>
+where
+    Q: Magnitude,
+{
+    fn step_by_quantity(self, step: Q) -> Steps<Q> {
+        Steps::new(self.start, self.end, step, false)
+    }
+}
+
+impl<Q> StepByQuantity<Q> for RangeInclusive<Q>
+where
+    Q: Magnitude,
+{
+    fn step_by_quantity(self, step: Q) -> Steps<Q> {
+        let (start, end) = self.into_inner();
+        Steps::new(start, end, step, true)
+    }
+}
+
+impl<Q> Iterator for Steps<Q>
+where
+    Q: Magnitude,
+{
+    type Item = Q;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let value = self.start + self.index as f64 * self.step;
+        let before = if self.step > 0.0 {
+            value < self.end
+        } else {
+            value > self.end
+        };
+        if before || (self.inclusive && value == self.end) {
+            self.index += 1;
+            Some(Q::from_magnitude(value))
+        } else {
+            self.done = true;
+            None
+        }
+    }
+}
+
+impl<Q> FusedIterator for Steps<Q> where Q: Magnitude {}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::length::{ft, m};
+    use crate::temp::DegF;
+    use crate::time::{ms, s};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn step_range() {
+        let t: Vec<_> = (0.0 * s..1.0 * s).step_by_quantity(0.25 * s).collect();
+        assert_eq!(t, [0.0 * s, 0.25 * s, 0.5 * s, 0.75 * s]);
+        let t: Vec<_> = (0.0 * s..1.1 * s).step_by_quantity(0.5 * s).collect();
+        assert_eq!(t, [0.0 * s, 0.5 * s, 1.0 * s]);
+        assert_eq!((5.0 * m..5.0 * m).step_by_quantity(1.0 * m).count(), 0);
+        assert_eq!((5.0 * m..=5.0 * m).step_by_quantity(1.0 * m).count(), 1);
+    }
+
+    #[test]
+    fn step_inclusive() {
+        let t: Vec<_> =
+            (0.0 * ms..=30.0 * ms).step_by_quantity(10.0 * ms).collect();
+        assert_eq!(t, [0.0 * ms, 10.0 * ms, 20.0 * ms, 30.0 * ms]);
+        let f: Vec<_> = (32.0 * DegF..=212.0 * DegF)
+            .step_by_quantity(90.0 * DegF)
+            .collect();
+        assert_eq!(f, [32.0 * DegF, 122.0 * DegF, 212.0 * DegF]);
+        // no accumulated error: 0.1 * 10 == 1.0
+        let n = (0.0 * ft..=1.0 * ft).step_by_quantity(0.1 * ft).count();
+        assert_eq!(n, 11);
+    }
+
+    #[test]
+    fn step_down() {
+        let t: Vec<_> = (3.0 * m..0.0 * m).step_by_quantity(-1.0 * m).collect();
+        assert_eq!(t, [3.0 * m, 2.0 * m, 1.0 * m]);
+        assert_eq!((0.0 * m..3.0 * m).step_by_quantity(-1.0 * m).count(), 0);
+        assert_eq!((3.0 * m..0.0 * m).step_by_quantity(1.0 * m).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_zero() {
+        let _ = (0.0 * m..1.0 * m).step_by_quantity(0.0 * m);
+    }
+}
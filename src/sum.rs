@@ -0,0 +1,156 @@
+// sum.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Compensated summation of quantities
+//!
+//! All quantity types implement [Sum], which adds values in order with plain
+//! `+`.  When adding a large number of small values, floating point error can
+//! accumulate; [KahanSum] keeps a running compensation term to avoid this.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::m, sum::KahanSum, Length};
+//!
+//! let segments = [0.1 * m; 10];
+//!
+//! let plain: Length<m> = segments.iter().copied().sum();
+//! assert_eq!(plain, 0.9999999999999999 * m);
+//!
+//! let kahan: KahanSum<_> = segments.iter().copied().collect();
+//! assert_eq!(kahan.total(), 1.0 * m);
+//! ```
+//!
+//! [Sum]: https://doc.rust-lang.org/core/iter/trait.Sum.html
+use crate::quan::Magnitude;
+use core::marker::PhantomData;
+
+/// Accumulator for compensated summation of quantities.
+///
+/// This uses the Kahan-Babuška (Neumaier) algorithm, which also handles terms
+/// larger than the running sum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KahanSum<Q>
+where
+    Q: Magnitude,
+{
+    /// Running sum
+    sum: f64,
+
+    /// Compensation for lost low-order bits
+    compensation: f64,
+
+    /// Quantity type
+    quan: PhantomData<Q>,
+}
+
+impl<Q> Default for KahanSum<Q>
+where
+    Q: Magnitude,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q> KahanSum<Q>
+where
+    Q: Magnitude,
+{
+    /// Create a new accumulator with zero total
+    pub fn new() -> Self {
+        KahanSum {
+            sum: 0.0,
+            compensation: 0.0,
+            quan: PhantomData,
+        }
+    }
+
+    /// Add a quantity to the sum
+    pub fn add(&mut self, quan: Q) {
+        let val = quan.magnitude();
+        let sum = self.sum + val;
+        if self.sum.abs() >= val.abs() {
+            self.compensation += (self.sum - sum) + val;
+        } else {
+            self.compensation += (val - sum) + self.sum;
+        }
+        self.sum = sum;
+    }
+
+    /// Get the total of all quantities added
+    pub fn total(&self) -> Q {
+        Q::from_magnitude(self.sum + self.compensation)
+    }
+}
+
+impl<Q> Extend<Q> for KahanSum<Q>
+where
+    Q: Magnitude,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Q>,
+    {
+        for quan in iter {
+            self.add(quan);
+        }
+    }
+}
+
+impl<Q> FromIterator<Q> for KahanSum<Q>
+where
+    Q: Magnitude,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Q>,
+    {
+        let mut sum = Self::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{km, m};
+    use crate::mass::g;
+    use crate::time::{h, s};
+    use crate::{Length, Period, Speed};
+
+    #[test]
+    fn sum() {
+        let a: Length<km> = [1.5 * km, 2.5 * km, 3.0 * km].into_iter().sum();
+        assert_eq!(a, 7.0 * km);
+        let b: Period<s> = [1.0 * s, 2.0 * s].into_iter().sum();
+        assert_eq!(b, 3.0 * s);
+        let c: Speed<km, h> = [5.0 * km / h, 6.0 * km / h].into_iter().sum();
+        assert_eq!(c, 11.0 * km / h);
+        let d = [1.0 * g, 2.0 * g]
+            .into_iter()
+            .sum::<crate::quan::Quantity<g>>();
+        assert_eq!(d, 3.0 * g);
+    }
+
+    #[test]
+    fn kahan() {
+        let mut sum = KahanSum::new();
+        for _ in 0..1_000_000 {
+            sum.add(0.001 * m);
+        }
+        assert_eq!(sum.total(), 1_000.0 * m);
+        let plain: Length<m> = core::iter::repeat_n(0.001 * m, 1_000_000).sum();
+        assert_ne!(plain, 1_000.0 * m);
+    }
+
+    #[test]
+    fn kahan_large_term() {
+        let vals = [1.0 * g, 1e100 * g, 1.0 * g, -1e100 * g];
+        let sum: KahanSum<_> = vals.into_iter().collect();
+        assert_eq!(sum.total(), 2.0 * g);
+        assert_eq!(KahanSum::<Length<m>>::default().total(), 0.0 * m);
+    }
+}
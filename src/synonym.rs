@@ -0,0 +1,361 @@
+// synonym.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Parsing human input with unit synonyms
+//!
+//! Quantity `FromStr` only accepts the exact labels used by `Display`.  A
+//! [Synonyms] table also accepts unit names, plurals, common abbreviations
+//! and symbols, mapping each to a canonical label.  Labels are matched
+//! without regard to ASCII case, and compound labels such as `meters/sec`
+//! are normalized on each side of the `/`.
+//!
+//! Applications can extend the [DEFAULT_SYNONYMS] with their own table.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{ft, In, km, m}, synonym::Synonyms, time::h, Length};
+//!
+//! let syn = Synonyms::default();
+//!
+//! assert_eq!(syn.parse::<Length<m>>("12 metres"), Ok(12.0 * m));
+//! assert_eq!(syn.parse::<Length<km>>("3.5 KM"), Ok(3.5 * km));
+//! assert_eq!(syn.parse::<Length<ft>>("6′"), Ok(6.0 * ft));
+//! assert_eq!(syn.parse("80 kph"), Ok(80.0 * km / h));
+//!
+//! let custom = Synonyms::new(&[("inch", "in"), ("zoll", "in")]);
+//! assert_eq!(custom.parse::<Length<In>>("2 Zoll"), Ok(2.0 * In));
+//! ```
+use crate::display::StackBuf;
+use crate::quan::{Label, ParseError};
+use core::fmt::Write;
+
+/// Default unit synonyms, as `(alias, label)` pairs
+pub const DEFAULT_SYNONYMS: &[(&str, &str)] = &[
+    // length
+    ("kilometer", "km"),
+    ("kilometers", "km"),
+    ("kilometre", "km"),
+    ("kilometres", "km"),
+    ("meter", "m"),
+    ("meters", "m"),
+    ("metre", "m"),
+    ("metres", "m"),
+    ("centimeter", "cm"),
+    ("centimeters", "cm"),
+    ("centimetre", "cm"),
+    ("centimetres", "cm"),
+    ("millimeter", "mm"),
+    ("millimeters", "mm"),
+    ("millimetre", "mm"),
+    ("millimetres", "mm"),
+    ("um", "μm"),
+    ("µm", "μm"),
+    ("micron", "μm"),
+    ("microns", "μm"),
+    ("mile", "mi"),
+    ("miles", "mi"),
+    ("foot", "ft"),
+    ("feet", "ft"),
+    ("′", "ft"),
+    ("'", "ft"),
+    ("inch", "in"),
+    ("inches", "in"),
+    ("″", "in"),
+    ("\"", "in"),
+    ("yard", "yd"),
+    ("yards", "yd"),
+    ("yds", "yd"),
+    ("nmi", "NM"),
+    ("light-year", "ly"),
+    ("light-years", "ly"),
+    ("parsec", "pc"),
+    ("parsecs", "pc"),
+    // time
+    ("week", "wk"),
+    ("weeks", "wk"),
+    ("wks", "wk"),
+    ("day", "d"),
+    ("days", "d"),
+    ("hour", "h"),
+    ("hours", "h"),
+    ("hr", "h"),
+    ("hrs", "h"),
+    ("minute", "min"),
+    ("minutes", "min"),
+    ("mins", "min"),
+    ("second", "s"),
+    ("seconds", "s"),
+    ("sec", "s"),
+    ("secs", "s"),
+    ("millisecond", "ms"),
+    ("milliseconds", "ms"),
+    ("msec", "ms"),
+    ("us", "μs"),
+    ("µs", "μs"),
+    ("microsecond", "μs"),
+    ("microseconds", "μs"),
+    ("nanosecond", "ns"),
+    ("nanoseconds", "ns"),
+    // speed
+    ("kph", "km/h"),
+    ("kmh", "km/h"),
+    ("mph", "mi/h"),
+    ("fps", "ft/s"),
+    ("kn", "NM/h"),
+    ("kt", "NM/h"),
+    ("knot", "NM/h"),
+    ("knots", "NM/h"),
+    // mass
+    ("tonne", "t"),
+    ("tonnes", "t"),
+    ("kilogram", "kg"),
+    ("kilograms", "kg"),
+    ("kgs", "kg"),
+    ("gram", "g"),
+    ("grams", "g"),
+    ("milligram", "mg"),
+    ("milligrams", "mg"),
+    ("pound", "lb"),
+    ("pounds", "lb"),
+    ("lbs", "lb"),
+    // land area
+    ("hectare", "ha"),
+    ("hectares", "ha"),
+    ("acre", "ac"),
+    ("acres", "ac"),
+    // liquid volume
+    ("liter", "L"),
+    ("liters", "L"),
+    ("litre", "L"),
+    ("litres", "L"),
+    ("milliliter", "mL"),
+    ("milliliters", "mL"),
+    ("millilitre", "mL"),
+    ("millilitres", "mL"),
+    ("US cups", "US cup"),
+    ("US tablespoon", "US tbsp"),
+    ("US tablespoons", "US tbsp"),
+    ("US teaspoon", "US tsp"),
+    ("US teaspoons", "US tsp"),
+    ("US fluid ounce", "US fl oz"),
+    ("US fluid ounces", "US fl oz"),
+    // torque
+    ("N-m", "N·m"),
+    ("newton meter", "N·m"),
+    ("newton meters", "N·m"),
+    ("lb-ft", "lbf·ft"),
+    ("ft-lb", "lbf·ft"),
+    ("lbf-ft", "lbf·ft"),
+    ("lb-in", "lbf·in"),
+    ("in-lb", "lbf·in"),
+    // information
+    ("byte", "B"),
+    ("bytes", "B"),
+    ("bits", "bit"),
+    ("bps", "bit/s"),
+    ("kbps", "kbit/s"),
+    ("Mbps", "Mbit/s"),
+    ("Gbps", "Gbit/s"),
+    // temperature
+    ("C", "°C"),
+    ("celsius", "°C"),
+    ("F", "°F"),
+    ("fahrenheit", "°F"),
+    ("K", "°K"),
+    ("kelvin", "°K"),
+];
+
+/// Table of unit synonyms for parsing
+///
+/// The application table is checked before the default table.
+#[derive(Clone, Copy, Debug)]
+pub struct Synonyms<'a> {
+    /// Application synonyms
+    table: &'a [(&'a str, &'a str)],
+
+    /// Flag to also check default synonyms
+    defaults: bool,
+}
+
+impl Default for Synonyms<'_> {
+    fn default() -> Self {
+        Synonyms {
+            table: &[],
+            defaults: true,
+        }
+    }
+}
+
+impl<'a> Synonyms<'a> {
+    /// Create a synonym table, extending the [DEFAULT_SYNONYMS]
+    pub const fn new(table: &'a [(&'a str, &'a str)]) -> Self {
+        Synonyms {
+            table,
+            defaults: true,
+        }
+    }
+
+    /// Create a synonym table, without the [DEFAULT_SYNONYMS]
+    pub const fn without_defaults(table: &'a [(&'a str, &'a str)]) -> Self {
+        Synonyms {
+            table,
+            defaults: false,
+        }
+    }
+
+    /// Look up the canonical label for an alias (ignoring ASCII case)
+    pub fn canonical(&self, alias: &str) -> Option<&'a str> {
+        let defaults = if self.defaults { DEFAULT_SYNONYMS } else { &[] };
+        // exact matches take priority over case-insensitive ones
+        let all = || self.table.iter().chain(defaults);
+        all()
+            .find(|(a, _)| *a == alias)
+            .or_else(|| all().find(|(a, _)| a.eq_ignore_ascii_case(alias)))
+            .map(|(_, label)| *label)
+    }
+
+    /// Parse a quantity, accepting synonyms for the unit label
+    ///
+    /// Whitespace between the value and label is optional.
+    pub fn parse<Q: Label>(&self, s: &str) -> Result<Q, ParseError> {
+        let s = s.trim();
+        let mut value_err = false;
+        for (i, _) in s.char_indices().skip(1) {
+            let (value, label) = s.split_at(i);
+            let label = label.trim_start();
+            if !self.matches::<Q>(label) {
+                continue;
+            }
+            match value.parse::<f64>() {
+                Ok(value) => {
+                    if cfg!(feature = "assert-finite") && !value.is_finite() {
+                        return Err(ParseError::Value);
+                    }
+                    return Ok(Q::from_magnitude(value));
+                }
+                Err(_) => value_err = true,
+            }
+        }
+        Err(if value_err {
+            ParseError::Value
+        } else {
+            ParseError::Unit
+        })
+    }
+
+    /// Check whether a label matches a quantity type
+    fn matches<Q: Label>(&self, label: &str) -> bool {
+        if label.is_empty() {
+            return false;
+        }
+        if is_label_ignore_case::<Q>(label) {
+            return true;
+        }
+        if let Some(canon) = self.canonical(label) {
+            if is_label_ignore_case::<Q>(canon) {
+                return true;
+            }
+        }
+        match label.split_once('/') {
+            Some((num, den)) => {
+                let num = num.trim_end();
+                let den = den.trim_start();
+                let num = self.canonical(num).unwrap_or(num);
+                let den = self.canonical(den).unwrap_or(den);
+                let mut buf = StackBuf::default();
+                write!(buf, "{num}/{den}").is_ok()
+                    && is_label_ignore_case::<Q>(buf.as_str())
+            }
+            None => false,
+        }
+    }
+}
+
+/// Check whether a label matches a quantity type (ignoring ASCII case)
+fn is_label_ignore_case<Q: Label>(label: &str) -> bool {
+    if Q::is_label(label) {
+        return true;
+    }
+    let mut buf = StackBuf::default();
+    Q::write_label(&mut buf).is_ok() && buf.as_str().eq_ignore_ascii_case(label)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::information::{Bandwidth, Mbit, B};
+    use crate::land::ac;
+    use crate::length::{ft, m, mi, mm, In, NM};
+    use crate::liquid::{cup_us, tbsp_us};
+    use crate::mass::lb;
+    use crate::quan::Quantity;
+    use crate::temp::DegF;
+    use crate::time::{h, min, ms, s};
+    use crate::{Length, Period, Speed};
+
+    #[test]
+    fn synonym_lookup() {
+        let syn = Synonyms::default();
+        assert_eq!(syn.canonical("hrs"), Some("h"));
+        assert_eq!(syn.canonical("Metres"), Some("m"));
+        assert_eq!(syn.canonical("furlongs"), None);
+        let syn = Synonyms::new(&[("C", "cups")]);
+        assert_eq!(syn.canonical("C"), Some("cups"));
+        assert_eq!(syn.canonical("c"), Some("cups"));
+        assert_eq!(Synonyms::without_defaults(&[]).canonical("hrs"), None);
+    }
+
+    #[test]
+    fn synonym_parse() {
+        let syn = Synonyms::default();
+        assert_eq!(syn.parse::<Period<s>>("30 sec"), Ok(30.0 * s));
+        assert_eq!(syn.parse::<Period<h>>("1.5hrs"), Ok(1.5 * h));
+        assert_eq!(syn.parse::<Period<min>>("10 MIN"), Ok(10.0 * min));
+        assert_eq!(syn.parse::<Period<ms>>("5 ms"), Ok(5.0 * ms));
+        assert_eq!(syn.parse::<Length<In>>("12″"), Ok(12.0 * In));
+        assert_eq!(syn.parse::<Length<mm>>("2e3 millimetres"), Ok(2e3 * mm));
+        assert_eq!(syn.parse::<Quantity<lb>>("3 lbs"), Ok(3 * lb));
+        assert_eq!(syn.parse::<Quantity<ac>>("160 acres"), Ok(160 * ac));
+        assert_eq!(syn.parse::<Quantity<DegF>>("72 F"), Ok(72 * DegF));
+        assert_eq!(syn.parse::<Speed<mi, h>>("65 MPH"), Ok(65.0 * mi / h));
+        assert_eq!(syn.parse::<crate::Knots>("15 knots"), Ok(15.0 * NM / h));
+        assert_eq!(
+            syn.parse::<Quantity<crate::torque::lbf_ft>>("90 ft-lb"),
+            Ok(90 * crate::torque::lbf_ft)
+        );
+        assert_eq!(
+            syn.parse::<Bandwidth<Mbit, s>>("100 Mbps"),
+            Ok(Bandwidth::new(100.0))
+        );
+        assert_eq!(syn.parse::<Quantity<B>>("512 bytes"), Ok(512 * B));
+        assert_eq!(
+            syn.parse::<Quantity<tbsp_us>>("2 US tablespoons"),
+            Ok(2 * tbsp_us)
+        );
+        assert_eq!(
+            syn.parse::<Quantity<cup_us>>("1.5 us cups"),
+            Ok(1.5 * cup_us)
+        );
+        assert_eq!(
+            syn.parse::<Speed<m, s>>("9.8 meters / sec"),
+            Ok(9.8 * m / s)
+        );
+    }
+
+    #[test]
+    fn synonym_errors() {
+        let syn = Synonyms::default();
+        assert_eq!(syn.parse::<Length<ft>>("3 miles"), Err(ParseError::Unit));
+        assert_eq!(
+            syn.parse::<Length<ft>>("three feet"),
+            Err(ParseError::Value)
+        );
+        assert_eq!(syn.parse::<Length<ft>>("3"), Err(ParseError::Unit));
+        assert_eq!(syn.parse::<Length<ft>>(""), Err(ParseError::Unit));
+        let syn = Synonyms::without_defaults(&[]);
+        assert_eq!(syn.parse::<Length<ft>>("3 feet"), Err(ParseError::Unit));
+        assert_eq!(syn.parse::<Length<ft>>("3 FT"), Ok(3.0 * ft));
+    }
+}
@@ -31,19 +31,19 @@
 //! let boiling = 0 * Delisle;
 //! assert_eq!(boiling.to_string(), "0 °D");
 //! assert_relative_eq!(
-//!     boiling.to::<DegC>().value,
+//!     boiling.to::<DegC>().value(),
 //!     100.0,
 //!     max_relative = 0.000_1
 //! );
 //! let freezing = 0 * DegC;
 //! assert_relative_eq!(
-//!     freezing.to::<Delisle>().value,
+//!     freezing.to::<Delisle>().value(),
 //!     150.0,
 //!     max_relative = 0.000_1
 //! );
 //! ```
 use crate::declare_unit;
-use crate::quan::Temperature;
+use crate::quan::{Quantity, Temperature, Unit};
 
 declare_unit!(
     /** Degrees Celsius / Centigrade */
@@ -52,6 +52,8 @@ declare_unit!(
     Temperature,
     1.0,
     -273.15,
+    "degree Celsius",
+    "degrees Celsius",
 );
 
 declare_unit!(
@@ -61,6 +63,8 @@ declare_unit!(
     Temperature,
     1.0,
     0.0,
+    "degree Kelvin",
+    "degrees Kelvin",
 );
 
 declare_unit!(
@@ -70,6 +74,8 @@ declare_unit!(
     Temperature,
     5.0 / 9.0,
     -459.67,
+    "degree Fahrenheit",
+    "degrees Fahrenheit",
 );
 
 declare_unit!(
@@ -79,6 +85,8 @@ declare_unit!(
     Temperature,
     5.0 / 9.0,
     0.0,
+    "degree Rankine",
+    "degrees Rankine",
 );
 
 declare_unit!(
@@ -86,16 +94,210 @@ declare_unit!(
     DegRe,
     "°Ré",
     Temperature,
-    0.8,
-    -273.15,
+    1.25,
+    -218.52,
+    "degree Réaumur",
+    "degrees Réaumur",
+);
+
+unit_fns!(
+    celsius => Quantity<DegC>::new_const,
+    kelvin => Quantity<DegK>::new_const,
+    fahrenheit => Quantity<DegF>::new_const,
 );
 
+impl<U> Quantity<U>
+where
+    U: Unit<Measure = Temperature>,
+{
+    /// Create a temperature from an absolute value in kelvins
+    ///
+    /// ```rust
+    /// use mag::{quan::Quantity, temp::DegC};
+    ///
+    /// let t = Quantity::<DegC>::from_kelvin(373.15);
+    /// assert_eq!(t.to_string(), "100 °C");
+    /// ```
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        Quantity::<DegK>::new(kelvin).to()
+    }
+
+    /// Get the absolute temperature in kelvins
+    ///
+    /// ```rust
+    /// use mag::temp::DegF;
+    ///
+    /// assert_eq!(format!("{:.2}", (98.6 * DegF).to_kelvin()), "310.15");
+    /// ```
+    pub fn to_kelvin(self) -> f64 {
+        self.to::<DegK>().value()
+    }
+}
+
+/// Two-point linear calibration for temperature sensors
+///
+/// Maps raw sensor readings (ADC counts, volts, etc.) to temperatures by
+/// interpolating between two reference points.
+///
+/// ```rust
+/// use mag::temp::{Calibration, DegC};
+///
+/// // ice bath reads 412 counts; boiling water reads 3_280 counts
+/// let cal = Calibration::new((412.0, 0.0 * DegC), (3_280.0, 100.0 * DegC))
+///     .unwrap();
+///
+/// assert_eq!(cal.apply(1_846.0), 50.0 * DegC);
+/// assert_eq!(cal.inverse(50.0 * DegC), 1_846.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration<U>
+where
+    U: Unit<Measure = Temperature>,
+{
+    /// Raw reading at first point
+    raw: f64,
+
+    /// Reference temperature at first point
+    reference: Quantity<U>,
+
+    /// Temperature change per raw unit
+    slope: f64,
+}
+
+impl<U> Calibration<U>
+where
+    U: Unit<Measure = Temperature>,
+{
+    /// Create a calibration from two `(raw, reference)` points
+    ///
+    /// Returns `None` if the raw values are equal (or not finite), or if the
+    /// reference temperatures are equal (a zero slope cannot be inverted).
+    pub fn new(
+        (raw0, ref0): (f64, Quantity<U>),
+        (raw1, ref1): (f64, Quantity<U>),
+    ) -> Option<Self> {
+        let slope = (ref1.value() - ref0.value()) / (raw1 - raw0);
+        if slope.is_finite() && slope != 0.0 && raw0.is_finite() {
+            Some(Calibration {
+                raw: raw0,
+                reference: ref0,
+                slope,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Map a raw reading to a calibrated temperature
+    pub fn apply(&self, raw: f64) -> Quantity<U> {
+        Quantity::new(self.reference.value() + self.slope * (raw - self.raw))
+    }
+
+    /// Map a temperature to the expected raw reading
+    pub fn inverse(&self, temp: Quantity<U>) -> f64 {
+        self.raw + (temp.value() - self.reference.value()) / self.slope
+    }
+}
+
+/// Approximate the wet-bulb temperature (requires `libm` feature)
+///
+/// Solves the psychrometric equation, with saturation vapor pressure from
+/// the Tetens formula.  Accuracy is within a few tenths of a degree for
+/// normal weather conditions.
+///
+/// * `temp` Dry-bulb (air) temperature
+/// * `rh` Relative humidity, in percent (0 to 100)
+/// * `pressure` Atmospheric pressure
+///
+/// ```rust
+/// use mag::{pressure::kPa, temp::{wet_bulb, DegC}};
+///
+/// let tw = wet_bulb(20.0 * DegC, 50.0, 101.325 * kPa);
+/// assert_eq!(format!("{:.1}", tw), "13.9 °C");
+/// ```
+#[cfg(feature = "libm")]
+pub fn wet_bulb<U, P>(
+    temp: Quantity<U>,
+    rh: f64,
+    pressure: Quantity<P>,
+) -> Quantity<U>
+where
+    U: Unit<Measure = Temperature>,
+    P: Unit<Measure = crate::quan::Pressure>,
+{
+    use crate::pressure::kPa;
+
+    /// Saturation vapor pressure (kPa) at a temperature (°C)
+    fn saturation(t: f64) -> f64 {
+        0.6108 * libm::exp(17.27 * t / (t + 237.3))
+    }
+
+    let t = temp.to::<DegC>().value();
+    let p = pressure.to::<kPa>().value();
+    let e = saturation(t) * rh / 100.0;
+    // vapor pressure implied by a wet-bulb temperature, minus actual
+    let residual = |tw: f64| {
+        let psy = 0.000_66 * (1.0 + 0.001_15 * tw);
+        saturation(tw) - psy * p * (t - tw) - e
+    };
+    // residual increases with wet-bulb temperature; bisect
+    let (mut lo, mut hi) = (t - 100.0, t);
+    for _ in 0..64 {
+        let mid = (lo + hi) / 2.0;
+        if residual(mid) > 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Quantity::<DegC>::new((lo + hi) / 2.0).to()
+}
+
 #[cfg(test)]
 mod test {
     extern crate alloc;
 
     use super::*;
     use alloc::{format, string::ToString};
+    use approx::assert_abs_diff_eq;
+
+    /// Tolerance for conversions
+    const EPS: f64 = 1e-9;
+
+    #[test]
+    #[cfg(feature = "libm")]
+    fn temp_wet_bulb() {
+        use crate::pressure::{hPa, inHg};
+        let tw = wet_bulb(30.0 * DegC, 100.0, 1_013.25 * hPa);
+        assert_abs_diff_eq!(tw.value(), 30.0, epsilon = 1e-6);
+        let tw = wet_bulb(35.0 * DegC, 20.0, 1_013.25 * hPa);
+        assert_eq!(format!("{:.1}", tw), "19.1 °C");
+        let tw = wet_bulb(86.0 * DegF, 60.0, 29.92 * inHg);
+        assert_eq!(format!("{:.1}", tw), "75.0 °F");
+        // lower pressure (at altitude) gives lower wet-bulb
+        let tw = wet_bulb(20.0 * DegC, 50.0, 80.0 * crate::pressure::kPa);
+        assert_eq!(format!("{:.1}", tw), "13.3 °C");
+    }
+
+    #[test]
+    fn temp_const() {
+        const THRESHOLDS: [Quantity<DegF>; 3] = [
+            celsius(-40.0).to_const(),
+            celsius(0.0).to_const(),
+            kelvin(373.15).to_const(),
+        ];
+        assert_abs_diff_eq!(THRESHOLDS[0].value(), -40.0, epsilon = EPS);
+        assert_abs_diff_eq!(THRESHOLDS[1].value(), 32.0, epsilon = EPS);
+        assert_abs_diff_eq!(THRESHOLDS[2].value(), 212.0, epsilon = EPS);
+        for t in [-40.0, 0.0, 37.5, 100.0] {
+            let c = celsius(t);
+            assert_eq!(c.to_const::<DegF>(), c.to::<DegF>());
+            assert_eq!(c.to_const::<DegRe>(), c.to::<DegRe>());
+            assert_eq!(c.to_const::<DegK>(), c.to::<DegK>());
+        }
+        let m = crate::mass::kilograms(2.5);
+        assert_eq!(m.to_const::<crate::mass::lb>(), m.to());
+    }
 
     #[test]
     fn temp_display() {
@@ -115,6 +317,47 @@ mod test {
         assert_eq!((0.0 * DegK).to(), -273.15 * DegC);
     }
 
+    /// Well-known temperatures, in K, °C, °F, °R and °Ré
+    const KNOWN: [[f64; 5]; 4] = [
+        // absolute zero
+        [0.0, -273.15, -459.67, 0.0, -218.52],
+        // freezing point of water
+        [273.15, 0.0, 32.0, 491.67, 0.0],
+        // normal body temperature
+        [310.15, 37.0, 98.6, 558.27, 29.6],
+        // boiling point of water
+        [373.15, 100.0, 212.0, 671.67, 80.0],
+    ];
+
+    #[test]
+    fn temp_known() {
+        for [k, c, f, r, re] in KNOWN {
+            assert_abs_diff_eq!((k * DegK).to_kelvin(), k, epsilon = EPS);
+            assert_abs_diff_eq!((c * DegC).to_kelvin(), k, epsilon = EPS);
+            assert_abs_diff_eq!((f * DegF).to_kelvin(), k, epsilon = EPS);
+            assert_abs_diff_eq!((r * DegR).to_kelvin(), k, epsilon = EPS);
+            assert_abs_diff_eq!((re * DegRe).to_kelvin(), k, epsilon = EPS);
+            let tc = Quantity::<DegC>::from_kelvin(k).value();
+            let tf = Quantity::<DegF>::from_kelvin(k).value();
+            let tr = Quantity::<DegR>::from_kelvin(k).value();
+            let tre = Quantity::<DegRe>::from_kelvin(k).value();
+            assert_abs_diff_eq!(tc, c, epsilon = EPS);
+            assert_abs_diff_eq!(tf, f, epsilon = EPS);
+            assert_abs_diff_eq!(tr, r, epsilon = EPS);
+            assert_abs_diff_eq!(tre, re, epsilon = EPS);
+            assert_abs_diff_eq!(
+                (f * DegF).to::<DegC>().value(),
+                c,
+                epsilon = EPS
+            );
+            assert_abs_diff_eq!(
+                (c * DegC).to::<DegRe>().value(),
+                re,
+                epsilon = EPS
+            );
+        }
+    }
+
     #[test]
     fn temp_add() {
         assert_eq!(10.0 * DegF + 5.5 * DegF, 15.5 * DegF);
@@ -126,4 +369,24 @@ mod test {
         assert_eq!(70.0 * DegF - 15.6 * DegF, 54.4 * DegF);
         assert_eq!(40.0 * DegC - 16.1 * DegC, 23.9 * DegC);
     }
+
+    #[test]
+    fn temp_parse() {
+        assert_eq!("-5.2 °F".parse(), Ok(-5.2 * DegF));
+        assert_eq!("22.4°C".parse(), Ok(22.4 * DegC));
+        assert!("22.4 C".parse::<Quantity<DegC>>().is_err());
+    }
+
+    #[test]
+    fn calibration() {
+        let cal = Calibration::new((1.0, 32.0 * DegF), (3.0, 212.0 * DegF));
+        let cal = cal.unwrap();
+        assert_eq!(cal.apply(2.0), 122.0 * DegF);
+        assert_eq!(cal.apply(-1.0), -148.0 * DegF);
+        assert_eq!(cal.inverse(302.0 * DegF), 4.0);
+        let bad = Calibration::new((5.0, 0.0 * DegC), (5.0, 10.0 * DegC));
+        assert_eq!(bad, None);
+        let flat = Calibration::new((1.0, 20.0 * DegC), (2.0, 20.0 * DegC));
+        assert_eq!(flat, None);
+    }
 }
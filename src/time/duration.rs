@@ -0,0 +1,98 @@
+// duration.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Conversion between Period and core Duration
+//!
+use crate::bounded::OutOfRange;
+use crate::time::{s, Unit};
+use crate::Period;
+use core::time::Duration;
+
+impl<U> Period<U>
+where
+    U: Unit,
+{
+    /// Convert from a [Duration]
+    ///
+    /// Durations are rounded to the nearest `f64`.
+    ///
+    /// [Duration]: https://doc.rust-lang.org/core/time/struct.Duration.html
+    pub fn from_duration(dur: Duration) -> Self {
+        Period::<s>::new(dur.as_secs_f64()).to()
+    }
+
+    /// Convert to a [Duration]
+    ///
+    /// Returns an error if the period is negative, not finite, or too large
+    /// for a `Duration`.
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use mag::{bounded::OutOfRange, time::ms};
+    ///
+    /// assert_eq!((250.0 * ms).to_duration(), Ok(Duration::from_millis(250)));
+    /// assert_eq!((-5.0 * ms).to_duration(), Err(OutOfRange(-5.0)));
+    /// ```
+    /// [Duration]: https://doc.rust-lang.org/core/time/struct.Duration.html
+    pub fn to_duration(self) -> Result<Duration, OutOfRange> {
+        let quantity = self.quantity();
+        let secs = self.to::<s>().quantity();
+        Duration::try_from_secs_f64(secs).map_err(|_| OutOfRange(quantity))
+    }
+}
+
+impl<U> From<Duration> for Period<U>
+where
+    U: Unit,
+{
+    fn from(dur: Duration) -> Self {
+        Period::from_duration(dur)
+    }
+}
+
+impl<U> TryFrom<Period<U>> for Duration
+where
+    U: Unit,
+{
+    type Error = OutOfRange;
+
+    fn try_from(per: Period<U>) -> Result<Self, Self::Error> {
+        per.to_duration()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::time::{h, min, ms, ns};
+    use alloc::format;
+
+    #[test]
+    fn from_duration() {
+        let d = Duration::from_millis(1_500);
+        assert_eq!(Period::<s>::from_duration(d), 1.5 * s);
+        assert_eq!(Period::<ms>::from(d), 1_500.0 * ms);
+        let p: Period<min> = Duration::from_secs(90).into();
+        assert_eq!(p, 1.5 * min);
+        let p = Period::<ns>::from(Duration::from_nanos(7));
+        assert_eq!(format!("{:.3}", p), "7.000 ns");
+    }
+
+    #[test]
+    fn to_duration() {
+        assert_eq!((2.0 * h).to_duration(), Ok(Duration::from_secs(7_200)));
+        assert_eq!(Duration::try_from(0.0 * s), Ok(Duration::ZERO));
+        assert_eq!(Duration::try_from(-1.0 * s), Err(OutOfRange(-1.0)));
+        assert_eq!((1e30 * h).to_duration(), Err(OutOfRange(1e30)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "assert-finite"))]
+    fn to_duration_nan() {
+        assert!(Period::<s>::new(f64::NAN).to_duration().is_err());
+        assert!(Period::<s>::new(f64::INFINITY).to_duration().is_err());
+    }
+}
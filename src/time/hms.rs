@@ -0,0 +1,189 @@
+// hms.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Periods in days, hours, minutes and seconds
+//!
+use crate::quan::ParseError;
+use crate::time::{s, Unit};
+use crate::Period;
+use core::fmt;
+
+/// Components of a composite period, largest first
+const COMPONENTS: [(&str, f64); 4] =
+    [("d", 86_400.0), ("h", 3_600.0), ("min", 60.0), ("s", 1.0)];
+
+/// Display wrapper for a [Period] in days, hours, minutes and seconds
+///
+/// Zero components are omitted, such as `1 d 2 h 3 min 4 s` or `5 min`.  A
+/// precision applies to the seconds; without one, seconds are rounded to the
+/// nearest nanosecond.
+///
+/// Created by [Period::display_hms].
+///
+/// [Period::display_hms]: ../struct.Period.html#method.display_hms
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HmsDisplay<U>(pub Period<U>)
+where
+    U: Unit;
+
+impl<U> Period<U>
+where
+    U: Unit,
+{
+    /// Display in days, hours, minutes and seconds
+    ///
+    /// ```rust
+    /// use mag::time::{h, s};
+    ///
+    /// let a = (93_784.0 * s).display_hms();
+    /// assert_eq!(a.to_string(), "1 d 2 h 3 min 4 s");
+    /// assert_eq!((1.75 * h).display_hms().to_string(), "1 h 45 min");
+    /// assert_eq!(format!("{:.1}", (90.25 * s).display_hms()), "1 min 30.3 s");
+    /// ```
+    pub fn display_hms(self) -> HmsDisplay<U> {
+        HmsDisplay(self)
+    }
+
+    /// Parse a period in days, hours, minutes and seconds
+    ///
+    /// This accepts the format written by [display_hms]: each component is a
+    /// value and a label (`d`, `h`, `min` or `s`), largest first.  Components
+    /// may be omitted, but not repeated.  A leading `-` negates the period.
+    ///
+    /// ```rust
+    /// use mag::{quan::ParseError, time::s, Period};
+    ///
+    /// assert_eq!(Period::parse_hms("1 d 2 h 3 min 4 s"), Ok(93_784.0 * s));
+    /// assert_eq!(Period::parse_hms("-2 min 0.5 s"), Ok(-120.5 * s));
+    /// assert_eq!(Period::<s>::parse_hms("4 s 3 min"), Err(ParseError::Unit));
+    /// ```
+    ///
+    /// [display_hms]: #method.display_hms
+    pub fn parse_hms(text: &str) -> Result<Self, ParseError> {
+        let text = text.trim();
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(rest) => (-1.0, rest),
+            None => (1.0, text),
+        };
+        let mut tokens = text.split_whitespace();
+        let mut components = COMPONENTS.iter();
+        let mut secs = 0.0;
+        let mut empty = true;
+        while let Some(value) = tokens.next() {
+            if !value.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+                return Err(ParseError::Value);
+            }
+            let value: f64 = value.parse().map_err(|_| ParseError::Value)?;
+            if !value.is_finite() {
+                return Err(ParseError::Value);
+            }
+            let label = tokens.next().ok_or(ParseError::Unit)?;
+            let (_, factor) = components
+                .find(|(lbl, _)| *lbl == label)
+                .ok_or(ParseError::Unit)?;
+            secs += value * factor;
+            empty = false;
+        }
+        if empty {
+            return Err(ParseError::Value);
+        }
+        Ok(Period::<s>::new(sign * secs).to())
+    }
+}
+
+impl<U> fmt::Display for HmsDisplay<U>
+where
+    U: Unit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = f.precision().unwrap_or(9);
+        let scale = (0..digits).fold(1.0, |sc, _| sc * 10.0);
+        let round = |val: f64| {
+            // values this large have no fractional digits to round
+            if (val * scale).abs() < 9_007_199_254_740_992.0 {
+                crate::quantize(val * scale, 1.0).0 as f64 / scale
+            } else {
+                val
+            }
+        };
+        let mut secs = round(self.0.quantity() * U::S_FACTOR);
+        if secs < 0.0 {
+            f.write_str("-")?;
+            secs = -secs;
+        }
+        let mut sep = "";
+        for (label, factor) in &COMPONENTS[..3] {
+            let count = (secs / factor) as u64;
+            if count > 0 {
+                write!(f, "{sep}{count} {label}")?;
+                secs -= count as f64 * factor;
+                sep = " ";
+            }
+        }
+        let secs = round(secs);
+        if secs > 0.0 || sep.is_empty() {
+            match f.precision() {
+                Some(p) => write!(f, "{sep}{secs:.p$} s"),
+                None => write!(f, "{sep}{secs} s"),
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::time::{d, h, min, ms};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn hms_display() {
+        assert_eq!(
+            (93_784.0 * s).display_hms().to_string(),
+            "1 d 2 h 3 min 4 s"
+        );
+        assert_eq!((2.0 * d).display_hms().to_string(), "2 d");
+        assert_eq!((0.0 * s).display_hms().to_string(), "0 s");
+        assert_eq!((1.1 * h).display_hms().to_string(), "1 h 6 min");
+        assert_eq!((-90.0 * min).display_hms().to_string(), "-1 h 30 min");
+        assert_eq!((1_500.0 * ms).display_hms().to_string(), "1.5 s");
+        assert_eq!(
+            (93_784.3 * s).display_hms().to_string(),
+            "1 d 2 h 3 min 4.3 s"
+        );
+    }
+
+    #[test]
+    fn hms_precision() {
+        assert_eq!(format!("{:.0}", (59.7 * s).display_hms()), "1 min");
+        assert_eq!(format!("{:.2}", (61.0 * s).display_hms()), "1 min 1.00 s");
+        assert_eq!(format!("{:.1}", (0.04 * s).display_hms()), "0.0 s");
+    }
+
+    #[test]
+    fn hms_parse() {
+        assert_eq!(Period::parse_hms("1 d 2 h 3 min 4 s"), Ok(93_784.0 * s));
+        assert_eq!(Period::parse_hms(" 90 min "), Ok(1.5 * h));
+        assert_eq!(Period::parse_hms("-1 h 30 min"), Ok(-90.0 * min));
+        assert_eq!(Period::parse_hms("2 d 0.5 h"), Ok(48.5 * h));
+        let p = Period::<s>::parse_hms;
+        assert_eq!(p(""), Err(ParseError::Value));
+        assert_eq!(p("1 h -5 min"), Err(ParseError::Value));
+        assert_eq!(p("five s"), Err(ParseError::Value));
+        assert_eq!(p("5"), Err(ParseError::Unit));
+        assert_eq!(p("5 min 5 min"), Err(ParseError::Unit));
+        assert_eq!(p("5 ms"), Err(ParseError::Unit));
+    }
+
+    #[test]
+    fn hms_round_trip() {
+        let a = 200_000.25 * s;
+        let b = Period::parse_hms(&a.display_hms().to_string());
+        assert_eq!(b, Ok(a));
+    }
+}
@@ -26,31 +26,73 @@
 //! [Frequency]: ../struct.Frequency.html
 //! [Period]: ../struct.Period.html
 //!
+mod duration;
+mod hms;
+mod stamp;
+#[cfg(feature = "std")]
+mod stopwatch;
+mod sweep;
+mod ticks;
 pub(crate) mod timepriv;
 
-/// Unit definition for time
-pub trait Unit {
-    /// Unit label
-    const LABEL: &'static str;
+pub use hms::HmsDisplay;
+pub use stamp::Timestamp;
+#[cfg(feature = "std")]
+pub use stopwatch::Stopwatch;
+pub use sweep::Sweep;
+pub use ticks::Ticks;
+
+use crate::quan::{self, Time};
+use crate::{Frequency, Period};
 
+/// Unit definition for time
+///
+/// Every time unit is a [quan::Unit] of [Time], which provides the label,
+/// names and runtime identifier.
+pub trait Unit: quan::Unit<Measure = Time> {
     /// Inverse unit label
     const INVERSE: &'static str;
 
     /// Multiplication factor to convert to seconds
-    const S_FACTOR: f64;
+    const S_FACTOR: f64 = <Self as quan::Unit>::FACTOR;
+
+    /// Exact factor to convert to seconds, as a `(numerator, denominator)`
+    ///
+    /// By default, this is found from a decimal [S_FACTOR], such as `0.001`.
+    /// Conversions between two units with exact ratios are rounded only once.
+    ///
+    /// [S_FACTOR]: #associatedconstant.S_FACTOR
+    const S_RATIO: Option<(u64, u64)> = crate::decimal_ratio(Self::S_FACTOR);
 
     /// Multiplication factor to convert to another unit
     fn factor<T: Unit>() -> f64 {
-        Self::S_FACTOR / T::S_FACTOR
+        <Self as Factor<T>>::FACTOR
     }
 }
 
+/// Conversion factor to another unit, evaluated when compiling
+pub(crate) trait Factor<T> {
+    /// Multiplication factor to convert to `T`
+    const FACTOR: f64;
+}
+
+impl<U, T> Factor<T> for U
+where
+    U: Unit + ?Sized,
+    T: Unit,
+{
+    const FACTOR: f64 =
+        crate::exact_factor(U::S_RATIO, T::S_RATIO, U::S_FACTOR / T::S_FACTOR);
+}
+
 /// Define a custom [unit] of [time]
 ///
 /// * `unit` Unit struct name
 /// * `label` Standard unit label
 /// * `inverse` Inverse time unit (frequency)
 /// * `s_factor` Factor to convert to seconds
+/// * `name` Unit name, singular (optional)
+/// * `plural` Unit name, plural (optional)
 ///
 /// # Example: Fortnight
 /// ```rust
@@ -76,32 +118,45 @@ macro_rules! time_unit {
         $label:expr,
         $inverse:expr,
         $s_factor:expr
+        $(, $name:expr, $plural:expr)?
     ) => {
         $(#[$doc])*
         #[allow(non_camel_case_types)]
-        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
         pub struct $unit;
 
         impl $crate::time::Unit for $unit {
-            const LABEL: &'static str = $label;
             const INVERSE: &'static str = $inverse;
-            const S_FACTOR: f64 = $s_factor;
         }
 
-        // f64 * <unit> => Period
-        impl core::ops::Mul<$unit> for f64 {
-            type Output = $crate::Period<$unit>;
-            fn mul(self, _other: $unit) -> Self::Output {
-                $crate::Period::new(self)
-            }
+        impl $crate::quan::Unit for $unit {
+            type Measure = $crate::quan::Time;
+            const LABEL: &'static str = $label;
+            const FACTOR: f64 = $s_factor;
+            const ZERO: f64 = 0.0;
+            $(
+                const NAME: &'static str = $name;
+                const NAME_PLURAL: &'static str = $plural;
+            )?
         }
 
-        // i32 * <unit> => Period
-        impl core::ops::Mul<$unit> for i32 {
-            type Output = $crate::Period<$unit>;
-            fn mul(self, _other: $unit) -> Self::Output {
-                $crate::Period::new(f64::from(self))
-            }
+        // scalar * <unit> => Period
+        $crate::impl_scalar_mul!($unit => $crate::Period<$unit>);
+
+        // "<label>" => <unit>, for quantity!
+        impl $crate::lit::Lookup<$unit>
+            for $crate::lit::Label<{ $crate::lit::hash($label) }>
+        {
+            type Part = $unit;
+            const PART: $unit = $unit;
+        }
+
+        // "<inverse>" => Frequency, for quantity!
+        impl $crate::lit::Lookup<$unit>
+            for $crate::lit::Label<{ $crate::lit::hash($inverse) }>
+        {
+            type Part = $crate::Frequency<$unit>;
+            const PART: Self::Part = $crate::Frequency::new(1.0);
         }
 
         // f64 / <unit> => Frequency
@@ -127,7 +182,18 @@ macro_rules! time_unit {
         {
             type Output = $crate::Speed<L, $unit>;
             fn div(self, _unit: $unit) -> Self::Output {
-                $crate::Speed::new(self.quantity)
+                $crate::Speed::new(self.quantity())
+            }
+        }
+
+        // Speed / <unit> => Acceleration
+        impl<L> core::ops::Div<$unit> for $crate::Speed<L, $unit>
+        where
+            L: $crate::length::Unit
+        {
+            type Output = $crate::Acceleration<L, $unit>;
+            fn div(self, _unit: $unit) -> Self::Output {
+                $crate::Acceleration::new(self.quantity())
             }
         }
     };
@@ -138,7 +204,9 @@ time_unit!(
     Gs,
     "Gs",
     "nHz",
-    1_000_000_000.0
+    1_000_000_000.0,
+    "gigasecond",
+    "gigaseconds"
 );
 
 time_unit!(
@@ -146,7 +214,9 @@ time_unit!(
     Ms,
     "Ms",
     "μHz",
-    1_000_000.0
+    1_000_000.0,
+    "megasecond",
+    "megaseconds"
 );
 
 time_unit!(
@@ -154,7 +224,9 @@ time_unit!(
     Ks,
     "Ks",
     "mHz",
-    1_000.0
+    1_000.0,
+    "kilosecond",
+    "kiloseconds"
 );
 
 time_unit!(
@@ -162,7 +234,9 @@ time_unit!(
     wk,
     "wk",
     "/wk",
-    7.0 * 24.0 * 60.0 * 60.0
+    7.0 * 24.0 * 60.0 * 60.0,
+    "week",
+    "weeks"
 );
 
 time_unit!(
@@ -170,7 +244,9 @@ time_unit!(
     d,
     "d",
     "/d",
-    24.0 * 60.0 * 60.0
+    24.0 * 60.0 * 60.0,
+    "day",
+    "days"
 );
 
 time_unit!(
@@ -178,7 +254,9 @@ time_unit!(
     h,
     "h",
     "/h",
-    60.0 * 60.0
+    60.0 * 60.0,
+    "hour",
+    "hours"
 );
 
 time_unit!(
@@ -186,7 +264,9 @@ time_unit!(
     min,
     "min",
     "/min",
-    60.0
+    60.0,
+    "minute",
+    "minutes"
 );
 
 time_unit!(
@@ -194,7 +274,9 @@ time_unit!(
     s,
     "s",
     "㎐",
-    1.0
+    1.0,
+    "second",
+    "seconds"
 );
 
 time_unit!(
@@ -202,7 +284,9 @@ time_unit!(
     ds,
     "ds",
     "daHz",
-    0.1
+    0.1,
+    "decisecond",
+    "deciseconds"
 );
 
 time_unit!(
@@ -210,7 +294,9 @@ time_unit!(
     ms,
     "ms",
     "㎑",
-    0.001
+    0.001,
+    "millisecond",
+    "milliseconds"
 );
 
 time_unit!(
@@ -218,7 +304,9 @@ time_unit!(
     us,
     "μs",
     "㎒",
-    0.000_001
+    0.000_001,
+    "microsecond",
+    "microseconds"
 );
 
 time_unit!(
@@ -226,7 +314,9 @@ time_unit!(
     ns,
     "ns",
     "㎓",
-    0.000_000_001
+    0.000_000_001,
+    "nanosecond",
+    "nanoseconds"
 );
 
 time_unit!(
@@ -234,17 +324,55 @@ time_unit!(
     ps,
     "ps",
     "㎔",
-    0.000_000_000_001
+    0.000_000_000_001,
+    "picosecond",
+    "picoseconds"
+);
+
+unit_fns!(
+    weeks => Period<wk>::new,
+    days => Period<d>::new,
+    hours => Period<h>::new,
+    minutes => Period<min>::new,
+    seconds => Period<s>::new,
+    milliseconds => Period<ms>::new,
+    microseconds => Period<us>::new,
+    nanoseconds => Period<ns>::new,
+    hertz => Frequency<s>::new,
 );
 
 #[cfg(test)]
 mod test {
     extern crate alloc;
 
-    use super::super::Frequency;
     use super::*;
     use alloc::{format, string::ToString};
 
+    #[test]
+    fn time_fns() {
+        const TIMEOUT: Period<ms> = milliseconds(250.0);
+        assert_eq!(TIMEOUT.to(), 0.25 * s);
+        assert_eq!(seconds(0.25), 0.25 * s);
+        assert_eq!(hours(1.5), 1.5 * h);
+        assert_eq!(hertz(60.0), 60.0 / s);
+    }
+
+    #[test]
+    fn time_exact() {
+        assert_eq!(ms::S_RATIO, Some((1, 1_000)));
+        assert_eq!((1.0 * ms).to(), 1_000.0 * us);
+        assert_eq!((90.0 * min).to(), 1.5 * h);
+        assert_eq!((3.0 / ms).to(), 0.003 / us);
+    }
+
+    #[test]
+    fn time_scalars() {
+        let ticks: u64 = 1_500;
+        assert_eq!(ms * ticks, 1_500.0 * ms);
+        assert_eq!(h * 0.5_f32, 0.5 * h);
+        assert_eq!(s * 60_u32, 60.0 * s);
+    }
+
     #[test]
     fn time_display() {
         assert_eq!((23.7 * s).to_string(), "23.7 s");
@@ -282,6 +410,36 @@ mod test {
         assert_eq!(2.5 / ds * 2.0, 5.0 / ds);
     }
 
+    #[test]
+    fn time_assign() {
+        let mut t = 0.0 * s;
+        for lap in [61.5 * s, 59.0 * s, 60.5 * s] {
+            t += lap;
+        }
+        assert_eq!(t, 181.0 * s);
+        t -= 1.0 * s;
+        t /= 3.0;
+        assert_eq!(t, 60.0 * s);
+        let mut f = 50.0 / s;
+        f *= 1.2;
+        assert_eq!(f, 60.0 / s);
+    }
+
+    #[test]
+    fn time_parse() {
+        assert_eq!("23.7 s".parse(), Ok(23.7 * s));
+        assert_eq!("50 ㎐".parse(), Ok(50.0 / s));
+        assert_eq!("2 /d".parse(), Ok(2.0 / d));
+        assert!("15 min".parse::<Period<h>>().is_err());
+    }
+
+    #[test]
+    fn time_rem() {
+        assert_eq!((250.0 * s) % (90.0 * s), 70.0 * s);
+        assert_eq!((3.0 * h) % (1.25 * h), 0.5 * h);
+        assert_eq!((-30.0 * s).rem_euclid(120.0 * s), 90.0 * s);
+    }
+
     #[test]
     fn time_div() {
         assert_eq!(5. / h, Frequency::<h>::new(5.0));
@@ -289,4 +447,19 @@ mod test {
         assert_eq!(1.0 / (1.0 * s), 1.0 / s);
         assert_eq!(2.0 / (1.0 / min), 2.0 * min);
     }
+
+    #[test]
+    fn time_recip() {
+        assert_eq!((8.0 * h).recip(), 0.125 / h);
+        assert_eq!((0.125 / h).recip(), 8.0 * h);
+        assert_eq!((-2.0 * s).recip(), -0.5 / s);
+        assert_eq!((40.0 / ms).recip().recip(), 40.0 / ms);
+    }
+
+    #[test]
+    fn time_cycles() {
+        assert_eq!((60.0 / s) * (2.5 * s), 150.0);
+        assert_eq!((0.5 * h) * (4.0 / h), 2.0);
+        assert_eq!((50.0 / s) * (100.0 * ms).to(), 5.0);
+    }
 }
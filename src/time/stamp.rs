@@ -0,0 +1,142 @@
+// stamp.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Timestamps relative to an epoch
+//!
+use crate::time::{s, Unit};
+use crate::Period;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+
+/// Point in time, in seconds since an _epoch_.
+///
+/// There is no calendar or time zone logic; the epoch is whatever the
+/// application chooses (program start, simulation start, Unix epoch, etc).
+/// The `E` type parameter is a marker for the epoch, which prevents mixing
+/// timestamps anchored to different epochs.
+///
+/// ## Operations
+///
+/// * Timestamp `-` Timestamp `=>` [Period]<[s]>
+/// * Timestamp `+` [Period] `=>` Timestamp
+/// * Timestamp `-` [Period] `=>` Timestamp
+///
+/// ```rust
+/// use mag::time::{min, s, Timestamp};
+///
+/// let start = Timestamp::<()>::new(100.0);
+/// let end = start + 2 * min;
+///
+/// assert_eq!(end.seconds, 220.0);
+/// assert_eq!(end - start, 120.0 * s);
+/// ```
+/// [Period]: ../struct.Period.html
+/// [s]: struct.s.html
+///
+pub struct Timestamp<E = ()> {
+    /// Seconds since epoch
+    pub seconds: f64,
+
+    /// Epoch marker
+    epoch: PhantomData<E>,
+}
+
+// Manual impls avoid requiring traits on the epoch marker
+impl<E> Clone for Timestamp<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for Timestamp<E> {}
+
+impl<E> fmt::Debug for Timestamp<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Timestamp")
+            .field("seconds", &self.seconds)
+            .finish()
+    }
+}
+
+impl<E> PartialEq for Timestamp<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.seconds == other.seconds
+    }
+}
+
+impl<E> PartialOrd for Timestamp<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.seconds.partial_cmp(&other.seconds)
+    }
+}
+
+impl<E> Timestamp<E> {
+    /// Create a timestamp from seconds since the epoch
+    pub fn new(seconds: f64) -> Self {
+        Timestamp {
+            seconds: crate::check_finite(seconds),
+            epoch: PhantomData,
+        }
+    }
+
+    /// Get the epoch timestamp
+    pub fn epoch() -> Self {
+        Self::new(0.0)
+    }
+
+    /// Get the period since the epoch
+    pub fn since_epoch(self) -> Period<s> {
+        Period::new(self.seconds)
+    }
+}
+
+// Timestamp - Timestamp => Period
+impl<E> Sub for Timestamp<E> {
+    type Output = Period<s>;
+    fn sub(self, other: Self) -> Self::Output {
+        Period::new(self.seconds - other.seconds)
+    }
+}
+
+// Timestamp + Period => Timestamp
+impl<E, U> Add<Period<U>> for Timestamp<E>
+where
+    U: Unit,
+{
+    type Output = Self;
+    fn add(self, per: Period<U>) -> Self::Output {
+        Self::new(self.seconds + per.to::<s>().quantity())
+    }
+}
+
+// Timestamp - Period => Timestamp
+impl<E, U> Sub<Period<U>> for Timestamp<E>
+where
+    U: Unit,
+{
+    type Output = Self;
+    fn sub(self, per: Period<U>) -> Self::Output {
+        Self::new(self.seconds - per.to::<s>().quantity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::time::{h, ms};
+
+    struct Boot;
+
+    #[test]
+    fn timestamp_ops() {
+        let t = Timestamp::<Boot>::new(3_600.0);
+        assert_eq!(t - Timestamp::epoch(), 3_600.0 * s);
+        assert_eq!(t + 500 * ms, Timestamp::new(3_600.5));
+        assert_eq!(t - 1 * h, Timestamp::epoch());
+        assert!(t > Timestamp::new(3_599.9));
+        assert_eq!((t + 2.5 * s).since_epoch(), 3_602.5 * s);
+    }
+}
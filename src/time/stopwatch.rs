@@ -0,0 +1,73 @@
+// stopwatch.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Stopwatch for measuring elapsed time (requires `std` feature)
+//!
+use crate::time::Unit;
+use crate::Period;
+use std::time::Instant;
+
+/// Stopwatch measuring elapsed [Period]s
+///
+/// Wraps a monotonic [Instant] from the standard library.
+///
+/// ```rust
+/// use mag::time::{ms, Stopwatch};
+///
+/// let watch = Stopwatch::start();
+/// // ... do some work ...
+/// let elapsed = watch.elapsed::<ms>();
+/// assert!(elapsed.quantity() >= 0.0);
+/// ```
+/// [Instant]: https://doc.rust-lang.org/std/time/struct.Instant.html
+/// [Period]: ../struct.Period.html
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Stopwatch {
+    /// Starting instant
+    started: Instant,
+}
+
+impl Stopwatch {
+    /// Start a new stopwatch
+    pub fn start() -> Self {
+        Stopwatch {
+            started: Instant::now(),
+        }
+    }
+
+    /// Get elapsed period since starting
+    pub fn elapsed<U: Unit>(&self) -> Period<U> {
+        Period::from_duration(self.started.elapsed())
+    }
+
+    /// Get elapsed period and restart (for lap timing)
+    pub fn lap<U: Unit>(&mut self) -> Period<U> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.started);
+        self.started = now;
+        Period::from_duration(elapsed)
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Self::start()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::time::ms;
+
+    #[test]
+    fn stopwatch_elapsed() {
+        let mut watch = Stopwatch::start();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let lap = watch.lap::<ms>();
+        assert!(lap >= 2.0 * ms);
+        assert!(watch.elapsed::<ms>() >= 0.0 * ms);
+    }
+}
@@ -0,0 +1,174 @@
+// sweep.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Frequency sweeps
+//!
+use crate::time::Unit;
+use crate::Frequency;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Spacing between sweep frequencies
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Spacing {
+    /// Linear spacing
+    Linear,
+    /// Logarithmic spacing
+    #[cfg(feature = "std")]
+    Log,
+}
+
+/// Iterator of frequencies between two endpoints (inclusive).
+///
+/// Created by [Frequency::linspace] or [Frequency::logspace].
+///
+/// [Frequency::linspace]: ../struct.Frequency.html#method.linspace
+/// [Frequency::logspace]: ../struct.Frequency.html#method.logspace
+#[derive(Clone, Debug)]
+pub struct Sweep<U>
+where
+    U: Unit,
+{
+    /// Starting frequency quantity
+    start: f64,
+
+    /// Ending frequency quantity
+    stop: f64,
+
+    /// Number of frequencies
+    count: usize,
+
+    /// Index of next frequency
+    index: usize,
+
+    /// Spacing
+    spacing: Spacing,
+
+    /// Measurement unit
+    unit: PhantomData<U>,
+}
+
+impl<U> Frequency<U>
+where
+    U: Unit,
+{
+    /// Sweep `count` linearly spaced frequencies from `start` to `stop`
+    ///
+    /// ```rust
+    /// use mag::{time::ms, Frequency};
+    ///
+    /// let sweep: Vec<_> = Frequency::linspace(1.0 / ms, 2.0 / ms, 5).collect();
+    /// assert_eq!(sweep, [1.0 / ms, 1.25 / ms, 1.5 / ms, 1.75 / ms, 2.0 / ms]);
+    /// ```
+    pub fn linspace(start: Self, stop: Self, count: usize) -> Sweep<U> {
+        Sweep {
+            start: start.quantity(),
+            stop: stop.quantity(),
+            count,
+            index: 0,
+            spacing: Spacing::Linear,
+            unit: PhantomData,
+        }
+    }
+
+    /// Sweep `count` logarithmically spaced frequencies from `start` to
+    /// `stop` (requires `std` feature)
+    ///
+    /// Both endpoints must be positive.
+    ///
+    /// ```rust
+    /// use mag::{time::s, Frequency};
+    ///
+    /// let decades: Vec<_> = Frequency::logspace(10.0 / s, 10_000.0 / s, 4)
+    ///     .map(|f| f.quantity().round())
+    ///     .collect();
+    /// assert_eq!(decades, [10.0, 100.0, 1_000.0, 10_000.0]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn logspace(start: Self, stop: Self, count: usize) -> Sweep<U> {
+        Sweep {
+            start: start.quantity(),
+            stop: stop.quantity(),
+            count,
+            index: 0,
+            spacing: Spacing::Log,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<U> Sweep<U>
+where
+    U: Unit,
+{
+    /// Get frequency at an index
+    fn frequency(&self, i: usize) -> Frequency<U> {
+        if i + 1 == self.count {
+            return Frequency::new(self.stop);
+        }
+        let t = i as f64 / (self.count - 1) as f64;
+        let (a, b) = (self.start, self.stop);
+        match self.spacing {
+            Spacing::Linear => Frequency::new(a + (b - a) * t),
+            #[cfg(feature = "std")]
+            Spacing::Log => Frequency::new(a * (b / a).powf(t)),
+        }
+    }
+}
+
+impl<U> Iterator for Sweep<U>
+where
+    U: Unit,
+{
+    type Item = Frequency<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.count {
+            let freq = self.frequency(self.index);
+            self.index += 1;
+            Some(freq)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.count - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<U> ExactSizeIterator for Sweep<U> where U: Unit {}
+
+impl<U> FusedIterator for Sweep<U> where U: Unit {}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use crate::time::{s, us};
+    use crate::Frequency;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn linspace() {
+        let f: Vec<_> = Frequency::linspace(0.0 / s, 30.0 / s, 4).collect();
+        assert_eq!(f, [0.0 / s, 10.0 / s, 20.0 / s, 30.0 / s]);
+        let f: Vec<_> = Frequency::linspace(5.0 / us, 1.0 / us, 3).collect();
+        assert_eq!(f, [5.0 / us, 3.0 / us, 1.0 / us]);
+        assert_eq!(Frequency::linspace(1.0 / s, 2.0 / s, 1).len(), 1);
+        assert_eq!(Frequency::linspace(1.0 / s, 2.0 / s, 0).next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn logspace() {
+        let sweep = Frequency::logspace(20.0 / s, 20_000.0 / s, 31);
+        assert_eq!(sweep.len(), 31);
+        let f: Vec<_> = sweep.collect();
+        assert_eq!(f[0], 20.0 / s);
+        assert_eq!(f[10], 199.99999999999997 / s);
+        assert_eq!(f[30], 20_000.0 / s);
+    }
+}
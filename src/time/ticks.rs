@@ -0,0 +1,149 @@
+// ticks.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Integer tick counts for schedulers
+//!
+use crate::bounded::OutOfRange;
+use crate::rounding::Rounding;
+use crate::time::{s, Unit};
+use crate::Period;
+
+/// Integer count of ticks at a fixed rate of `HZ` per second
+///
+/// Ticks are used by schedulers and RTOS kernels, where time is kept as an
+/// integer count.  Conversion from a [Period] uses an explicit [Rounding]
+/// direction; conversion to a period is exact for counts up to 2⁵³.
+///
+/// ## Example
+///
+/// ```rust
+/// use mag::{rounding::Rounding, time::{ms, Ticks}};
+///
+/// type Kernel = Ticks<1_000>;
+///
+/// let t = Kernel::from_period(2.5 * ms, Rounding::Up)?;
+/// assert_eq!(t.count(), 3);
+/// assert_eq!(t.to_period::<ms>(), 3.0 * ms);
+/// # Ok::<(), mag::bounded::OutOfRange>(())
+/// ```
+///
+/// A tick rate of zero is rejected when compiling:
+///
+/// ```compile_fail
+/// let _ = mag::time::Ticks::<0>::default();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ticks<const HZ: u32> {
+    /// Tick count
+    count: u64,
+}
+
+/// Tick counts at or above this (2⁶³) are out of range
+const MAX_TICKS: f64 = 9_223_372_036_854_775_808.0;
+
+impl<const HZ: u32> Ticks<HZ> {
+    /// Check that the tick rate is not zero
+    const NONZERO_HZ: () = assert!(HZ > 0, "tick rate must not be zero");
+
+    /// Create a new tick count
+    pub const fn new(count: u64) -> Self {
+        let () = Self::NONZERO_HZ;
+        Ticks { count }
+    }
+
+    /// Get the tick count
+    pub const fn count(self) -> u64 {
+        self.count
+    }
+
+    /// Convert from a period, rounding to a whole tick
+    ///
+    /// Returns an error if the rounded count is negative, not finite, or
+    /// 2⁶³ or more.
+    pub fn from_period<U>(
+        per: Period<U>,
+        rounding: Rounding,
+    ) -> Result<Self, OutOfRange>
+    where
+        U: Unit,
+    {
+        let quantity = per.quantity();
+        let ticks = per.to::<s>().quantity() * f64::from(HZ);
+        if ticks.is_finite() && ticks < MAX_TICKS {
+            let ticks = rounding.round(ticks);
+            if ticks >= 0.0 {
+                return Ok(Self::new(ticks as u64));
+            }
+        }
+        Err(OutOfRange(quantity))
+    }
+
+    /// Convert to a period
+    pub fn to_period<U>(self) -> Period<U>
+    where
+        U: Unit,
+    {
+        Period::<s>::new(self.count as f64 / f64::from(HZ)).to()
+    }
+}
+
+impl<const HZ: u32> Default for Ticks<HZ> {
+    fn default() -> Self {
+        let () = Self::NONZERO_HZ;
+        Ticks { count: 0 }
+    }
+}
+
+impl<U, const HZ: u32> From<Ticks<HZ>> for Period<U>
+where
+    U: Unit,
+{
+    fn from(ticks: Ticks<HZ>) -> Self {
+        ticks.to_period()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::time::{min, ms, us};
+
+    #[test]
+    fn ticks_from() {
+        let per = 1.25 * ms;
+        let t = |r| Ticks::<1_000>::from_period(per, r).map(Ticks::count);
+        assert_eq!(t(Rounding::Down), Ok(1));
+        assert_eq!(t(Rounding::Up), Ok(2));
+        assert_eq!(t(Rounding::TowardZero), Ok(1));
+        assert_eq!(t(Rounding::Nearest), Ok(1));
+        assert_eq!(
+            Ticks::<32_768>::from_period(1.0 * min, Rounding::Nearest),
+            Ok(Ticks::new(1_966_080))
+        );
+        assert_eq!(
+            Ticks::<100>::from_period(-0.004 * s, Rounding::Nearest),
+            Ok(Ticks::new(0))
+        );
+    }
+
+    #[test]
+    fn ticks_range() {
+        assert_eq!(
+            Ticks::<1_000>::from_period(-2.0 * ms, Rounding::Nearest),
+            Err(OutOfRange(-2.0))
+        );
+        assert_eq!(
+            Ticks::<1_000_000>::from_period(1e15 * s, Rounding::Down),
+            Err(OutOfRange(1e15))
+        );
+    }
+
+    #[test]
+    fn ticks_to() {
+        let t = Ticks::<1_000_000>::new(1_500);
+        assert_eq!(t.to_period::<us>(), 1_500.0 * us);
+        assert_eq!(Period::<ms>::from(t), 1.5 * ms);
+        assert_eq!(Ticks::<50>::default().to_period::<s>(), 0.0 * s);
+    }
+}
@@ -7,10 +7,12 @@
 //!
 extern crate alloc;
 
-use crate::{length, time::Unit, Length, Speed};
+use crate::quan::{fmt_quantity, Label, Quantity};
+use crate::time::{Factor, Unit};
+use crate::{length, Length, Speed};
 use core::fmt;
 use core::marker::PhantomData;
-use core::ops::{Add, Div, Mul, Sub};
+use core::ops::{Add, Div, Mul, Rem, Sub};
 
 /// _Period_, _duration_ or _interval_ of time.
 ///
@@ -20,14 +22,25 @@ use core::ops::{Add, Div, Mul, Sub};
 ///
 /// * f64 `*` [unit] `=>` Period
 /// * i32 `*` [unit] `=>` Period
+/// * [unit] `*` f64, f32, i32, u32, i64 or u64 `=>` Period
 /// * Period `+` Period `=>` Period
 /// * Period `-` Period `=>` Period
+/// * Period `%` Period `=>` Period
 /// * Period `*` f64 `=>` Period
+/// * Period `*` u32 `=>` Period
+/// * i32 `*` Period `=>` Period
+/// * u32 `*` Period `=>` Period
 /// * f64 `*` Period `=>` Period
+/// * Period `/` Period `=>` [Ratio]
+/// * Period `*` [Frequency] `=>` f64 (cycle count)
 /// * f64 `/` Period `=>` [Frequency]
+/// * Period `+=` Period
+/// * Period `-=` Period
+/// * Period `*=` f64
+/// * Period `/=` f64
 ///
-/// Units must be the same for operations with two Period operands.  The [to]
-/// method can be used for conversion.
+/// Units must be the same for operations with two Period operands, or with
+/// a [Frequency].  The [to] method can be used for conversion.
 ///
 /// ```rust
 /// use mag::time::{min, s};
@@ -39,6 +52,7 @@ use core::ops::{Add, Div, Mul, Sub};
 /// assert_eq!((a + b.to()).to_string(), "16.5 min");
 /// ```
 /// [Frequency]: struct.Frequency.html
+/// [Ratio]: ratio/struct.Ratio.html
 /// [unit]: time/index.html
 /// [to]: struct.Period.html#method.to
 ///
@@ -48,6 +62,7 @@ where
     U: Unit,
 {
     /// Period quantity
+    #[deprecated(note = "use the `quantity()` method")]
     pub quantity: f64,
 
     /// Measurement unit
@@ -65,12 +80,21 @@ where
 /// * Frequency `+` Frequency `=>` Frequency
 /// * Frequency `-` Frequency `=>` Frequency
 /// * Frequency `*` f64 `=>` Frequency
+/// * Frequency `*` u32 `=>` Frequency
+/// * i32 `*` Frequency `=>` Frequency
+/// * u32 `*` Frequency `=>` Frequency
 /// * f64 `*` Frequency `=>` Frequency
 /// * f64 `/` [Period] `=>` Frequency
 /// * f64 `/` Frequency `=>` [Period]
+/// * Frequency `/` Frequency `=>` [Ratio]
+/// * Frequency `*` [Period] `=>` f64 (cycle count)
+/// * Frequency `+=` Frequency
+/// * Frequency `-=` Frequency
+/// * Frequency `*=` f64
+/// * Frequency `/=` f64
 ///
-/// Units must be the same for operations with two Frequency operands.  The
-/// [to] method can be used for conversion.
+/// Units must be the same for operations with two Frequency operands, or
+/// with a [Period].  The [to] method can be used for conversion.
 ///
 /// ```rust
 /// use mag::time::{ns, s};
@@ -82,6 +106,7 @@ where
 /// assert_eq!(b.to_string(), "500 ㎓");
 /// ```
 /// [Period]: struct.Period.html
+/// [Ratio]: ratio/struct.Ratio.html
 /// [unit]: time/index.html
 /// [to]: struct.Frequency.html#method.to
 ///
@@ -91,22 +116,60 @@ where
     U: Unit,
 {
     /// Frequency quantity
+    #[deprecated(note = "use the `quantity()` method")]
     pub quantity: f64,
 
     /// Measurement unit
     unit: PhantomData<U>,
 }
 
-impl_base_ops!(Period, Unit);
-impl_base_ops!(Frequency, Unit);
+impl_base_ops!(Period, Unit, super::s);
+impl_base_ops!(Frequency, Unit, super::s);
+
+// Quantity => Period
+impl<U> From<Quantity<U>> for Period<U>
+where
+    U: Unit,
+{
+    fn from(q: Quantity<U>) -> Self {
+        Period::new(q.value())
+    }
+}
+
+// Period => Quantity
+impl<U> From<Period<U>> for Quantity<U>
+where
+    U: Unit,
+{
+    fn from(q: Period<U>) -> Self {
+        Quantity::new(q.quantity())
+    }
+}
+
+impl<U> Label for Period<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::LABEL)
+    }
+}
 
 impl<U> fmt::Display for Period<U>
 where
     U: Unit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.quantity.fmt(f)?;
-        write!(f, " {}", U::LABEL)
+        fmt_quantity(self, f)
+    }
+}
+
+impl<U> Label for Frequency<U>
+where
+    U: Unit,
+{
+    fn write_label<W: fmt::Write>(w: &mut W) -> fmt::Result {
+        w.write_str(U::INVERSE)
     }
 }
 
@@ -115,8 +178,7 @@ where
     U: Unit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.quantity.fmt(f)?;
-        write!(f, " {}", U::INVERSE)
+        fmt_quantity(self, f)
     }
 }
 
@@ -125,18 +187,64 @@ where
     U: Unit,
 {
     /// Create a new period quantity
-    pub fn new(quantity: f64) -> Self {
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
         Period::<U> {
-            quantity,
+            quantity: crate::check_finite(quantity),
             unit: PhantomData,
         }
     }
 
     /// Convert to specified units
-    pub fn to<T: Unit>(self) -> Period<T> {
-        let quantity = self.quantity * U::factor::<T>();
+    pub const fn to<T: Unit>(self) -> Period<T> {
+        let quantity = self.quantity() * <U as Factor<T>>::FACTOR;
         Period::new(quantity)
     }
+
+    /// Get the reciprocal frequency (rate)
+    ///
+    /// A zero period has an infinite frequency, which is not finite (see the
+    /// `assert-finite` feature).
+    ///
+    /// ```rust
+    /// use mag::time::{ms, s};
+    ///
+    /// assert_eq!((0.5 * s).recip(), 2.0 / s);
+    /// assert_eq!((20.0 * ms).recip().to::<s>(), 50.0 / s);
+    /// ```
+    pub fn recip(self) -> Frequency<U> {
+        Frequency::new(1.0 / self.quantity())
+    }
+
+    /// Get position within a repeating cycle
+    ///
+    /// Unlike `%`, the result is never negative (for a positive cycle).
+    ///
+    /// ```rust
+    /// use mag::time::s;
+    ///
+    /// assert_eq!((-10.0 * s).rem_euclid(90.0 * s), 80.0 * s);
+    /// assert_eq!((-10.0 * s) % (90.0 * s), -10.0 * s);
+    /// ```
+    pub fn rem_euclid(self, cycle: Self) -> Self {
+        let r = self.quantity() % cycle.quantity();
+        if r < 0.0 {
+            Period::new(r + cycle.quantity().abs())
+        } else {
+            Period::new(r)
+        }
+    }
+}
+
+// Period % Period => Period
+impl<U> Rem for Period<U>
+where
+    U: Unit,
+{
+    type Output = Self;
+    fn rem(self, cycle: Self) -> Self::Output {
+        Period::new(self.quantity() % cycle.quantity())
+    }
 }
 
 // f64 / Period => Frequency
@@ -146,7 +254,7 @@ where
 {
     type Output = Frequency<U>;
     fn div(self, other: Period<U>) -> Self::Output {
-        Self::Output::new(self / other.quantity)
+        Self::Output::new(self / other.quantity())
     }
 }
 
@@ -158,7 +266,7 @@ where
 {
     type Output = Speed<L, T>;
     fn div(self, per: Period<T>) -> Self::Output {
-        Speed::new(self.quantity / per.quantity)
+        Speed::new(self.quantity() / per.quantity())
     }
 }
 
@@ -167,18 +275,34 @@ where
     U: Unit,
 {
     /// Create a new frequency quantity
-    pub fn new(quantity: f64) -> Self {
+    #[allow(deprecated)]
+    pub const fn new(quantity: f64) -> Self {
         Frequency::<U> {
-            quantity,
+            quantity: crate::check_finite(quantity),
             unit: PhantomData,
         }
     }
 
     /// Convert to specified units
-    pub fn to<T: Unit>(self) -> Frequency<T> {
-        let quantity = self.quantity / U::factor::<T>();
+    pub const fn to<T: Unit>(self) -> Frequency<T> {
+        let quantity = self.quantity() / <U as Factor<T>>::FACTOR;
         Frequency::new(quantity)
     }
+
+    /// Get the reciprocal period (cycle time)
+    ///
+    /// A zero frequency has an infinite period, which is not finite (see the
+    /// `assert-finite` feature).
+    ///
+    /// ```rust
+    /// use mag::time::{min, s};
+    ///
+    /// assert_eq!((4.0 / s).recip(), 0.25 * s);
+    /// assert_eq!((30.0 / min).recip().to::<s>(), 2.0 * s);
+    /// ```
+    pub fn recip(self) -> Period<U> {
+        Period::new(1.0 / self.quantity())
+    }
 }
 
 // f64 / Frequency => Period
@@ -188,7 +312,29 @@ where
 {
     type Output = Period<U>;
     fn div(self, other: Frequency<U>) -> Self::Output {
-        Self::Output::new(self / other.quantity)
+        Self::Output::new(self / other.quantity())
+    }
+}
+
+// Frequency * Period => f64
+impl<U> Mul<Period<U>> for Frequency<U>
+where
+    U: Unit,
+{
+    type Output = f64;
+    fn mul(self, per: Period<U>) -> Self::Output {
+        self.quantity() * per.quantity()
+    }
+}
+
+// Period * Frequency => f64
+impl<U> Mul<Frequency<U>> for Period<U>
+where
+    U: Unit,
+{
+    type Output = f64;
+    fn mul(self, freq: Frequency<U>) -> Self::Output {
+        self.quantity() * freq.quantity()
     }
 }
 
@@ -200,7 +346,7 @@ where
 {
     type Output = Speed<L, T>;
     fn mul(self, len: Length<L>) -> Self::Output {
-        Speed::new(self.quantity * len.quantity)
+        Speed::new(self.quantity() * len.quantity())
     }
 }
 
@@ -212,6 +358,6 @@ where
 {
     type Output = Speed<L, T>;
     fn mul(self, freq: Frequency<T>) -> Self::Output {
-        Speed::new(self.quantity * freq.quantity)
+        Speed::new(self.quantity() * freq.quantity())
     }
 }
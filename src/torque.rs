@@ -0,0 +1,175 @@
+// torque.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Units of torque.
+//!
+//! Each unit is defined relative to newton meters with a conversion factor.
+//! They can be used to conveniently create Torque quantities.
+//!
+//! ## Operations
+//!
+//! * [Force] `*` [Length] `=>` Torque (N·m)
+//! * [Length] `*` [Force] `=>` Torque (N·m)
+//! * Torque `/` [Length] `=>` [Force] (N)
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{force::N, length::{cm, m}, torque::{lbf_ft, N_m}};
+//!
+//! let a = (40.0 * N) * (25.0 * cm);
+//! let b = 30.0 * lbf_ft;
+//!
+//! assert_eq!(a, 10.0 * N_m);
+//! assert_eq!(format!("{:.2}", b.to::<N_m>()), "40.67 N·m");
+//! assert_eq!(a / (0.5 * m), 20.0 * N);
+//! ```
+//!
+//! [Force]: ../struct.Force.html
+//! [Length]: ../struct.Length.html
+use crate::declare_unit;
+use crate::mass::kg;
+use crate::quan::{Mass, Quantity, Torque, Unit};
+use crate::{length, time, Force, Length};
+use core::ops::{Div, Mul};
+
+declare_unit!(
+    /** Newton meter / Newton metre */
+    N_m,
+    "N·m",
+    Torque,
+    1.0,
+    "newton meter",
+    "newton meters",
+);
+
+declare_unit!(
+    /** Kilonewton meter / Kilonewton metre */
+    kN_m,
+    "kN·m",
+    Torque,
+    1_000.0,
+    "kilonewton meter",
+    "kilonewton meters",
+);
+
+declare_unit!(
+    /** Newton centimeter / Newton centimetre */
+    N_cm,
+    "N·cm",
+    Torque,
+    0.01,
+    "newton centimeter",
+    "newton centimeters",
+);
+
+declare_unit!(
+    /** Pound-force foot */
+    lbf_ft,
+    "lbf·ft",
+    Torque,
+    1.355_817_948_331_400_4,
+    "pound-foot",
+    "pound-feet",
+);
+
+declare_unit!(
+    /** Pound-force inch */
+    lbf_in,
+    "lbf·in",
+    Torque,
+    0.112_984_829_027_616_7,
+    "pound-inch",
+    "pound-inches",
+);
+
+// Force * Length => Torque
+impl<M, L, T, R> Mul<Length<R>> for Force<M, L, T>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+    R: length::Unit,
+{
+    type Output = Quantity<N_m>;
+    fn mul(self, arm: Length<R>) -> Self::Output {
+        let force = self.to::<kg, length::m, time::s>();
+        let arm = arm.to::<length::m>();
+        Quantity::new(force.quantity() * arm.quantity())
+    }
+}
+
+// Length * Force => Torque
+impl<M, L, T, R> Mul<Force<M, L, T>> for Length<R>
+where
+    M: Unit<Measure = Mass>,
+    L: length::Unit,
+    T: time::Unit,
+    R: length::Unit,
+{
+    type Output = Quantity<N_m>;
+    fn mul(self, force: Force<M, L, T>) -> Self::Output {
+        force * self
+    }
+}
+
+// Torque / Length => Force
+impl<U, R> Div<Length<R>> for Quantity<U>
+where
+    U: Unit<Measure = Torque>,
+    R: length::Unit,
+{
+    type Output = Force<kg, length::m, time::s>;
+    fn div(self, arm: Length<R>) -> Self::Output {
+        let torque = self.to::<N_m>();
+        let arm = arm.to::<length::m>();
+        Force::new(torque.value() / arm.quantity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate alloc;
+
+    use super::*;
+    use crate::force::N;
+    use crate::length::{ft, m, In};
+    use crate::mass::lb;
+    use crate::time::s;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn torque_display() {
+        assert_eq!((25.0 * N_m).to_string(), "25 N·m");
+        assert_eq!((1.5 * kN_m).to_string(), "1.5 kN·m");
+        assert_eq!((80 * lbf_ft).to_string(), "80 lbf·ft");
+        assert_eq!(format!("{:#}", 12 * lbf_in), "12 lbf*in");
+    }
+
+    #[test]
+    fn torque_to() {
+        assert_eq!((1.0 * kN_m).to(), 1_000.0 * N_m);
+        assert_eq!((250.0 * N_cm).to(), 2.5 * N_m);
+        assert_eq!(
+            format!("{:.6}", (1.0 * lbf_ft).to::<lbf_in>()),
+            "12.000000 lbf·in"
+        );
+        assert_eq!(
+            format!("{:.3}", (100.0 * N_m).to::<lbf_ft>()),
+            "73.756 lbf·ft"
+        );
+    }
+
+    #[test]
+    fn torque_ops() {
+        assert_eq!((5.0 * N) * (2.0 * m), 10.0 * N_m);
+        assert_eq!((2.0 * m) * (5.0 * N), 10.0 * N_m);
+        assert_eq!((10.0 * N_m) / (4.0 * m), 2.5 * N);
+        assert_eq!(3.0 * N_m + 1.0 * N_m, 4.0 * N_m);
+        // 1 lbf = 1 lb · 32.174049 ft/s²
+        let lbf = Force::<lb, ft, s>::new(32.174_048_556_430_45);
+        let t = (lbf * (1.0 * In)).to::<lbf_in>();
+        assert_eq!(format!("{:.6}", t), "1.000000 lbf·in");
+    }
+}
@@ -0,0 +1,168 @@
+// uom_ops.rs
+//
+// Copyright (C) 2022-2026  Douglas P Lau
+//
+//! Conversions to and from the `uom` crate
+//!
+//! This module requires the `uom` feature.  `From` is implemented both ways
+//! between these types and their `uom::si::f64` counterparts:
+//!
+//! * [Length] ⇔ `Length`
+//! * [Mass] quantities ⇔ `Mass`
+//! * [Period] ⇔ `Time`
+//! * [Speed] ⇔ `Velocity`
+//!
+//! Values are converted through SI base units, so any mag units can be used.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use mag::{length::{ft, m}, Length};
+//! use uom::si::{f64, length::meter};
+//!
+//! let a: f64::Length = (10.0 * m).into();
+//! assert_eq!(a.get::<meter>(), 10.0);
+//!
+//! let b: Length<ft> = f64::Length::new::<meter>(0.3048).into();
+//! assert_eq!(b, 1.0 * ft);
+//! ```
+//!
+//! [Mass]: quan/struct.Mass.html
+use crate::quan::{Mass, Quantity, Unit};
+use crate::{length, mass, time, Length, Period, Speed};
+use uom::si::f64;
+use uom::si::{
+    length::meter, mass::kilogram, time::second, velocity::meter_per_second,
+};
+
+// Length => uom Length
+impl<U> From<Length<U>> for f64::Length
+where
+    U: length::Unit,
+{
+    fn from(len: Length<U>) -> Self {
+        f64::Length::new::<meter>(len.to::<length::m>().quantity())
+    }
+}
+
+// uom Length => Length
+impl<U> From<f64::Length> for Length<U>
+where
+    U: length::Unit,
+{
+    fn from(len: f64::Length) -> Self {
+        Length::<length::m>::new(len.get::<meter>()).to()
+    }
+}
+
+// Mass quantity => uom Mass
+impl<U> From<Quantity<U>> for f64::Mass
+where
+    U: Unit<Measure = Mass>,
+{
+    fn from(mass: Quantity<U>) -> Self {
+        f64::Mass::new::<kilogram>(mass.to::<mass::kg>().value())
+    }
+}
+
+// uom Mass => Mass quantity
+impl<U> From<f64::Mass> for Quantity<U>
+where
+    U: Unit<Measure = Mass>,
+{
+    fn from(mass: f64::Mass) -> Self {
+        Quantity::<mass::kg>::new(mass.get::<kilogram>()).to()
+    }
+}
+
+// Period => uom Time
+impl<U> From<Period<U>> for f64::Time
+where
+    U: time::Unit,
+{
+    fn from(per: Period<U>) -> Self {
+        f64::Time::new::<second>(per.to::<time::s>().quantity())
+    }
+}
+
+// uom Time => Period
+impl<U> From<f64::Time> for Period<U>
+where
+    U: time::Unit,
+{
+    fn from(per: f64::Time) -> Self {
+        Period::<time::s>::new(per.get::<second>()).to()
+    }
+}
+
+// Speed => uom Velocity
+impl<L, P> From<Speed<L, P>> for f64::Velocity
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn from(speed: Speed<L, P>) -> Self {
+        f64::Velocity::new::<meter_per_second>(speed.to_base().quantity())
+    }
+}
+
+// uom Velocity => Speed
+impl<L, P> From<f64::Velocity> for Speed<L, P>
+where
+    L: length::Unit,
+    P: time::Unit,
+{
+    fn from(speed: f64::Velocity) -> Self {
+        let mps = Speed::<length::m, time::s>::new;
+        mps(speed.get::<meter_per_second>()).to()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::length::{km, mm};
+    use crate::mass::{g, lb};
+    use crate::time::{h, min, ms, s};
+    use uom::si::{
+        length::kilometer, mass::gram, time::minute,
+        velocity::kilometer_per_hour,
+    };
+
+    #[test]
+    fn uom_length() {
+        let a: f64::Length = (2.5 * km).into();
+        assert_eq!(a.get::<kilometer>(), 2.5);
+        let b: Length<mm> = f64::Length::new::<meter>(1.5).into();
+        assert_eq!(b, 1_500.0 * mm);
+    }
+
+    #[test]
+    fn uom_mass() {
+        let a: f64::Mass = (250.0 * g).into();
+        assert_eq!(a.get::<gram>(), 250.0);
+        let b: Quantity<lb> = f64::Mass::new::<kilogram>(0.45359237).into();
+        assert_eq!(b, 1.0 * lb);
+    }
+
+    #[test]
+    fn uom_time() {
+        let a: f64::Time = (90.0 * s).into();
+        assert_eq!(a.get::<minute>(), 1.5);
+        let b: Period<ms> = f64::Time::new::<minute>(2.0).into();
+        assert_eq!(b, 120_000.0 * ms);
+        let c: Period<min> = f64::Time::from(3.0 * h).into();
+        assert_eq!(c, 180.0 * min);
+    }
+
+    #[test]
+    fn uom_speed() {
+        let a: f64::Velocity = (10.0 * length::m / s).into();
+        assert_eq!(a.get::<kilometer_per_hour>(), 36.0);
+        let b: Speed<km, h> =
+            f64::Velocity::new::<meter_per_second>(25.0).into();
+        assert_eq!(b, 90.0 * km / h);
+        let c: Speed<length::m, s> = f64::Velocity::from(90.0 * km / h).into();
+        assert_eq!(c, 25.0 * length::m / s);
+    }
+}
@@ -0,0 +1,78 @@
+//! Check that formatting quantities never allocates
+use mag::length::{ft, km, m, mi};
+use mag::mass::kg;
+use mag::temp::DegC;
+use mag::time::{h, min, s};
+use mag::{Acceleration, Speed};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator which counts allocations
+struct Counter;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+/// Fixed-size stack buffer
+struct Buf {
+    data: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, st: &str) -> fmt::Result {
+        let end = self.len + st.len();
+        self.data
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(st.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Buf {
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.data[..self.len]).unwrap()
+    }
+}
+
+/// Format a value, returning the number of allocations made
+fn count<Q: fmt::Display>(q: Q, expected: &str) -> usize {
+    let mut buf = Buf {
+        data: [0; 64],
+        len: 0,
+    };
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    write!(buf, "{q:.2}").unwrap();
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    assert_eq!(buf.as_str(), expected);
+    after - before
+}
+
+#[test]
+fn display_no_alloc() {
+    assert_eq!(count(2.5 * m, "2.50 m"), 0);
+    assert_eq!(count(3.0 * ft * ft, "3.00 ft²"), 0);
+    assert_eq!(count(2.0 * km * km * km, "2.00 km³"), 0);
+    assert_eq!(count(1.5 * s, "1.50 s"), 0);
+    assert_eq!(count(60.0 / min, "60.00 /min"), 0);
+    assert_eq!(count(Speed::<mi, h>::new(55.0), "55.00 mi/h"), 0);
+    assert_eq!(count(Acceleration::<m, s>::new(9.8), "9.80 m/s²"), 0);
+    assert_eq!(count(5.0 * kg, "5.00 kg"), 0);
+    assert_eq!(count(20.0 * DegC, "20.00 °C"), 0);
+}